@@ -3,7 +3,9 @@ use std::sync::Arc;
 use utils::{auth_middleware::InternalAuthState, types::jwks::JwkSet};
 use utoipa::OpenApi;
 
-use crate::{config::Config, types::app_state::AppState};
+use crate::{
+    config::Config, services::page_collaboration::CollaborationRooms, types::app_state::AppState,
+};
 
 mod authentik_api;
 mod config;
@@ -79,14 +81,31 @@ pub async fn build() -> (axum::Router, Config) {
         authentik_api_url: Arc::new(config.authentik_api_url.clone()),
         authentik_api_token: Arc::new(config.authentik_api_token.clone()),
         storage_service_url: Arc::new(config.storage_service_url.clone()),
+        collaboration_rooms: CollaborationRooms::default(),
     };
 
     let arc_state = Arc::new(app_state.clone());
 
-    workers::asset_cleanup::init_asset_cleanup_worker(arc_state, &config.draft_cleanup_cron)
+    workers::asset_cleanup::init_asset_cleanup_worker(arc_state.clone(), &config.draft_cleanup_cron)
         .await
         .expect("Failed to init asset cleanup worker");
 
+    workers::page_trash::init_page_trash_purge_worker(
+        arc_state.clone(),
+        &config.page_trash_purge_cron,
+        config.page_trash_retention_days,
+    )
+    .await
+    .expect("Failed to init page trash purge worker");
+
+    workers::task_reminders::init_task_reminder_worker(
+        arc_state,
+        &config.task_reminder_cron,
+        config.task_reminder_lead_hours,
+    )
+    .await
+    .expect("Failed to init task reminder worker");
+
     let router = axum::Router::new()
         .merge(router::init_router(app_state.clone()))
         .merge(
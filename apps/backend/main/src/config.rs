@@ -11,6 +11,10 @@ pub struct Config {
     pub db_max_connections: u32,
     pub log_filter: String,
     pub draft_cleanup_cron: String,
+    pub task_reminder_cron: String,
+    pub task_reminder_lead_hours: i32,
+    pub page_trash_purge_cron: String,
+    pub page_trash_retention_days: i32,
 }
 
 impl Config {
@@ -76,6 +80,22 @@ impl Config {
         let draft_cleanup_cron = std::env::var("DRAFT_CLEANUP_CRON")
             .unwrap_or_else(|_| "0 0 2,14 * * * *".to_string());
 
+        let task_reminder_cron = std::env::var("TASK_REMINDER_CRON")
+            .unwrap_or_else(|_| "0 */15 * * * * *".to_string());
+
+        let task_reminder_lead_hours = std::env::var("TASK_REMINDER_LEAD_HOURS")
+            .unwrap_or_else(|_| "24".to_string())
+            .parse::<i32>()
+            .map_err(|_| "TASK_REMINDER_LEAD_HOURS must be a valid i32".to_string())?;
+
+        let page_trash_purge_cron = std::env::var("PAGE_TRASH_PURGE_CRON")
+            .unwrap_or_else(|_| "0 30 3 * * * *".to_string());
+
+        let page_trash_retention_days = std::env::var("PAGE_TRASH_RETENTION_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<i32>()
+            .map_err(|_| "PAGE_TRASH_RETENTION_DAYS must be a valid i32".to_string())?;
+
         Ok(Config {
             database_url: database_url.unwrap(),
             port,
@@ -89,6 +109,10 @@ impl Config {
             db_max_connections,
             log_filter,
             draft_cleanup_cron,
+            task_reminder_cron,
+            task_reminder_lead_hours,
+            page_trash_purge_cron,
+            page_trash_retention_days,
         })
     }
 }
@@ -1,9 +1,13 @@
 use axum::{Json, extract::State};
 use error_handlers::handlers::ErrorResponse;
+use sql::assets::model::EntityType;
 
 use crate::{
     controllers::assets::dto::AssetResponse,
-    services::assets::{AssetsService, dto::CreateAssetDto},
+    services::{
+        assets::{AssetsService, dto::CreateAssetDto},
+        page_collaboration::PageCollaborationService,
+    },
     types::app_state::AppState,
 };
 
@@ -30,6 +34,10 @@ pub async fn create_asset(
 
     tx.commit().await?;
 
+    if matches!(asset.entity_type, EntityType::PageText) {
+        PageCollaborationService::refresh(&state, asset.entity_id).await;
+    }
+
     Ok(Json(AssetResponse {
         id: asset.id,
         name: asset.name,
@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::shared::validation::Validate;
+
 #[derive(utoipa::ToSchema, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBoardStatusRequest {
@@ -11,6 +13,8 @@ pub struct CreateBoardStatusRequest {
     pub initial: Option<bool>,
 }
 
+impl Validate for CreateBoardStatusRequest {}
+
 #[derive(Debug, Clone, utoipa::ToSchema, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BoardStatusResponse {
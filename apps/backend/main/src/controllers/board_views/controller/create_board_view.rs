@@ -0,0 +1,37 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+
+use crate::{
+    controllers::board_views::dto::{BoardViewResponse, CreateBoardViewRequest},
+    services::board_views::BoardViewService,
+    shared::extractors::json::ValidatedJson,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/views",
+    operation_id = "create_board_view",
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+    ),
+    request_body = CreateBoardViewRequest,
+    responses(
+        (status = 200, description = "Board view created", body = BoardViewResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Board View"],
+)]
+pub async fn create_board_view(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedJson(dto): ValidatedJson<CreateBoardViewRequest>,
+) -> Result<Json<BoardViewResponse>, ErrorResponse> {
+    BoardViewService::create(&state.postgres, &page_access, dto)
+        .await
+        .map(|v| Json(BoardViewResponse::from(v)))
+}
@@ -0,0 +1,36 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::board_views::dto::BoardViewResponse, services::board_views::BoardViewService,
+    shared::extractors::path::ValidatedPath, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/pages/{page_id}/views/{view_id}",
+    operation_id = "delete_board_view",
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+        ("view_id" = Uuid, Path, description = "Board view ID"),
+    ),
+    responses(
+        (status = 200, description = "Board view deleted", body = BoardViewResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Board View"],
+)]
+pub async fn delete_board_view(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, view_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<BoardViewResponse>, ErrorResponse> {
+    BoardViewService::delete(&state.postgres, &page_access, view_id)
+        .await
+        .map(|v| Json(BoardViewResponse::from(v)))
+}
@@ -0,0 +1,32 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+
+use crate::{
+    controllers::board_views::dto::BoardViewResponse, services::board_views::BoardViewService,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/views",
+    operation_id = "get_board_views",
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+    ),
+    responses(
+        (status = 200, description = "Shared views and the user's private views", body = Vec<BoardViewResponse>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Board View"],
+)]
+pub async fn get_board_views(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+) -> Result<Json<Vec<BoardViewResponse>>, ErrorResponse> {
+    BoardViewService::get_visible_views(&state.postgres, page_access.page_id, page_access.user_id)
+        .await
+        .map(|views| Json(views.into_iter().map(BoardViewResponse::from).collect()))
+}
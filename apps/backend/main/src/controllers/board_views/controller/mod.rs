@@ -0,0 +1,4 @@
+pub mod create_board_view;
+pub mod delete_board_view;
+pub mod get_board_views;
+pub mod update_board_view;
@@ -0,0 +1,41 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::board_views::dto::{BoardViewResponse, UpdateBoardViewRequest},
+    services::board_views::BoardViewService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    put,
+    path = "/pages/{page_id}/views/{view_id}",
+    operation_id = "update_board_view",
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+        ("view_id" = Uuid, Path, description = "Board view ID"),
+    ),
+    request_body = UpdateBoardViewRequest,
+    responses(
+        (status = 200, description = "Board view updated", body = BoardViewResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Board View"],
+)]
+pub async fn update_board_view(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, view_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedJson(dto): ValidatedJson<UpdateBoardViewRequest>,
+) -> Result<Json<BoardViewResponse>, ErrorResponse> {
+    BoardViewService::update(&state.postgres, &page_access, view_id, dto)
+        .await
+        .map(|v| Json(BoardViewResponse::from(v)))
+}
@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::board_views::model::{BoardView, BoardViewFilters, BoardViewGroupBy, BoardViewSort};
+use uuid::Uuid;
+
+use crate::shared::validation::Validate;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBoardViewRequest {
+    pub name: String,
+    pub is_shared: Option<bool>,
+    pub filters: Option<BoardViewFilters>,
+    pub sort: Option<BoardViewSort>,
+    pub group_by: Option<BoardViewGroupBy>,
+}
+
+impl Validate for CreateBoardViewRequest {}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBoardViewRequest {
+    pub name: Option<String>,
+    pub is_shared: Option<bool>,
+    pub filters: Option<BoardViewFilters>,
+    pub sort: Option<BoardViewSort>,
+    pub group_by: Option<BoardViewGroupBy>,
+}
+
+impl Validate for UpdateBoardViewRequest {}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardViewResponse {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub is_shared: bool,
+    pub filters: BoardViewFilters,
+    pub sort: BoardViewSort,
+    pub group_by: BoardViewGroupBy,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<BoardView> for BoardViewResponse {
+    fn from(view: BoardView) -> Self {
+        Self {
+            id: view.id,
+            page_id: view.page_id,
+            owner_id: view.owner_id,
+            name: view.name,
+            is_shared: view.is_shared,
+            filters: view.filters.0,
+            sort: view.sort.0,
+            group_by: view.group_by,
+            created_at: view.created_at,
+            updated_at: view.updated_at,
+        }
+    }
+}
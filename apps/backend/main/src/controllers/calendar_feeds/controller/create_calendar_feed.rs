@@ -0,0 +1,40 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::calendar_feeds::dto::{
+        CalendarFeedResponse, CreateCalendarFeedRequest, CreatedCalendarFeedResponse,
+    },
+    services::calendar_feeds::CalendarFeedService,
+    shared::extractors::json::ValidatedJson,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/calendar/feeds",
+    operation_id = "create_calendar_feed",
+    request_body = CreateCalendarFeedRequest,
+    responses(
+        (status = 200, description = "Calendar feed created, the token is only returned here", body = CreatedCalendarFeedResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "No access to the workspace or board", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Calendar"],
+)]
+pub async fn create_calendar_feed(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedJson(dto): ValidatedJson<CreateCalendarFeedRequest>,
+) -> Result<Json<CreatedCalendarFeedResponse>, ErrorResponse> {
+    let (feed, token) = CalendarFeedService::create(&state.postgres, user_id, dto).await?;
+
+    Ok(Json(CreatedCalendarFeedResponse {
+        feed: CalendarFeedResponse::from(feed),
+        path: format!("/calendar/ics/{token}.ics"),
+        token,
+    }))
+}
@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use error_handlers::handlers::ErrorResponse;
+
+use crate::{
+    controllers::calendar_feeds::dto::CalendarFeedQuery,
+    services::calendar_feeds::CalendarFeedService, shared::extractors::query::ValidatedQuery,
+    types::app_state::AppState,
+};
+
+/// Unauthenticated, calendar apps cannot send the Authentik token so the feed token is the credential.
+#[utoipa::path(
+    get,
+    path = "/calendar/ics/{token}",
+    operation_id = "get_calendar_feed_ics",
+    params(
+        ("token" = String, Path, description = "Feed token, optionally followed by `.ics`"),
+        ("lang" = Option<String>, Query, description = "Language used for status titles"),
+    ),
+    responses(
+        (status = 200, description = "iCalendar document", content_type = "text/calendar", body = String),
+        (status = 404, description = "Unknown or revoked feed", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(()),
+    tags = ["Calendar"],
+)]
+pub async fn get_calendar_feed_ics(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<CalendarFeedQuery>,
+) -> Result<Response, ErrorResponse> {
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+
+    let calendar = CalendarFeedService::render(&state.postgres, token, query.lang).await?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/calendar; charset=utf-8")],
+        calendar,
+    )
+        .into_response())
+}
@@ -0,0 +1,28 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::calendar_feeds::dto::CalendarFeedResponse,
+    services::calendar_feeds::CalendarFeedService, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/calendar/feeds",
+    operation_id = "get_calendar_feeds",
+    responses(
+        (status = 200, description = "Active calendar feeds of the current user", body = Vec<CalendarFeedResponse>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Calendar"],
+)]
+pub async fn get_calendar_feeds(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+) -> Result<Json<Vec<CalendarFeedResponse>>, ErrorResponse> {
+    CalendarFeedService::get_active(&state.postgres, user_id)
+        .await
+        .map(|feeds| Json(feeds.into_iter().map(CalendarFeedResponse::from).collect()))
+}
@@ -0,0 +1,4 @@
+pub mod create_calendar_feed;
+pub mod get_calendar_feed_ics;
+pub mod get_calendar_feeds;
+pub mod revoke_calendar_feed;
@@ -0,0 +1,34 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::calendar_feeds::dto::CalendarFeedResponse,
+    services::calendar_feeds::CalendarFeedService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/calendar/feeds/{feed_id}",
+    operation_id = "revoke_calendar_feed",
+    params(
+        ("feed_id" = Uuid, Path, description = "Calendar feed ID"),
+    ),
+    responses(
+        (status = 200, description = "Calendar feed revoked, its token stops working immediately", body = CalendarFeedResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Calendar"],
+)]
+pub async fn revoke_calendar_feed(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath(feed_id): ValidatedPath<Uuid>,
+) -> Result<Json<CalendarFeedResponse>, ErrorResponse> {
+    CalendarFeedService::revoke(&state.postgres, user_id, feed_id)
+        .await
+        .map(|f| Json(CalendarFeedResponse::from(f)))
+}
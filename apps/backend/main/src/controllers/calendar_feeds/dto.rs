@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::calendar_feeds::model::{CalendarFeed, CalendarFeedScope};
+use uuid::Uuid;
+
+use crate::shared::validation::Validate;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCalendarFeedRequest {
+    pub scope: CalendarFeedScope,
+    /// Required for `workspace` feeds
+    pub workspace_id: Option<Uuid>,
+    /// Required for `board` feeds
+    pub page_id: Option<Uuid>,
+}
+
+impl Validate for CreateCalendarFeedRequest {}
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarFeedQuery {
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarFeedResponse {
+    pub id: Uuid,
+    pub scope: CalendarFeedScope,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<CalendarFeed> for CalendarFeedResponse {
+    fn from(value: CalendarFeed) -> Self {
+        Self {
+            id: value.id,
+            scope: value.scope,
+            workspace_id: value.workspace_id,
+            page_id: value.page_id,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedCalendarFeedResponse {
+    #[serde(flatten)]
+    pub feed: CalendarFeedResponse,
+    /// Shown only once, store it in the calendar subscription URL
+    pub token: String,
+    pub path: String,
+}
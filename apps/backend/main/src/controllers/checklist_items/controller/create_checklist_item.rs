@@ -0,0 +1,36 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::checklist_items::dto::{ChecklistItemResponse, CreateChecklistItemRequest},
+    services::checklist_items::ChecklistItemService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/tasks/{task_id}/checklist",
+    operation_id = "create_checklist_item",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    request_body = CreateChecklistItemRequest,
+    responses(
+        (status = 200, description = "Checklist item appended to the end of the list", body = ChecklistItemResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Checklist"],
+)]
+pub async fn create_checklist_item(
+    State(state): State<AppState>,
+    ValidatedPath(task_id): ValidatedPath<Uuid>,
+    ValidatedJson(dto): ValidatedJson<CreateChecklistItemRequest>,
+) -> Result<Json<ChecklistItemResponse>, ErrorResponse> {
+    ChecklistItemService::create(&state.postgres, task_id, dto)
+        .await
+        .map(|i| Json(ChecklistItemResponse::from(i)))
+}
@@ -0,0 +1,35 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::checklist_items::dto::ChecklistItemResponse,
+    services::checklist_items::ChecklistItemService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/tasks/{task_id}/checklist/{item_id}",
+    operation_id = "delete_checklist_item",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID"),
+    ),
+    responses(
+        (status = 200, description = "Checklist item deleted", body = ChecklistItemResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Checklist"],
+)]
+pub async fn delete_checklist_item(
+    State(state): State<AppState>,
+    ValidatedPath((task_id, item_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<ChecklistItemResponse>, ErrorResponse> {
+    ChecklistItemService::delete(&state.postgres, task_id, item_id)
+        .await
+        .map(|i| Json(ChecklistItemResponse::from(i)))
+}
@@ -0,0 +1,33 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::checklist_items::dto::ChecklistItemResponse,
+    services::checklist_items::ChecklistItemService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/tasks/{task_id}/checklist",
+    operation_id = "get_checklist_items",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    responses(
+        (status = 200, description = "Checklist items ordered by position", body = Vec<ChecklistItemResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Checklist"],
+)]
+pub async fn get_checklist_items(
+    State(state): State<AppState>,
+    ValidatedPath(task_id): ValidatedPath<Uuid>,
+) -> Result<Json<Vec<ChecklistItemResponse>>, ErrorResponse> {
+    ChecklistItemService::get_by_task_id(&state.postgres, task_id)
+        .await
+        .map(|items| Json(items.into_iter().map(ChecklistItemResponse::from).collect()))
+}
@@ -0,0 +1,5 @@
+pub mod create_checklist_item;
+pub mod delete_checklist_item;
+pub mod get_checklist_items;
+pub mod move_checklist_item;
+pub mod update_checklist_item;
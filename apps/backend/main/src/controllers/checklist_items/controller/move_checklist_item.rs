@@ -0,0 +1,38 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::checklist_items::dto::{ChecklistItemResponse, MoveChecklistItemRequest},
+    services::checklist_items::ChecklistItemService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    put,
+    path = "/tasks/{task_id}/checklist/{item_id}/position",
+    operation_id = "move_checklist_item",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("item_id" = Uuid, Path, description = "Checklist item ID"),
+    ),
+    request_body = MoveChecklistItemRequest,
+    responses(
+        (status = 200, description = "Checklist item moved, other items are shifted accordingly", body = ChecklistItemResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Checklist"],
+)]
+pub async fn move_checklist_item(
+    State(state): State<AppState>,
+    ValidatedPath((task_id, item_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedJson(dto): ValidatedJson<MoveChecklistItemRequest>,
+) -> Result<Json<ChecklistItemResponse>, ErrorResponse> {
+    ChecklistItemService::move_to_position(&state.postgres, task_id, item_id, dto)
+        .await
+        .map(|i| Json(ChecklistItemResponse::from(i)))
+}
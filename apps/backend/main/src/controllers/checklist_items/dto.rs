@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::checklist_items::model::ChecklistItem;
+use uuid::Uuid;
+
+use crate::shared::validation::Validate;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChecklistItemRequest {
+    pub text: String,
+    pub assignee_id: Option<Uuid>,
+}
+
+impl Validate for CreateChecklistItemRequest {}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChecklistItemRequest {
+    pub text: Option<String>,
+    pub is_done: Option<bool>,
+    /// `null` removes the assignee, omitting the field leaves it unchanged.
+    #[serde(
+        default,
+        deserialize_with = "crate::shared::deserialization::deserialize_nullable"
+    )]
+    pub assignee_id: Option<Option<Uuid>>,
+}
+
+impl Validate for UpdateChecklistItemRequest {}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveChecklistItemRequest {
+    pub position: i32,
+}
+
+impl Validate for MoveChecklistItemRequest {}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ChecklistItemResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub text: String,
+    pub is_done: bool,
+    pub assignee_id: Option<Uuid>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<ChecklistItem> for ChecklistItemResponse {
+    fn from(value: ChecklistItem) -> Self {
+        Self {
+            id: value.id,
+            task_id: value.task_id,
+            text: value.text,
+            is_done: value.is_done,
+            assignee_id: value.assignee_id,
+            position: value.position,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::shared::tiptap_content::MentionKind;
+use uuid::Uuid;
+
+use crate::{
+    controllers::links::dto::BacklinkResponse, services::links::LinkService,
+    shared::extractors::path::ValidatedPath, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/backlinks",
+    operation_id = "get_page_backlinks",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+    ),
+    responses(
+        (status = 200, description = "Pages and tasks referencing the page, limited to the ones the user can access", body = Vec<BacklinkResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Backlinks"],
+)]
+pub async fn get_page_backlinks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+) -> Result<Json<Vec<BacklinkResponse>>, ErrorResponse> {
+    LinkService::get_backlinks(&state.postgres, MentionKind::Page, page_id, user_id)
+        .await
+        .map(|backlinks| Json(backlinks.into_iter().map(BacklinkResponse::from).collect()))
+}
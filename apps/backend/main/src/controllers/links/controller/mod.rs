@@ -0,0 +1,2 @@
+pub mod get_page_backlinks;
+pub mod get_task_backlinks;
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sql::links::model::Backlink;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BacklinkResponse {
+    pub page_id: Uuid,
+    pub page_title: String,
+    /// Set when the link is in a task description.
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Backlink> for BacklinkResponse {
+    fn from(value: Backlink) -> Self {
+        Self {
+            page_id: value.page_id,
+            page_title: value.page_title,
+            task_id: value.task_id,
+            task_title: value.task_title,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use axum::{Extension, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::mentions::dto::{MentionResponse, MentionsQuery},
+    services::mentions::MentionService,
+    shared::extractors::query::ValidatedQuery,
+    types::{app_state::AppState, pagination::Pagination},
+};
+
+#[utoipa::path(
+    get,
+    path = "/mentions",
+    operation_id = "get_my_mentions",
+    params(
+        ("limit" = Option<i64>, Query, description = "Count of items to return. Default: 10"),
+        ("offset" = Option<i64>, Query, description = "Start position. Default: 0"),
+    ),
+    responses(
+        (status = 200, description = "Places the current user was mentioned in, newest first", body = Pagination<MentionResponse>),
+        (status = 400, description = "Invalid query parameters", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Mentions"],
+)]
+pub async fn get_my_mentions(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<MentionsQuery>,
+) -> Result<Pagination<MentionResponse>, ErrorResponse> {
+    MentionService::get_user_mentions(&state.postgres, user_id, query.limit, query.offset)
+        .await
+        .map(|(mentions, total)| {
+            Pagination::new(
+                mentions.into_iter().map(MentionResponse::from).collect(),
+                total,
+                query.limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+                query
+                    .offset
+                    .unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+            )
+        })
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::mentions::model::MentionSource;
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct MentionsQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionResponse {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub page_title: String,
+    /// Set when the mention is in a task description.
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    pub mentioned_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<MentionSource> for MentionResponse {
+    fn from(value: MentionSource) -> Self {
+        Self {
+            id: value.id,
+            page_id: value.page_id,
+            page_title: value.page_title,
+            task_id: value.task_id,
+            task_title: value.task_title,
+            mentioned_by: value.mentioned_by,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -1,8 +1,20 @@
 pub mod assets;
 pub mod board_statuses;
+pub mod board_views;
+pub mod calendar_feeds;
+pub mod checklist_items;
 pub mod internal;
+pub mod links;
+pub mod mentions;
 pub mod page;
+pub mod page_collaboration;
+pub mod page_comments;
+pub mod page_share_links;
+pub mod page_templates;
+pub mod page_versions;
 pub mod profile;
+pub mod search;
 pub mod task;
+pub mod task_chat_messages;
 pub mod user;
 pub mod workspace;
@@ -8,7 +8,7 @@ use uuid::Uuid;
 use crate::{
     controllers::page::dto::{CreatePageRequest, PageResponse},
     repos::pages::CreatePageDto,
-    services::pages::PageService,
+    services::{page_templates::PageTemplateService, pages::PageService},
     shared::extractors::json::ValidatedJson,
     types::app_state::AppState,
 };
@@ -23,7 +23,9 @@ use crate::{
     request_body = CreatePageRequest,
     responses(
         (status = 200, description = "Page created", body = PageResponse),
+        (status = 400, description = "Template of another page type", body = ErrorResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
     ),
     tags = ["Page"],
@@ -36,18 +38,32 @@ pub async fn create_page(
 ) -> Result<Json<PageResponse>, ErrorResponse> {
     let mut tx = state.postgres.begin().await?;
 
-    let page = PageService::create(
-        &mut tx,
-        CreatePageDto {
-            title: create_page_dto.title,
-            r#type: create_page_dto.r#type,
-            parent_page_id: create_page_dto.parent_page_id,
-            content: create_page_dto.content,
-            workspace_id,
-            owner_id: user_id,
-        },
-    )
-    .await?;
+    let page = match create_page_dto.template_id {
+        Some(template_id) => {
+            PageTemplateService::create_page(
+                &mut tx,
+                workspace_id,
+                user_id,
+                template_id,
+                create_page_dto,
+            )
+            .await?
+        }
+        None => {
+            PageService::create(
+                &mut tx,
+                CreatePageDto {
+                    title: create_page_dto.title,
+                    r#type: create_page_dto.r#type,
+                    parent_page_id: create_page_dto.parent_page_id,
+                    content: create_page_dto.content,
+                    workspace_id,
+                    owner_id: user_id,
+                },
+            )
+            .await?
+        }
+    };
 
     tx.commit().await?;
 
@@ -31,17 +31,6 @@ pub async fn create_page_access(
     Extension(user_page_access): Extension<PageAccess>,
     ValidatedJson(dto): ValidatedJson<CreatePageAccessRequest>,
 ) -> Result<Json<PageAccessResponse>, ErrorResponse> {
-    if user_page_access.role < sql::page::model::Role::Admin {
-        return Err(ErrorResponse::forbidden(
-            codes::ForbiddenErrorCode::InsufficientPermissions,
-            Some(HashMap::from([(
-                "message".to_string(),
-                "Insufficient permissions".to_string(),
-            )])),
-            None,
-        ));
-    }
-
     if dto.role > sql::page::model::Role::Admin
         && user_page_access.role < sql::page::model::Role::Owner
     {
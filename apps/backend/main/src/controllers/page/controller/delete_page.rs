@@ -12,7 +12,7 @@ use crate::{controllers::page::dto::PageResponse, services::pages::PageService,
         ("page_id" = Uuid, Path, description = "Page ID"),
     ),
     responses(
-        (status = 200, description = "Page deleted", body = PageResponse),
+        (status = 200, description = "Page and its child pages moved to trash", body = PageResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
     ),
@@ -22,7 +22,11 @@ pub async fn delete_page(
     State(state): State<AppState>,
     Path(page_id): Path<Uuid>,
 ) -> Result<Json<PageResponse>, ErrorResponse> {
-    PageService::delete(&state.postgres, page_id)
-        .await
-        .map(|p| Json(PageResponse::from(p)))
+    let mut tx = state.postgres.begin().await?;
+
+    let page = PageService::delete(&mut tx, page_id).await?;
+
+    tx.commit().await?;
+
+    Ok(Json(PageResponse::from(page)))
 }
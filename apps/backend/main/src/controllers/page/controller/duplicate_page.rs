@@ -0,0 +1,44 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{DuplicatePageRequest, PageResponse},
+    services::page_duplication::PageDuplicationService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/duplicate",
+    operation_id = "duplicate_page",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+    ),
+    request_body = DuplicatePageRequest,
+    responses(
+        (status = 200, description = "Copy of the page, placed right after it. Group pages are copied with all their child pages", body = PageResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn duplicate_page(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedJson(dto): ValidatedJson<DuplicatePageRequest>,
+) -> Result<Json<PageResponse>, ErrorResponse> {
+    let mut tx = state.postgres.begin().await?;
+
+    let page =
+        PageDuplicationService::duplicate(&mut tx, page_id, page_access.user_id, dto).await?;
+
+    tx.commit().await?;
+
+    Ok(Json(PageResponse::from(page)))
+}
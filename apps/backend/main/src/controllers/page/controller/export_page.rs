@@ -0,0 +1,76 @@
+use axum::{
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{PageExportQuery, TaskExportRecord},
+    services::page_export::{ExportFormat, PageExportService},
+    shared::extractors::{
+        path::ValidatedPath, query::ValidatedQuery, x_user_language::XUserLanguage,
+    },
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/export",
+    operation_id = "export_page",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("format" = crate::controllers::page::dto::PageExportFormat, Query, description = "Export format, `csv` and `json` for boards, `md` and `html` for text pages"),
+        ("zip" = Option<bool>, Query, description = "Bundle a text page with its files into a zip. Default: false"),
+        ("x-user-language" = Option<String>, Header, description = "Language used for status titles"),
+    ),
+    responses(
+        (status = 200, description = "Streamed export of the board tasks or rendered text page", content(
+            (Vec<TaskExportRecord> = "application/json"),
+            (String = "text/csv"),
+            (String = "text/markdown"),
+            (String = "text/html"),
+            (Vec<u8> = "application/zip"),
+        )),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn export_page(
+    State(state): State<AppState>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<PageExportQuery>,
+    XUserLanguage(lang): XUserLanguage,
+) -> Result<Response, ErrorResponse> {
+    let export = match ExportFormat::from(query.format) {
+        ExportFormat::Board(format) => {
+            PageExportService::export_board(&state.postgres, page_id, lang, format).await?
+        }
+        ExportFormat::Text(format) => {
+            PageExportService::export_text(
+                &state.postgres,
+                &state.storage_service_url,
+                &state.jwt_secret,
+                page_id,
+                format,
+                query.zip.unwrap_or(false),
+            )
+            .await?
+        }
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, export.content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", export.file_name),
+            ),
+        ],
+        export.body,
+    )
+        .into_response())
+}
@@ -30,17 +30,6 @@ pub async fn get_page_access_list(
     Extension(user_page_access): Extension<PageAccess>,
     // Path(page_id): Path<Uuid>,
 ) -> Result<Json<Vec<PageAccessResponse>>, ErrorResponse> {
-    if user_page_access.role < sql::page::model::Role::Member {
-        return Err(ErrorResponse::forbidden(
-            codes::ForbiddenErrorCode::InsufficientPermissions,
-            Some(HashMap::from([(
-                "message".to_string(),
-                "Insufficient permissions".to_string(),
-            )])),
-            None,
-        ));
-    }
-
     let page_access_list =
         PageService::get_page_access_list(&state.postgres, user_page_access.page_id)
             .await
@@ -4,8 +4,10 @@ use sql::page::model::PageAccess;
 use uuid::Uuid;
 
 use crate::{
-    controllers::page::dto::DetailedPageResponse,
-    shared::extractors::{path::ValidatedPath, x_user_language::XUserLanguage},
+    controllers::page::dto::{DetailedPageResponse, PageDetailsQuery},
+    shared::extractors::{
+        path::ValidatedPath, query::ValidatedQuery, x_user_language::XUserLanguage,
+    },
     services::pages::PageService,
     types::app_state::AppState,
 };
@@ -16,10 +18,13 @@ use crate::{
     operation_id = "get_page_detailed",
     params(
         ("page_id" = Uuid, Path, description = "Page ID"),
+        ("view_id" = Option<Uuid>, Query, description = "Saved board view whose filters and sort are applied to the tasks of a board page, grouping is left to the client"),
+        ("descendants" = Option<bool>, Query, description = "Include every page below a group page. Default: false"),
     ),
     responses(
         (status = 200, description = "Page details", body = DetailedPageResponse),
         (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Board view not found", body = ErrorResponse),
         (status = 500, description = "Internal server error", body = ErrorResponse),
     ),
     tags = ["Page"],
@@ -28,9 +33,17 @@ pub async fn get_page_details(
     State(state): State<AppState>,
     Extension(page_access): Extension<PageAccess>,
     ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<PageDetailsQuery>,
     XUserLanguage(lang): XUserLanguage,
 ) -> Result<Json<DetailedPageResponse>, ErrorResponse> {
-    PageService::get_detailed_page(&state.postgres, page_id, page_access, lang)
-        .await
-        .map(Json)
+    PageService::get_detailed_page(
+        &state.postgres,
+        page_id,
+        page_access,
+        lang,
+        query.view_id,
+        query.descendants.unwrap_or(false),
+    )
+    .await
+    .map(Json)
 }
@@ -15,7 +15,10 @@ use crate::{
     types::app_state::AppState,
 };
 
-pub fn build_page_tree(pages: Vec<(Page, PageResponse)>) -> Vec<(Page, PageResponse)> {
+/// Nests child pages under their group, roots and children keep the sibling `position` order.
+pub fn build_page_tree(mut pages: Vec<(Page, PageResponse)>) -> Vec<(Page, PageResponse)> {
+    pages.sort_by_key(|(page, _)| (page.position, page.created_at));
+
     let mut children_by_parent: HashMap<Option<Uuid>, Vec<(Page, PageResponse)>> = HashMap::new();
 
     for entry in pages {
@@ -90,6 +93,8 @@ pub async fn get_page_list(
                 id: page.id,
                 r#type: page.r#type,
                 title: page.title,
+                parent_page_id: page.parent_page_id,
+                position: page.position,
                 child_pages: None,
                 created_at: page.created_at,
                 updated_at: page.updated_at,
@@ -0,0 +1,53 @@
+use axum::{Extension, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{PageResponse, PageTrashQuery},
+    services::pages::PageService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::{app_state::AppState, pagination::Pagination},
+};
+
+#[utoipa::path(
+    get,
+    path = "/workspaces/{workspace_id}/trash",
+    operation_id = "get_page_trash",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+        ("limit" = Option<i64>, Query, description = "Count of items to return. Default: 10"),
+        ("offset" = Option<i64>, Query, description = "Start position. Default: 0"),
+    ),
+    responses(
+        (status = 200, description = "Trashed pages accessible to the user, child pages trashed along with a parent are not listed separately", body = Pagination<PageResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn get_page_trash(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath(workspace_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<PageTrashQuery>,
+) -> Result<Pagination<PageResponse>, ErrorResponse> {
+    let (pages, total) = PageService::get_trash(
+        &state.postgres,
+        workspace_id,
+        user_id,
+        query.limit,
+        query.offset,
+    )
+    .await?;
+
+    Ok(Pagination::new(
+        pages.into_iter().map(PageResponse::from).collect(),
+        total,
+        query.limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+        query
+            .offset
+            .unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+    ))
+}
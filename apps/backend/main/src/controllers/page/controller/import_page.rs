@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension, Json,
+    extract::{Multipart, Path, State},
+};
+use error_handlers::{codes, handlers::ErrorResponse};
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{ImportPageForm, PageResponse},
+    services::page_import::PageImportService,
+    types::app_state::AppState,
+};
+
+fn bad_request(msg: String) -> ErrorResponse {
+    ErrorResponse::bad_request(codes::BadRequestErrorCode::InvalidBody, None, Some(msg))
+}
+
+#[utoipa::path(
+    post,
+    path = "/workspaces/{workspace_id}/pages/import",
+    operation_id = "import_page",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+    ),
+    request_body(
+        content_type = "multipart/form-data",
+        content = ImportPageForm,
+    ),
+    responses(
+        (status = 200, description = "Text page created from the Markdown file", body = PageResponse),
+        (status = 400, description = "Invalid file or content", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn import_page(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(workspace_id): Path<Uuid>,
+    mut multipart: Multipart,
+) -> Result<Json<PageResponse>, ErrorResponse> {
+    let mut file: Option<(String, Vec<u8>)> = None;
+    let mut title: Option<String> = None;
+    let mut parent_page_id: Option<Uuid> = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| bad_request(e.to_string()))?
+    {
+        let field_name = field.name().unwrap_or_default().to_string();
+
+        match field_name.as_str() {
+            "file" => {
+                let file_name = field.file_name().unwrap_or("Imported page").to_string();
+                let bytes = field
+                    .bytes()
+                    .await
+                    .map_err(|e| bad_request(e.to_string()))?;
+                file = Some((file_name, bytes.to_vec()));
+            }
+            "title" => {
+                title = Some(field.text().await.map_err(|e| bad_request(e.to_string()))?);
+            }
+            "parentPageId" => {
+                let val = field.text().await.map_err(|e| bad_request(e.to_string()))?;
+                parent_page_id = Some(Uuid::parse_str(val.trim()).map_err(|e| {
+                    ErrorResponse::bad_request(
+                        codes::BadRequestErrorCode::InvalidBody,
+                        Some(HashMap::from([("parentPageId".to_string(), e.to_string())])),
+                        None,
+                    )
+                })?);
+            }
+            _ => {}
+        }
+    }
+
+    let (file_name, bytes) = file.ok_or_else(|| bad_request("Missing 'file' field".to_string()))?;
+
+    let page = PageImportService::import_markdown(
+        &state.postgres,
+        &state.storage_service_url,
+        workspace_id,
+        user_id,
+        file_name,
+        bytes,
+        title,
+        parent_page_id,
+    )
+    .await?;
+
+    Ok(Json(PageResponse::from(page)))
+}
@@ -1,9 +1,15 @@
 pub mod create_page;
 pub mod delete_page;
+pub mod duplicate_page;
 pub mod get_page_list;
 pub mod get_page;
+pub mod get_page_trash;
+pub mod import_page;
+pub mod move_page;
+pub mod restore_page;
 pub mod update_page;
 
+pub mod export_page;
 pub mod get_page_details;
 
 pub mod create_page_access;
@@ -0,0 +1,44 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{MovePageRequest, PageResponse},
+    services::pages::PageService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    put,
+    path = "/pages/{page_id}/position",
+    operation_id = "move_page",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+    ),
+    request_body = MovePageRequest,
+    responses(
+        (status = 200, description = "Page moved, sibling pages are shifted accordingly", body = PageResponse),
+        (status = 400, description = "Invalid parent page or a move into its own subtree", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn move_page(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedJson(dto): ValidatedJson<MovePageRequest>,
+) -> Result<Json<PageResponse>, ErrorResponse> {
+    let mut tx = state.postgres.begin().await?;
+
+    let page = PageService::move_to_position(&mut tx, page_id, page_access.user_id, dto).await?;
+
+    tx.commit().await?;
+
+    Ok(Json(PageResponse::from(page)))
+}
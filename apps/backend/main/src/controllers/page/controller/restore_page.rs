@@ -0,0 +1,40 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::PageResponse, services::pages::PageService,
+    shared::extractors::path::ValidatedPath, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/workspaces/{workspace_id}/trash/{page_id}/restore",
+    operation_id = "restore_page",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+        ("page_id" = Uuid, Path, description = "Trashed page ID"),
+    ),
+    responses(
+        (status = 200, description = "Page restored with the child pages trashed along with it", body = PageResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Page not found in trash", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn restore_page(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath((workspace_id, page_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageResponse>, ErrorResponse> {
+    let mut tx = state.postgres.begin().await?;
+
+    let page = PageService::restore(&mut tx, workspace_id, page_id, user_id).await?;
+
+    tx.commit().await?;
+
+    Ok(Json(PageResponse::from(page)))
+}
@@ -1,5 +1,5 @@
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, State},
 };
 use error_handlers::handlers::ErrorResponse;
@@ -7,7 +7,7 @@ use uuid::Uuid;
 
 use crate::{
     controllers::page::dto::{PageResponse, UpdatePageRequest},
-    services::pages::PageService,
+    services::{page_collaboration::PageCollaborationService, pages::PageService},
     shared::extractors::json::ValidatedJson,
     types::app_state::AppState,
 };
@@ -31,13 +31,16 @@ use crate::{
 pub async fn update_page(
     State(state): State<AppState>,
     Path(page_id): Path<Uuid>,
+    Extension(user_id): Extension<Uuid>,
     ValidatedJson(update_page_dto): ValidatedJson<UpdatePageRequest>,
 ) -> Result<Json<PageResponse>, ErrorResponse> {
     let mut tx = state.postgres.begin().await?;
 
-    let page = PageService::update(&mut tx, page_id, update_page_dto).await?;
+    let page = PageService::update(&mut tx, page_id, user_id, update_page_dto).await?;
 
     tx.commit().await?;
 
+    PageCollaborationService::refresh(&state, page_id).await;
+
     Ok(Json(PageResponse::from(page)))
 }
@@ -1,7 +1,5 @@
-use std::collections::HashMap;
-
 use axum::{Extension, extract::State};
-use error_handlers::{codes, handlers::ErrorResponse};
+use error_handlers::handlers::ErrorResponse;
 use sql::page::model::PageAccess;
 
 use crate::{
@@ -35,17 +33,6 @@ pub async fn update_page_access(
     // Path(page_id): Path<Uuid>,
     ValidatedJson(dto): ValidatedJson<UpdatePageAccessRequest>,
 ) -> Result<axum::Json<PageAccessResponse>, ErrorResponse> {
-    if user_page_access.role < sql::page::model::Role::Admin {
-        return Err(ErrorResponse::forbidden(
-            codes::ForbiddenErrorCode::InsufficientPermissions,
-            Some(HashMap::from([(
-                "message".to_string(),
-                "Insufficient permissions".to_string(),
-            )])),
-            None,
-        ));
-    }
-
     // TODO: complete access checks
 
     let page_access = PageService::update_page_access(
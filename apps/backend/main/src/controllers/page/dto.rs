@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sql::{
@@ -7,17 +9,34 @@ use sql::{
 };
 use uuid::Uuid;
 
-use crate::controllers::board_statuses::dto::BoardStatusResponse;
+use crate::{
+    controllers::{board_statuses::dto::BoardStatusResponse, board_views::dto::BoardViewResponse},
+    repos::tasks::TaskExportRow,
+    shared::validation::{self, Validate, validate_tiptap_content},
+};
 
 // PAGE
 
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePageRequest {
+    /// May be omitted with a template, which then titles the page
+    #[serde(default)]
     pub title: String,
     pub r#type: PageType,
     pub content: Option<TipTapContent>,
     pub parent_page_id: Option<Uuid>,
+    /// Built-in or workspace template filling the page, its type has to match `type`
+    pub template_id: Option<Uuid>,
+}
+
+impl Validate for CreatePageRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_tiptap_content("content", self.content.as_ref(), &mut errors);
+
+        validation::into_result(errors)
+    }
 }
 
 #[derive(Debug, utoipa::ToSchema, Deserialize)]
@@ -28,12 +47,48 @@ pub struct UpdatePageRequest {
     pub content: Option<Option<TipTapContent>>,
 }
 
+impl Validate for UpdatePageRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_tiptap_content(
+            "content",
+            self.content.as_ref().and_then(|c| c.as_ref()),
+            &mut errors,
+        );
+
+        validation::into_result(errors)
+    }
+}
+
+#[derive(Debug, utoipa::ToSchema, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovePageRequest {
+    /// Destination group page, `null` or omitted moves the page to the workspace root.
+    pub parent_page_id: Option<Uuid>,
+    /// 1-based position among the destination siblings. Default: last.
+    pub position: Option<i32>,
+}
+
+impl Validate for MovePageRequest {}
+
+#[derive(Debug, utoipa::ToSchema, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicatePageRequest {
+    /// Title of the copy. Default: the page title followed by `(copy)`
+    pub title: Option<String>,
+    /// Copies the tasks of board pages with their checklists. Default: false
+    pub include_tasks: Option<bool>,
+}
+
+impl Validate for DuplicatePageRequest {}
+
 #[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PageSummary {
     pub id: Uuid,
     pub r#type: PageType,
     pub title: String,
+    pub position: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -46,6 +101,7 @@ impl From<Page> for PageSummary {
             id: page.id,
             r#type: page.r#type,
             title: page.title,
+            position: page.position,
             created_at: page.created_at,
             updated_at: page.updated_at,
             deleted_at: page.deleted_at,
@@ -59,6 +115,7 @@ impl From<&Page> for PageSummary {
             id: page.id,
             r#type: page.r#type.clone(),
             title: page.title.clone(),
+            position: page.position,
             created_at: page.created_at,
             updated_at: page.updated_at,
             deleted_at: page.deleted_at,
@@ -72,6 +129,7 @@ impl From<PageResponse> for PageSummary {
             id: page.id,
             r#type: page.r#type,
             title: page.title,
+            position: page.position,
             created_at: page.created_at,
             updated_at: page.updated_at,
             deleted_at: page.deleted_at,
@@ -85,6 +143,8 @@ pub struct PageResponse {
     pub id: Uuid,
     pub r#type: PageType,
     pub title: String,
+    pub parent_page_id: Option<Uuid>,
+    pub position: i32,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub child_pages: Option<Vec<PageSummary>>,
@@ -101,6 +161,8 @@ impl From<Page> for PageResponse {
             id: page.id,
             r#type: page.r#type,
             title: page.title,
+            parent_page_id: page.parent_page_id,
+            position: page.position,
             child_pages: None,
             created_at: page.created_at,
             updated_at: page.updated_at,
@@ -121,6 +183,91 @@ pub struct PageListQuery {
     pub format: Option<PageListFormat>,
 }
 
+#[derive(Deserialize)]
+pub struct PageTrashQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Deserialize)]
+pub struct PageDetailsQuery {
+    pub view_id: Option<Uuid>,
+    pub descendants: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema, PartialEq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum PageExportFormat {
+    /// Board pages
+    Csv,
+    /// Board pages
+    Json,
+    /// Text pages
+    Md,
+    /// Text pages
+    Html,
+}
+
+#[derive(Deserialize)]
+pub struct PageExportQuery {
+    pub format: PageExportFormat,
+    /// Bundles a text page export with its files into a zip
+    pub zip: Option<bool>,
+}
+
+#[derive(utoipa::ToSchema)]
+#[schema(rename_all = "camelCase")]
+pub struct ImportPageForm {
+    /// Markdown file, or a zip with one Markdown file and the images it references
+    #[allow(dead_code)]
+    #[schema(format = "binary")]
+    pub file: String,
+
+    /// Defaults to the file name
+    #[allow(dead_code)]
+    pub title: Option<String>,
+
+    #[allow(dead_code)]
+    pub parent_page_id: Option<Uuid>,
+}
+
+/// One exported task, also defines the CSV column order.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskExportRecord {
+    pub id: Uuid,
+    pub title: String,
+    pub status: String,
+    pub assignee: Option<String>,
+    pub reporter: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub position: i32,
+    pub description: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<TaskExportRow> for TaskExportRecord {
+    fn from(value: TaskExportRow) -> Self {
+        Self {
+            id: value.id,
+            title: value.title,
+            status: value.status_title,
+            assignee: value.assignee_name,
+            reporter: value.reporter_name,
+            due_date: value.due_date,
+            position: value.position,
+            description: value
+                .description
+                .0
+                .map(|d| d.to_plain_text())
+                .unwrap_or_default(),
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
 // PAGE ACCESS
 
 #[derive(Debug, utoipa::ToSchema, Deserialize)]
@@ -130,6 +277,8 @@ pub struct CreatePageAccessRequest {
     pub role: Role,
 }
 
+impl Validate for CreatePageAccessRequest {}
+
 #[derive(Debug, utoipa::ToSchema, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePageAccessRequest {
@@ -137,6 +286,8 @@ pub struct UpdatePageAccessRequest {
     pub role: Option<Role>,
 }
 
+impl Validate for UpdatePageAccessRequest {}
+
 #[derive(Debug, Serialize, utoipa::ToSchema, Clone)]
 pub struct PageAccessResponse {
     pub id: Uuid,
@@ -154,6 +305,10 @@ pub struct DetailedPageResponseBase {
     pub id: Uuid,
     pub title: String,
     pub user_role: Role,
+    /// Groups above the page the user can access, from the workspace root down to the parent
+    pub ancestors: Vec<PageSummary>,
+    /// Unresolved comment threads of the page
+    pub comment_count: i64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -180,6 +335,9 @@ pub struct TaskSummary {
     pub status_id: Uuid,
 
     pub assignee_id: Option<Uuid>,
+
+    pub checklist_done: i64,
+    pub checklist_total: i64,
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
@@ -190,6 +348,8 @@ pub struct DetailedPageResponseBoard {
     pub statuses: Vec<BoardStatusResponse>,
     pub tasks: Vec<TaskSummary>,
     pub assignees: Vec<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub view: Option<Box<BoardViewResponse>>,
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
@@ -199,6 +359,9 @@ pub struct DetailedPageResponseGroup {
     pub base: DetailedPageResponseBase,
 
     pub child_pages: Vec<PageSummary>,
+    /// Every page below the group, depth first, when requested with `descendants=true`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descendants: Option<Vec<PageResponse>>,
 }
 
 #[derive(Debug, Serialize, utoipa::ToSchema)]
@@ -208,3 +371,13 @@ pub enum DetailedPageResponse {
     Board(DetailedPageResponseBoard),
     Group(DetailedPageResponseGroup),
 }
+
+impl DetailedPageResponse {
+    pub fn base_mut(&mut self) -> &mut DetailedPageResponseBase {
+        match self {
+            Self::Text(page) => &mut page.base,
+            Self::Board(page) => &mut page.base,
+            Self::Group(page) => &mut page.base,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use axum::{
+    Extension,
+    extract::{State, WebSocketUpgrade},
+    response::Response,
+};
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::page::model::{PageAccess, PageType, Role};
+use uuid::Uuid;
+
+use crate::{
+    repos::pages::PageRepository, services::page_collaboration::PageCollaborationService,
+    shared::extractors::path::ValidatedPath, types::app_state::AppState,
+};
+
+/// Limit of a single Yjs message, a client sends its whole document once connected.
+const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/collaboration",
+    operation_id = "collaborate_page",
+    description = "WebSocket speaking the y-websocket protocol (Yjs sync and awareness). \
+        Members and above can edit, other roles only receive updates. \
+        The server merges the updates into its own copy of the document (the `default` \
+        XML fragment, as bound by the TipTap collaboration extension) and saves it as the \
+        page content once changes settle.",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+        ("token" = Option<String>, Query, description = "Access token, browsers cannot set headers on WebSocket requests"),
+    ),
+    responses(
+        (status = 101, description = "Switching to the WebSocket protocol"),
+        (status = 400, description = "Not a text page", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page"],
+)]
+pub async fn collaborate_page(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, ErrorResponse> {
+    let page = PageRepository::get_one_by_id(&state.postgres, page_id).await?;
+
+    if page.r#type != PageType::Text {
+        return Err(ErrorResponse::bad_request(
+            codes::BadRequestErrorCode::InvalidParams,
+            Some(HashMap::from([(
+                "pageId".to_string(),
+                "Only text pages can be edited collaboratively".to_string(),
+            )])),
+            None,
+        ));
+    }
+
+    let user_id = page_access.user_id;
+    let can_edit = page_access.role >= Role::Member;
+
+    Ok(ws
+        .max_message_size(MAX_MESSAGE_SIZE)
+        .on_upgrade(move |socket| {
+            PageCollaborationService::serve(state, socket, page_id, user_id, can_edit)
+        }))
+}
@@ -0,0 +1 @@
+pub mod collaborate_page;
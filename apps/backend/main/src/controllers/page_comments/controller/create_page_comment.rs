@@ -0,0 +1,49 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::{CreatePageCommentRequest, PageCommentResponse},
+    services::{page_collaboration::PageCollaborationService, page_comments::PageCommentService},
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/comments",
+    operation_id = "create_page_comment",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+    ),
+    request_body = CreatePageCommentRequest,
+    responses(
+        (status = 200, description = "Thread started", body = PageCommentResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn create_page_comment(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath(_): ValidatedPath<Uuid>,
+    ValidatedJson(dto): ValidatedJson<CreatePageCommentRequest>,
+) -> Result<Json<PageCommentResponse>, ErrorResponse> {
+    let mut tx = state.postgres.begin().await?;
+
+    let comment = PageCommentService::create(&mut tx, &page_access, dto).await?;
+
+    tx.commit().await?;
+
+    PageCollaborationService::refresh(&state, page_access.page_id).await;
+
+    let authors =
+        PageCommentService::get_authors(&state.postgres, std::slice::from_ref(&comment)).await?;
+
+    Ok(Json(PageCommentResponse::new(comment, &authors)))
+}
@@ -0,0 +1,39 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::PageCommentResponse,
+    services::page_comments::PageCommentService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/pages/{page_id}/comments/{comment_id}",
+    operation_id = "delete_page_comment",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID, the replies of a thread are deleted with it"),
+    ),
+    responses(
+        (status = 200, description = "Comment deleted", body = PageCommentResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn delete_page_comment(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, comment_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageCommentResponse>, ErrorResponse> {
+    let comment = PageCommentService::delete(&state.postgres, &page_access, comment_id).await?;
+    let authors =
+        PageCommentService::get_authors(&state.postgres, std::slice::from_ref(&comment)).await?;
+
+    Ok(Json(PageCommentResponse::new(comment, &authors)))
+}
@@ -0,0 +1,39 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::{PageCommentThreadResponse, PageCommentsQuery},
+    services::page_comments::PageCommentService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/comments",
+    operation_id = "get_page_comments",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("resolved" = Option<bool>, Query, description = "Only resolved or only open threads. Default: all threads"),
+    ),
+    responses(
+        (status = 200, description = "Comment threads of the page, oldest first", body = Vec<PageCommentThreadResponse>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Page not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn get_page_comments(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath(_): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<PageCommentsQuery>,
+) -> Result<Json<Vec<PageCommentThreadResponse>>, ErrorResponse> {
+    PageCommentService::get_threads(&state.postgres, page_access.page_id, query.resolved)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,7 @@
+pub mod create_page_comment;
+pub mod delete_page_comment;
+pub mod get_page_comments;
+pub mod reply_to_page_comment;
+pub mod resolve_page_comment;
+pub mod unresolve_page_comment;
+pub mod update_page_comment;
@@ -0,0 +1,43 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::{CreatePageCommentReplyRequest, PageCommentResponse},
+    services::page_comments::PageCommentService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/comments/{comment_id}/replies",
+    operation_id = "reply_to_page_comment",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("comment_id" = Uuid, Path, description = "First comment of the thread"),
+    ),
+    request_body = CreatePageCommentReplyRequest,
+    responses(
+        (status = 200, description = "Reply added", body = PageCommentResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn reply_to_page_comment(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, comment_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedJson(dto): ValidatedJson<CreatePageCommentReplyRequest>,
+) -> Result<Json<PageCommentResponse>, ErrorResponse> {
+    let reply = PageCommentService::reply(&state.postgres, &page_access, comment_id, dto).await?;
+    let authors =
+        PageCommentService::get_authors(&state.postgres, std::slice::from_ref(&reply)).await?;
+
+    Ok(Json(PageCommentResponse::new(reply, &authors)))
+}
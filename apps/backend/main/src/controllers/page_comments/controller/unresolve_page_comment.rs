@@ -0,0 +1,41 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::PageCommentResponse,
+    services::page_comments::PageCommentService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/comments/{comment_id}/unresolve",
+    operation_id = "unresolve_page_comment",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("comment_id" = Uuid, Path, description = "First comment of the thread"),
+    ),
+    responses(
+        (status = 200, description = "Thread reopened", body = PageCommentResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn unresolve_page_comment(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, comment_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageCommentResponse>, ErrorResponse> {
+    let thread =
+        PageCommentService::set_resolved(&state.postgres, &page_access, comment_id, false).await?;
+    let authors =
+        PageCommentService::get_authors(&state.postgres, std::slice::from_ref(&thread)).await?;
+
+    Ok(Json(PageCommentResponse::new(thread, &authors)))
+}
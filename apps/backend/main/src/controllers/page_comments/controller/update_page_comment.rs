@@ -0,0 +1,44 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::{PageCommentResponse, UpdatePageCommentRequest},
+    services::page_comments::PageCommentService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    put,
+    path = "/pages/{page_id}/comments/{comment_id}",
+    operation_id = "update_page_comment",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("comment_id" = Uuid, Path, description = "Comment ID"),
+    ),
+    request_body = UpdatePageCommentRequest,
+    responses(
+        (status = 200, description = "Comment updated", body = PageCommentResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Comments"],
+)]
+pub async fn update_page_comment(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, comment_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedJson(dto): ValidatedJson<UpdatePageCommentRequest>,
+) -> Result<Json<PageCommentResponse>, ErrorResponse> {
+    let comment =
+        PageCommentService::update(&state.postgres, &page_access, comment_id, dto).await?;
+    let authors =
+        PageCommentService::get_authors(&state.postgres, std::slice::from_ref(&comment)).await?;
+
+    Ok(Json(PageCommentResponse::new(comment, &authors)))
+}
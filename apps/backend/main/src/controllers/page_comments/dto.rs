@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use error_handlers::codes::FieldErrorCode;
+use serde::{Deserialize, Serialize};
+use sql::{page_comments::model::PageComment, user::model::User};
+use uuid::Uuid;
+
+use crate::shared::validation::{self, Validate};
+
+/// Limit of the text of a comment, in characters.
+const MAX_TEXT_LENGTH: usize = 10_000;
+
+fn validate_text(text: &str, errors: &mut HashMap<String, String>) {
+    if text.trim().is_empty() {
+        errors.insert(
+            "text".to_string(),
+            FieldErrorCode::FieldRequired.to_string(),
+        );
+    } else if text.chars().count() > MAX_TEXT_LENGTH {
+        errors.insert("text".to_string(), FieldErrorCode::FieldTooLong.to_string());
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PageCommentsQuery {
+    pub resolved: Option<bool>,
+}
+
+/// Range of the commented text, in ProseMirror positions of the latest content of the page.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCommentAnchor {
+    pub from: usize,
+    pub to: usize,
+}
+
+/// Starts a thread. With an `anchor`, the commented text gets a `comment` mark with the id of
+/// the thread as `commentId` and becomes the quote.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePageCommentRequest {
+    pub text: String,
+    /// The commented text, shown when the anchor was removed from the page
+    pub quote: Option<String>,
+    pub anchor: Option<PageCommentAnchor>,
+}
+
+impl Validate for CreatePageCommentRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_text(&self.text, &mut errors);
+
+        if self
+            .quote
+            .as_ref()
+            .is_some_and(|quote| quote.chars().count() > MAX_TEXT_LENGTH)
+        {
+            errors.insert(
+                "quote".to_string(),
+                FieldErrorCode::FieldTooLong.to_string(),
+            );
+        }
+
+        if self
+            .anchor
+            .as_ref()
+            .is_some_and(|anchor| anchor.from >= anchor.to)
+        {
+            errors.insert(
+                "anchor".to_string(),
+                FieldErrorCode::FieldOutOfRange.to_string(),
+            );
+        }
+
+        validation::into_result(errors)
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePageCommentReplyRequest {
+    pub text: String,
+}
+
+impl Validate for CreatePageCommentReplyRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_text(&self.text, &mut errors);
+
+        validation::into_result(errors)
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePageCommentRequest {
+    pub text: String,
+}
+
+impl Validate for UpdatePageCommentRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_text(&self.text, &mut errors);
+
+        validation::into_result(errors)
+    }
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCommentResponse {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    /// `null` for the first comment of a thread
+    pub parent_comment_id: Option<Uuid>,
+    pub author_id: Uuid,
+    /// Absent when the author could not be loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quote: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PageCommentResponse {
+    pub fn new(comment: PageComment, authors: &HashMap<Uuid, User>) -> Self {
+        Self {
+            id: comment.id,
+            page_id: comment.page_id,
+            parent_comment_id: comment.parent_comment_id,
+            author_id: comment.author_id,
+            author: authors.get(&comment.author_id).cloned(),
+            text: comment.text,
+            quote: comment.quote,
+            resolved_at: comment.resolved_at,
+            resolved_by: comment.resolved_by,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCommentThreadResponse {
+    #[serde(flatten)]
+    pub comment: PageCommentResponse,
+    /// False once the commented text was removed from the page
+    pub is_anchored: bool,
+    /// Oldest first
+    pub replies: Vec<PageCommentResponse>,
+}
@@ -0,0 +1,49 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+
+use crate::{
+    controllers::page_share_links::dto::{
+        CreatePageShareLinkRequest, CreatedPageShareLinkResponse, PageShareLinkResponse,
+    },
+    services::page_share_links::PageShareLinkService,
+    shared::extractors::json::ValidatedJson,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/share-links",
+    operation_id = "create_page_share_link",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+    ),
+    request_body = CreatePageShareLinkRequest,
+    responses(
+        (status = 200, description = "Share link created, the token is only returned here", body = CreatedPageShareLinkResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Share Links"],
+)]
+pub async fn create_page_share_link(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedJson(dto): ValidatedJson<CreatePageShareLinkRequest>,
+) -> Result<Json<CreatedPageShareLinkResponse>, ErrorResponse> {
+    let (link, token) = PageShareLinkService::create(
+        &state.postgres,
+        page_access.page_id,
+        page_access.user_id,
+        dto,
+    )
+    .await?;
+
+    Ok(Json(CreatedPageShareLinkResponse {
+        link: PageShareLinkResponse::from(link),
+        path: format!("/shared/{token}"),
+        token,
+    }))
+}
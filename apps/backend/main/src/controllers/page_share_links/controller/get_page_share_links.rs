@@ -0,0 +1,32 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+
+use crate::{
+    controllers::page_share_links::dto::PageShareLinkResponse,
+    services::page_share_links::PageShareLinkService, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/share-links",
+    operation_id = "get_page_share_links",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+    ),
+    responses(
+        (status = 200, description = "Active share links of the page", body = Vec<PageShareLinkResponse>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Share Links"],
+)]
+pub async fn get_page_share_links(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+) -> Result<Json<Vec<PageShareLinkResponse>>, ErrorResponse> {
+    PageShareLinkService::get_active(&state.postgres, page_access.page_id)
+        .await
+        .map(|links| Json(links.into_iter().map(PageShareLinkResponse::from).collect()))
+}
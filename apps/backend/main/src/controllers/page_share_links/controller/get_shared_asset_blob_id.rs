@@ -0,0 +1,46 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::assets::dto::ValidateAccessResponse,
+    services::page_share_links::PageShareLinkService,
+    shared::extractors::x_share_password::XSharePassword, types::app_state::AppState,
+};
+
+/// Called by the storage service to serve files embedded in a shared page.
+#[utoipa::path(
+    get,
+    path = "/shared/{token}/assets/{asset_id}/blob-id",
+    operation_id = "get_shared_asset_blob_id",
+    params(
+        ("token" = String, Path, description = "Share link token"),
+        ("asset_id" = Uuid, Path, description = "Asset id"),
+        ("x-share-password" = Option<String>, Header, description = "Password of a protected link"),
+    ),
+    responses(
+        (status = 200, description = "Blob id retrieved successfully", body = ValidateAccessResponse),
+        (status = 401, description = "Missing or wrong password, or too many wrong attempts", body = ErrorResponse),
+        (status = 403, description = "Asset is not part of the shared page", body = ErrorResponse),
+        (status = 404, description = "Unknown, expired or revoked link", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Share Links"],
+)]
+pub async fn get_shared_asset_blob_id(
+    State(state): State<AppState>,
+    Path((token, asset_id)): Path<(String, Uuid)>,
+    XSharePassword(password): XSharePassword,
+) -> Result<Json<ValidateAccessResponse>, ErrorResponse> {
+    PageShareLinkService::validate_asset_access(&state.postgres, &token, password, asset_id)
+        .await
+        .map(|asset| {
+            Json(ValidateAccessResponse {
+                blob_id: asset.blob_id,
+                name: asset.name,
+            })
+        })
+}
@@ -0,0 +1,41 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use error_handlers::handlers::ErrorResponse;
+
+use crate::{
+    controllers::page::dto::DetailedPageResponse,
+    services::page_share_links::PageShareLinkService,
+    shared::extractors::{x_share_password::XSharePassword, x_user_language::XUserLanguage},
+    types::app_state::AppState,
+};
+
+/// Unauthenticated, the share token is the credential.
+#[utoipa::path(
+    get,
+    path = "/shared/{token}",
+    operation_id = "get_shared_page",
+    params(
+        ("token" = String, Path, description = "Share link token"),
+        ("x-share-password" = Option<String>, Header, description = "Password of a protected link"),
+    ),
+    responses(
+        (status = 200, description = "Read-only page, user emails are removed", body = DetailedPageResponse),
+        (status = 401, description = "Missing or wrong password, or too many wrong attempts", body = ErrorResponse),
+        (status = 404, description = "Unknown, expired or revoked link", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    security(()),
+    tags = ["Share Links"],
+)]
+pub async fn get_shared_page(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    XSharePassword(password): XSharePassword,
+    XUserLanguage(lang): XUserLanguage,
+) -> Result<Json<DetailedPageResponse>, ErrorResponse> {
+    PageShareLinkService::get_shared_page(&state.postgres, &token, password, lang)
+        .await
+        .map(Json)
+}
@@ -0,0 +1,5 @@
+pub mod create_page_share_link;
+pub mod get_page_share_links;
+pub mod get_shared_asset_blob_id;
+pub mod get_shared_page;
+pub mod revoke_page_share_link;
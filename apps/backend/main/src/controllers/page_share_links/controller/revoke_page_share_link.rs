@@ -0,0 +1,37 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_share_links::dto::PageShareLinkResponse,
+    services::page_share_links::PageShareLinkService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    delete,
+    path = "/pages/{page_id}/share-links/{link_id}",
+    operation_id = "revoke_page_share_link",
+    params(
+        ("page_id" = Uuid, Path, description = "Page ID"),
+        ("link_id" = Uuid, Path, description = "Share link ID"),
+    ),
+    responses(
+        (status = 200, description = "Share link revoked, its token stops working immediately", body = PageShareLinkResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Share Links"],
+)]
+pub async fn revoke_page_share_link(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((_, link_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageShareLinkResponse>, ErrorResponse> {
+    PageShareLinkService::revoke(&state.postgres, page_access.page_id, link_id)
+        .await
+        .map(|l| Json(PageShareLinkResponse::from(l)))
+}
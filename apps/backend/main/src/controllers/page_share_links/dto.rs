@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::page_share_links::model::PageShareLink;
+use uuid::Uuid;
+
+use crate::shared::validation::Validate;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePageShareLinkRequest {
+    /// The link stops working after this date. Default: never
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Visitors have to send it in the `x-share-password` header
+    pub password: Option<String>,
+}
+
+impl Validate for CreatePageShareLinkRequest {}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageShareLinkResponse {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub has_password: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+impl From<PageShareLink> for PageShareLinkResponse {
+    fn from(value: PageShareLink) -> Self {
+        Self {
+            id: value.id,
+            page_id: value.page_id,
+            has_password: value.password_hash.is_some(),
+            expires_at: value.expires_at,
+            created_by: value.created_by,
+            created_at: value.created_at,
+            last_used_at: value.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedPageShareLinkResponse {
+    #[serde(flatten)]
+    pub link: PageShareLinkResponse,
+    /// Shown only once, part of the public page URL
+    pub token: String,
+    pub path: String,
+}
@@ -0,0 +1,37 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::workspace::model::WorkspaceAccess;
+
+use crate::{
+    controllers::page_templates::dto::{CreatePageTemplateRequest, PageTemplateResponse},
+    services::page_templates::PageTemplateService,
+    shared::extractors::json::ValidatedJson,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/workspaces/{workspace_id}/templates",
+    operation_id = "create_page_template",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+    ),
+    request_body = CreatePageTemplateRequest,
+    responses(
+        (status = 200, description = "Page template created", body = PageTemplateResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 422, description = "Validation errors", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Templates"],
+)]
+pub async fn create_page_template(
+    State(state): State<AppState>,
+    Extension(workspace_access): Extension<WorkspaceAccess>,
+    ValidatedJson(dto): ValidatedJson<CreatePageTemplateRequest>,
+) -> Result<Json<PageTemplateResponse>, ErrorResponse> {
+    PageTemplateService::create(&state.postgres, &workspace_access, dto)
+        .await
+        .map(|t| Json(PageTemplateResponse::from(t)))
+}
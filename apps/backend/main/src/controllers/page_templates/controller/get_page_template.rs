@@ -0,0 +1,37 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::workspace::model::WorkspaceAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_templates::dto::PageTemplateResponse,
+    services::page_templates::PageTemplateService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/workspaces/{workspace_id}/templates/{template_id}",
+    operation_id = "get_page_template",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+        ("template_id" = Uuid, Path, description = "Page template ID"),
+    ),
+    responses(
+        (status = 200, description = "Page template", body = PageTemplateResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Templates"],
+)]
+pub async fn get_page_template(
+    State(state): State<AppState>,
+    Extension(workspace_access): Extension<WorkspaceAccess>,
+    ValidatedPath((_, template_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageTemplateResponse>, ErrorResponse> {
+    PageTemplateService::get_one(&state.postgres, workspace_access.workspace_id, template_id)
+        .await
+        .map(|t| Json(PageTemplateResponse::from(t)))
+}
@@ -0,0 +1,39 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::workspace::model::WorkspaceAccess;
+
+use crate::{
+    controllers::page_templates::dto::PageTemplateResponse,
+    services::page_templates::PageTemplateService, types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/workspaces/{workspace_id}/templates",
+    operation_id = "get_page_templates",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+    ),
+    responses(
+        (status = 200, description = "Built-in templates followed by the templates of the workspace", body = Vec<PageTemplateResponse>),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Templates"],
+)]
+pub async fn get_page_templates(
+    State(state): State<AppState>,
+    Extension(workspace_access): Extension<WorkspaceAccess>,
+) -> Result<Json<Vec<PageTemplateResponse>>, ErrorResponse> {
+    PageTemplateService::get_all(&state.postgres, workspace_access.workspace_id)
+        .await
+        .map(|templates| {
+            Json(
+                templates
+                    .into_iter()
+                    .map(PageTemplateResponse::from)
+                    .collect(),
+            )
+        })
+}
@@ -0,0 +1,5 @@
+pub mod create_page_template;
+pub mod delete_page_template;
+pub mod get_page_template;
+pub mod get_page_templates;
+pub mod update_page_template;
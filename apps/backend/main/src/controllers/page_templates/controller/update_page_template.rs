@@ -0,0 +1,41 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::workspace::model::WorkspaceAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_templates::dto::{PageTemplateResponse, UpdatePageTemplateRequest},
+    services::page_templates::PageTemplateService,
+    shared::extractors::{json::ValidatedJson, path::ValidatedPath},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    patch,
+    path = "/workspaces/{workspace_id}/templates/{template_id}",
+    operation_id = "update_page_template",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+        ("template_id" = Uuid, Path, description = "Page template ID"),
+    ),
+    request_body = UpdatePageTemplateRequest,
+    responses(
+        (status = 200, description = "Page template updated", body = PageTemplateResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Built-in template, or not created by the user and not a workspace admin", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+        (status = 422, description = "Validation errors", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Templates"],
+)]
+pub async fn update_page_template(
+    State(state): State<AppState>,
+    Extension(workspace_access): Extension<WorkspaceAccess>,
+    ValidatedPath((_, template_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedJson(dto): ValidatedJson<UpdatePageTemplateRequest>,
+) -> Result<Json<PageTemplateResponse>, ErrorResponse> {
+    PageTemplateService::update(&state.postgres, &workspace_access, template_id, dto)
+        .await
+        .map(|t| Json(PageTemplateResponse::from(t)))
+}
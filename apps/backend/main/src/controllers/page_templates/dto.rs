@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use error_handlers::codes::FieldErrorCode;
+use serde::{Deserialize, Serialize};
+use sql::{
+    page::model::PageType,
+    page_templates::model::{PageTemplate, TemplateBoardStatus},
+    shared::tiptap_content::TipTapContent,
+};
+use uuid::Uuid;
+
+use crate::shared::validation::{self, Validate, validate_tiptap_content};
+
+/// Checks the content and statuses of a template against its page type.
+pub fn validate_template_type(
+    r#type: &PageType,
+    content: Option<&TipTapContent>,
+    statuses: Option<&[TemplateBoardStatus]>,
+    errors: &mut HashMap<String, String>,
+) {
+    if content.is_some() && *r#type != PageType::Text {
+        errors.insert(
+            "content".to_string(),
+            FieldErrorCode::FieldNotAllowed.to_string(),
+        );
+    }
+
+    let Some(statuses) = statuses else {
+        return;
+    };
+
+    if *r#type != PageType::Board {
+        errors.insert(
+            "statuses".to_string(),
+            FieldErrorCode::FieldNotAllowed.to_string(),
+        );
+        return;
+    }
+
+    if statuses.is_empty() {
+        errors.insert(
+            "statuses".to_string(),
+            FieldErrorCode::FieldTooShort.to_string(),
+        );
+    }
+
+    if statuses.iter().filter(|status| status.initial).count() > 1 {
+        errors.insert(
+            "statuses".to_string(),
+            FieldErrorCode::FieldMismatch.to_string(),
+        );
+    }
+
+    for (index, status) in statuses.iter().enumerate() {
+        if status
+            .localizations
+            .values()
+            .all(|title| title.trim().is_empty())
+        {
+            errors.insert(
+                format!("statuses[{index}].localizations"),
+                FieldErrorCode::FieldRequired.to_string(),
+            );
+        }
+    }
+}
+
+fn validate_required(field: &str, value: Option<&str>, errors: &mut HashMap<String, String>) {
+    if value.is_some_and(|value| value.trim().is_empty()) {
+        errors.insert(field.to_string(), FieldErrorCode::FieldRequired.to_string());
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePageTemplateRequest {
+    pub name: String,
+    pub r#type: PageType,
+    /// Title of the created pages, `{{date}}` and `{{user}}` are expanded
+    pub title_pattern: String,
+    /// Text templates only, `{{date}}` and `{{user}}` are expanded in its text
+    pub content: Option<TipTapContent>,
+    /// Board templates only. Default: the statuses of a new board
+    pub statuses: Option<Vec<TemplateBoardStatus>>,
+}
+
+impl Validate for CreatePageTemplateRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_required("name", Some(&self.name), &mut errors);
+        validate_required("titlePattern", Some(&self.title_pattern), &mut errors);
+        validate_tiptap_content("content", self.content.as_ref(), &mut errors);
+        validate_template_type(
+            &self.r#type,
+            self.content.as_ref(),
+            self.statuses.as_deref(),
+            &mut errors,
+        );
+
+        validation::into_result(errors)
+    }
+}
+
+/// The page type of a template cannot be changed.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePageTemplateRequest {
+    pub name: Option<String>,
+    pub title_pattern: Option<String>,
+    pub content: Option<Option<TipTapContent>>,
+    pub statuses: Option<Vec<TemplateBoardStatus>>,
+}
+
+impl Validate for UpdatePageTemplateRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_required("name", self.name.as_deref(), &mut errors);
+        validate_required("titlePattern", self.title_pattern.as_deref(), &mut errors);
+        validate_tiptap_content(
+            "content",
+            self.content.as_ref().and_then(|c| c.as_ref()),
+            &mut errors,
+        );
+
+        validation::into_result(errors)
+    }
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageTemplateResponse {
+    pub id: Uuid,
+    /// `null` for built-in templates
+    pub workspace_id: Option<Uuid>,
+    pub is_built_in: bool,
+    pub name: String,
+    pub r#type: PageType,
+    pub title_pattern: String,
+    pub content: Option<TipTapContent>,
+    pub statuses: Vec<TemplateBoardStatus>,
+    pub created_by: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<PageTemplate> for PageTemplateResponse {
+    fn from(template: PageTemplate) -> Self {
+        let is_built_in = template.workspace_id.is_none();
+
+        Self {
+            id: template.id,
+            workspace_id: template.workspace_id,
+            is_built_in,
+            name: template.name,
+            r#type: template.r#type,
+            title_pattern: template.title_pattern,
+            content: template.content.0,
+            statuses: template.statuses.0,
+            created_by: template.created_by,
+            created_at: (!is_built_in).then_some(template.created_at),
+            updated_at: (!is_built_in).then_some(template.updated_at),
+        }
+    }
+}
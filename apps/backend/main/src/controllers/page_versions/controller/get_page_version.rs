@@ -0,0 +1,36 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_versions::dto::PageVersionResponse,
+    services::page_versions::PageVersionService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/versions/{version_id}",
+    operation_id = "get_page_version",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+        ("version_id" = Uuid, Path, description = "Version ID"),
+    ),
+    responses(
+        (status = 200, description = "Version with its content", body = PageVersionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Version not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Versions"],
+)]
+pub async fn get_page_version(
+    State(state): State<AppState>,
+    ValidatedPath((page_id, version_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageVersionResponse>, ErrorResponse> {
+    PageVersionService::get_one(&state.postgres, page_id, version_id)
+        .await
+        .map(|v| Json(PageVersionResponse::from(v)))
+}
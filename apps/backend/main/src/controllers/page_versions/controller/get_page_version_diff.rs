@@ -0,0 +1,45 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_versions::dto::{PageVersionDiffQuery, PageVersionDiffResponse},
+    services::page_versions::PageVersionService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/versions/{version_id}/diff",
+    operation_id = "get_page_version_diff",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+        ("version_id" = Uuid, Path, description = "Version ID"),
+        ("compare_to" = Option<Uuid>, Query, description = "Version to compare with. Default: current content"),
+    ),
+    responses(
+        (status = 200, description = "Line diff of the page text from the version to the compared content", body = PageVersionDiffResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Version not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Versions"],
+)]
+pub async fn get_page_version_diff(
+    State(state): State<AppState>,
+    ValidatedPath((page_id, version_id)): ValidatedPath<(Uuid, Uuid)>,
+    ValidatedQuery(query): ValidatedQuery<PageVersionDiffQuery>,
+) -> Result<Json<PageVersionDiffResponse>, ErrorResponse> {
+    PageVersionService::diff(&state.postgres, page_id, version_id, query.compare_to)
+        .await
+        .map(|lines| {
+            Json(PageVersionDiffResponse {
+                version_id,
+                compare_to: query.compare_to,
+                lines,
+            })
+        })
+}
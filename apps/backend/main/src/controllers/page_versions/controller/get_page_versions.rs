@@ -0,0 +1,62 @@
+use axum::extract::State;
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_versions::dto::{PageVersionSummaryResponse, PageVersionsQuery},
+    repos::users::UserRepository,
+    services::page_versions::PageVersionService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::{app_state::AppState, pagination::Pagination},
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/versions",
+    operation_id = "get_page_versions",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+        ("limit" = Option<i64>, Query, description = "Count of items to return. Default: 10"),
+        ("offset" = Option<i64>, Query, description = "Start position. Default: 0"),
+    ),
+    responses(
+        (status = 200, description = "Versions of the page, newest first", body = Pagination<PageVersionSummaryResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Versions"],
+)]
+pub async fn get_page_versions(
+    State(state): State<AppState>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<PageVersionsQuery>,
+) -> Result<Pagination<PageVersionSummaryResponse>, ErrorResponse> {
+    let (versions, total) =
+        PageVersionService::get_list(&state.postgres, page_id, query.limit, query.offset).await?;
+
+    let author_ids = versions
+        .iter()
+        .filter_map(|v| v.created_by)
+        .collect::<Vec<_>>();
+
+    let authors = UserRepository::get_users_by_ids(&state.postgres, &author_ids)
+        .await
+        .map_err(ErrorResponse::from)?
+        .into_iter()
+        .map(|u| (u.id, u))
+        .collect();
+
+    Ok(Pagination::new(
+        versions
+            .into_iter()
+            .map(|v| PageVersionSummaryResponse::new(v, &authors))
+            .collect(),
+        total,
+        query.limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+        query
+            .offset
+            .unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+    ))
+}
@@ -0,0 +1,4 @@
+pub mod get_page_version;
+pub mod get_page_version_diff;
+pub mod get_page_versions;
+pub mod restore_page_version;
@@ -0,0 +1,46 @@
+use axum::{Extension, Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::page::model::PageAccess;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_versions::dto::PageVersionResponse,
+    services::{page_collaboration::PageCollaborationService, page_versions::PageVersionService},
+    shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/versions/{version_id}/restore",
+    operation_id = "restore_page_version",
+    params(
+        ("page_id" = Uuid, Path, description = "Text page ID"),
+        ("version_id" = Uuid, Path, description = "Version ID"),
+    ),
+    responses(
+        (status = 200, description = "Content restored, returns the version created by the restore", body = PageVersionResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Version not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Page Versions"],
+)]
+pub async fn restore_page_version(
+    State(state): State<AppState>,
+    Extension(page_access): Extension<PageAccess>,
+    ValidatedPath((page_id, version_id)): ValidatedPath<(Uuid, Uuid)>,
+) -> Result<Json<PageVersionResponse>, ErrorResponse> {
+    let mut tx = state.postgres.begin().await?;
+
+    let version =
+        PageVersionService::restore(&mut tx, page_id, version_id, page_access.user_id).await?;
+
+    tx.commit().await?;
+
+    PageCollaborationService::refresh(&state, page_id).await;
+
+    Ok(Json(PageVersionResponse::from(version)))
+}
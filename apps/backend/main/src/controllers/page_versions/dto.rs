@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::{
+    page_versions::model::{PageVersion, PageVersionSummary},
+    shared::tiptap_content::TipTapContent,
+    user::model::User,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct PageVersionsQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PageVersionDiffQuery {
+    pub compare_to: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageVersionSummaryResponse {
+    pub id: Uuid,
+    /// Absent for the baseline version and for deleted users.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PageVersionSummaryResponse {
+    pub fn new(version: PageVersionSummary, authors: &HashMap<Uuid, User>) -> Self {
+        Self {
+            id: version.id,
+            author: version.created_by.and_then(|id| authors.get(&id).cloned()),
+            created_at: version.created_at,
+            updated_at: version.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageVersionResponse {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub content: Option<TipTapContent>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<PageVersion> for PageVersionResponse {
+    fn from(value: PageVersion) -> Self {
+        Self {
+            id: value.id,
+            page_id: value.page_id,
+            content: value.content.0,
+            created_by: value.created_by,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum PageVersionDiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageVersionDiffLine {
+    pub op: PageVersionDiffOp,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PageVersionDiffResponse {
+    pub version_id: Uuid,
+    /// `None` when compared against the current page content.
+    pub compare_to: Option<Uuid>,
+    pub lines: Vec<PageVersionDiffLine>,
+}
@@ -0,0 +1,59 @@
+use axum::{Extension, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use sql::search::model::SearchHitKind;
+use uuid::Uuid;
+
+use crate::{
+    controllers::search::dto::{SearchHitResponse, SearchQuery},
+    services::search::SearchService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::{app_state::AppState, pagination::Pagination},
+};
+
+#[utoipa::path(
+    get,
+    path = "/workspaces/{workspace_id}/search",
+    operation_id = "search_workspace",
+    params(
+        ("workspace_id" = Uuid, Path, description = "Workspace ID"),
+        ("query" = String, Query, description = "Words to search for, matched by prefix"),
+        ("kinds" = Option<Vec<SearchHitKind>>, Query, explode = false, description = "Kinds of results separated by comma. Default: all"),
+        ("limit" = Option<i64>, Query, description = "Count of items to return. Default: 10"),
+        ("offset" = Option<i64>, Query, description = "Start position. Default: 0"),
+    ),
+    responses(
+        (status = 200, description = "Pages, tasks and chat messages the user can access, best matches first", body = Pagination<SearchHitResponse>),
+        (status = 400, description = "Invalid query parameters", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Search"],
+)]
+pub async fn search_workspace(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath(workspace_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<SearchQuery>,
+) -> Result<Pagination<SearchHitResponse>, ErrorResponse> {
+    SearchService::search(
+        &state.postgres,
+        workspace_id,
+        user_id,
+        &query.query,
+        query.kinds,
+        query.limit,
+        query.offset,
+    )
+    .await
+    .map(|(hits, total)| {
+        Pagination::new(
+            hits.into_iter().map(SearchHitResponse::from).collect(),
+            total,
+            query.limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+            query
+                .offset
+                .unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+        )
+    })
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use sql::search::model::{SearchHit, SearchHitKind};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub query: String,
+    #[serde(
+        default,
+        deserialize_with = "crate::shared::deserialization::deserialize_comma_separated_query_param"
+    )]
+    pub kinds: Option<Vec<SearchHitKind>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHitResponse {
+    pub kind: SearchHitKind,
+    /// Id of the page, task or chat message, depending on `kind`.
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub page_title: String,
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    /// HTML-escaped fragments with the matches wrapped in `<mark>`.
+    pub headline: String,
+    pub rank: f32,
+}
+
+impl From<SearchHit> for SearchHitResponse {
+    fn from(value: SearchHit) -> Self {
+        Self {
+            kind: value.kind,
+            id: value.id,
+            page_id: value.page_id,
+            page_title: value.page_title,
+            task_id: value.task_id,
+            task_title: value.task_title,
+            headline: value.headline,
+            rank: value.rank,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use error_handlers::handlers::ErrorResponse;
+use sql::user::model::User;
+use uuid::Uuid;
+
+use crate::{services::tasks::TaskService, types::app_state::AppState};
+
+#[utoipa::path(
+    get,
+    path = "/tasks/{task_id}/watchers",
+    operation_id = "get_task_watchers",
+    responses(
+        (status = 200, description = "Task watchers retrieved successfully", body = Vec<User>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    tag = "Tasks",
+)]
+pub async fn get_task_watchers(
+    State(state): State<AppState>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<User>>, ErrorResponse> {
+    TaskService::get_watchers(&state.postgres, task_id)
+        .await
+        .map(Json)
+}
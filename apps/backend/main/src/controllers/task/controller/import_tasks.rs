@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use axum::{Extension, Json, extract::Multipart, extract::State};
+use error_handlers::{codes, handlers::ErrorResponse};
+use uuid::Uuid;
+
+use crate::{
+    controllers::task::dto::{
+        ImportTasksForm, ImportTasksQuery, TaskImportMapping, TaskImportResponse,
+    },
+    services::task_import::TaskImportService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::app_state::AppState,
+};
+
+fn bad_request(msg: String) -> ErrorResponse {
+    ErrorResponse::bad_request(codes::BadRequestErrorCode::InvalidBody, None, Some(msg))
+}
+
+#[utoipa::path(
+    post,
+    path = "/pages/{page_id}/tasks/import",
+    operation_id = "import_tasks",
+    request_body(
+        content_type = "multipart/form-data",
+        content = ImportTasksForm,
+    ),
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+        ("dry_run" = Option<bool>, Query, description = "Only validate and preview the rows, defaults to true"),
+    ),
+    responses(
+        (status = 200, description = "Row preview for a dry run, created tasks otherwise", body = TaskImportResponse),
+        (status = 400, description = "Invalid file or column mapping", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 422, description = "Some rows are invalid, nothing was imported", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tag = "Tasks",
+)]
+pub async fn import_tasks(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<ImportTasksQuery>,
+    mut multipart: Multipart,
+) -> Result<Json<TaskImportResponse>, ErrorResponse> {
+    let mut file_bytes: Option<Vec<u8>> = None;
+    let mut mapping: Option<TaskImportMapping> = None;
+
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| bad_request(e.to_string()))?
+    {
+        let field_name = field.name().unwrap_or_default().to_string();
+
+        match field_name.as_str() {
+            "file" => {
+                let bytes = field
+                    .bytes()
+                    .await
+                    .map_err(|e| bad_request(e.to_string()))?;
+                file_bytes = Some(bytes.to_vec());
+            }
+            "mapping" => {
+                let val = field.text().await.map_err(|e| bad_request(e.to_string()))?;
+                mapping = Some(serde_json::from_str(&val).map_err(|e| {
+                    ErrorResponse::bad_request(
+                        codes::BadRequestErrorCode::InvalidBody,
+                        Some(HashMap::from([("mapping".to_string(), e.to_string())])),
+                        None,
+                    )
+                })?);
+            }
+            _ => {}
+        }
+    }
+
+    let file_bytes = file_bytes.ok_or_else(|| bad_request("Missing 'file' field".to_string()))?;
+    let mapping = mapping.ok_or_else(|| bad_request("Missing 'mapping' field".to_string()))?;
+
+    TaskImportService::import_csv(
+        &state.postgres,
+        page_id,
+        user_id,
+        &file_bytes,
+        mapping,
+        query.dry_run.unwrap_or(true),
+    )
+    .await
+    .map(Json)
+}
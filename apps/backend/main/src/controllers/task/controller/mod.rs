@@ -2,5 +2,9 @@ pub mod create_draft_task;
 pub mod create_task;
 pub mod delete_task;
 pub mod get_task;
+pub mod get_task_watchers;
 pub mod get_tasks_in_page;
+pub mod import_tasks;
+pub mod unwatch_task;
 pub mod update_task;
+pub mod watch_task;
@@ -0,0 +1,35 @@
+use axum::{
+    Extension, Json,
+    extract::{Path, State},
+};
+use error_handlers::handlers::ErrorResponse;
+use sql::user::model::User;
+use uuid::Uuid;
+
+use crate::{services::tasks::TaskService, types::app_state::AppState};
+
+#[utoipa::path(
+    delete,
+    path = "/tasks/{task_id}/watchers",
+    operation_id = "unwatch_task",
+    responses(
+        (status = 200, description = "Task unwatched successfully, returns the current watchers", body = Vec<User>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    tag = "Tasks",
+)]
+pub async fn unwatch_task(
+    State(state): State<AppState>,
+    Extension(user_id): Extension<Uuid>,
+    Path(task_id): Path<Uuid>,
+) -> Result<Json<Vec<User>>, ErrorResponse> {
+    TaskService::unwatch(&state.postgres, task_id, user_id).await?;
+
+    TaskService::get_watchers(&state.postgres, task_id)
+        .await
+        .map(Json)
+}
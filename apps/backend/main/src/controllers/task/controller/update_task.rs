@@ -1,6 +1,6 @@
 use crate::{repos::tasks::UpdateTaskDto, services::tasks::TaskService, types::app_state::AppState};
 use axum::{
-    Json,
+    Extension, Json,
     extract::{Path, State},
 };
 use error_handlers::handlers::ErrorResponse;
@@ -25,9 +25,10 @@ use crate::{controllers::task::dto::TaskResponse, shared::extractors::json::Vali
 pub async fn update_task(
     State(state): State<AppState>,
     Path(task_id): Path<Uuid>,
+    Extension(user_id): Extension<Uuid>,
     ValidatedJson(dto): ValidatedJson<UpdateTaskDto>,
 ) -> Result<Json<TaskResponse>, ErrorResponse> {
-    TaskService::update(&state.postgres, task_id, dto)
+    TaskService::update(&state.postgres, task_id, user_id, dto)
         .await
         .map(|t| Json(TaskResponse::from(t)))
 }
@@ -1,9 +1,14 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sql::{shared::tiptap_content::TipTapContent, task::model::Task, user::model::User};
 use uuid::Uuid;
 
-use crate::controllers::board_statuses::dto::BoardStatusResponse;
+use crate::{
+    controllers::board_statuses::dto::BoardStatusResponse,
+    shared::validation::{self, Validate, validate_tiptap_content},
+};
 
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -15,6 +20,15 @@ pub struct CreateTaskRequest {
     pub assignee_id: Option<Uuid>,
 }
 
+impl Validate for CreateTaskRequest {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_tiptap_content("description", self.description.as_ref(), &mut errors);
+
+        validation::into_result(errors)
+    }
+}
+
 #[derive(Debug, Serialize, utoipa::ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TaskResponse {
@@ -59,3 +73,53 @@ impl From<Task> for TaskResponse {
         }
     }
 }
+
+/// CSV header names to read each task field from, only `title` is required.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskImportMapping {
+    pub title: String,
+    pub status: Option<String>,
+    /// Only members of the workspace of the board can be assigned
+    pub assignee_email: Option<String>,
+    pub due_date: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(utoipa::ToSchema)]
+pub struct ImportTasksForm {
+    #[allow(dead_code)]
+    #[schema(format = "binary")]
+    pub file: String,
+
+    /// JSON encoded `TaskImportMapping`
+    #[allow(dead_code)]
+    pub mapping: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportTasksQuery {
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskImportRowPreview {
+    /// Line number in the uploaded file, the header is line 1
+    pub row: usize,
+    pub title: String,
+    pub status_id: Option<Uuid>,
+    pub assignee_id: Option<Uuid>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub has_description: bool,
+    /// Field name to error code, empty when the row is valid
+    pub errors: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskImportResponse {
+    pub dry_run: bool,
+    pub rows: Vec<TaskImportRowPreview>,
+    pub created: Vec<TaskResponse>,
+}
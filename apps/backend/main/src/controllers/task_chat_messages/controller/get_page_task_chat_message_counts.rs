@@ -0,0 +1,34 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::task_chat_messages::dto::TaskChatMessageCountResponse,
+    services::task_chat_messages::TaskChatMessageService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/pages/{page_id}/messages/counts",
+    operation_id = "get_page_task_chat_message_counts",
+    params(
+        ("page_id" = Uuid, Path, description = "Board page ID"),
+    ),
+    responses(
+        (status = 200, description = "Message counts per task, tasks without messages are omitted", body = Vec<TaskChatMessageCountResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Chat"],
+)]
+pub async fn get_page_task_chat_message_counts(
+    State(state): State<AppState>,
+    ValidatedPath(page_id): ValidatedPath<Uuid>,
+) -> Result<Json<Vec<TaskChatMessageCountResponse>>, ErrorResponse> {
+    TaskChatMessageService::get_counts_by_page_id(&state.postgres, page_id)
+        .await
+        .map(|counts| Json(counts.into_iter().map(Into::into).collect()))
+}
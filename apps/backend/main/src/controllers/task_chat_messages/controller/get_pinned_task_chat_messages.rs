@@ -0,0 +1,41 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::task_chat_messages::dto::TaskChatMessageResponse,
+    services::task_chat_messages::TaskChatMessageService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/tasks/{task_id}/messages/pinned",
+    operation_id = "get_pinned_task_chat_messages",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    responses(
+        (status = 200, description = "Pinned messages, newest first", body = Vec<TaskChatMessageResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Task not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Chat"],
+)]
+pub async fn get_pinned_task_chat_messages(
+    State(state): State<AppState>,
+    ValidatedPath(task_id): ValidatedPath<Uuid>,
+) -> Result<Json<Vec<TaskChatMessageResponse>>, ErrorResponse> {
+    let messages = TaskChatMessageService::get_pinned(&state.postgres, task_id).await?;
+    let authors = TaskChatMessageService::get_authors(&state.postgres, &messages).await?;
+
+    Ok(Json(
+        messages
+            .into_iter()
+            .map(|m| TaskChatMessageResponse::new(m, &authors))
+            .collect(),
+    ))
+}
@@ -0,0 +1,35 @@
+use axum::{Json, extract::State};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    controllers::task_chat_messages::dto::TaskChatMessageCountResponse,
+    services::task_chat_messages::TaskChatMessageService, shared::extractors::path::ValidatedPath,
+    types::app_state::AppState,
+};
+
+#[utoipa::path(
+    get,
+    path = "/tasks/{task_id}/messages/count",
+    operation_id = "get_task_chat_message_count",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+    ),
+    responses(
+        (status = 200, description = "Message counts of the task", body = TaskChatMessageCountResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Task not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Chat"],
+)]
+pub async fn get_task_chat_message_count(
+    State(state): State<AppState>,
+    ValidatedPath(task_id): ValidatedPath<Uuid>,
+) -> Result<Json<TaskChatMessageCountResponse>, ErrorResponse> {
+    TaskChatMessageService::get_count(&state.postgres, task_id)
+        .await
+        .map(|count| Json(count.into()))
+}
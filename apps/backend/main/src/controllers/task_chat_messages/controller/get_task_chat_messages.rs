@@ -0,0 +1,67 @@
+use axum::extract::State;
+use error_handlers::handlers::ErrorResponse;
+use sql::shared::types::SortOrder;
+use uuid::Uuid;
+
+use crate::{
+    controllers::task_chat_messages::dto::{TaskChatMessageResponse, TaskChatMessagesQuery},
+    repos::task_chat_messages::TaskChatMessageFilterBy,
+    services::task_chat_messages::TaskChatMessageService,
+    shared::extractors::{path::ValidatedPath, query::ValidatedQuery},
+    types::{app_state::AppState, pagination::Pagination},
+};
+
+#[utoipa::path(
+    get,
+    path = "/tasks/{task_id}/messages",
+    operation_id = "get_task_chat_messages",
+    params(
+        ("task_id" = Uuid, Path, description = "Task ID"),
+        ("limit" = Option<i64>, Query, description = "Count of items to return. Default: 10"),
+        ("offset" = Option<i64>, Query, description = "Start position. Default: 0"),
+        ("query" = Option<String>, Query, description = "Search in message text"),
+        ("sort_order" = Option<SortOrder>, Query, description = "Sort order by creation date. Default: desc"),
+    ),
+    responses(
+        (status = 200, description = "Chat history of the task", body = Pagination<TaskChatMessageResponse>),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 403, description = "Forbidden", body = ErrorResponse),
+        (status = 404, description = "Task not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Task Chat"],
+)]
+pub async fn get_task_chat_messages(
+    State(state): State<AppState>,
+    ValidatedPath(task_id): ValidatedPath<Uuid>,
+    ValidatedQuery(query): ValidatedQuery<TaskChatMessagesQuery>,
+) -> Result<Pagination<TaskChatMessageResponse>, ErrorResponse> {
+    let filter = TaskChatMessageFilterBy {
+        query: query.query.filter(|q| !q.trim().is_empty()),
+        sort_order: query.sort_order,
+    };
+
+    let (messages, total) = TaskChatMessageService::get_all_with_pagination(
+        &state.postgres,
+        task_id,
+        query.limit,
+        query.offset,
+        filter,
+    )
+    .await?;
+
+    let authors = TaskChatMessageService::get_authors(&state.postgres, &messages).await?;
+
+    Ok(Pagination::new(
+        messages
+            .into_iter()
+            .map(|m| TaskChatMessageResponse::new(m, &authors))
+            .collect(),
+        total,
+        query.limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+        query
+            .offset
+            .unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+    ))
+}
@@ -0,0 +1,4 @@
+pub mod get_page_task_chat_message_counts;
+pub mod get_pinned_task_chat_messages;
+pub mod get_task_chat_message_count;
+pub mod get_task_chat_messages;
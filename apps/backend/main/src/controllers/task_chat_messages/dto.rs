@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sql::{
+    shared::types::SortOrder,
+    task_chat_messages::model::{TaskChatMessage, TaskChatMessageCount},
+    user::model::User,
+};
+use uuid::Uuid;
+
+#[derive(Debug, Deserialize)]
+pub struct TaskChatMessagesQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub query: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskChatMessageResponse {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub text: String,
+    pub user_id: Uuid,
+    /// Absent when the author could not be loaded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    pub reply_to: Option<Uuid>,
+    pub pinned_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TaskChatMessageResponse {
+    pub fn new(message: TaskChatMessage, authors: &HashMap<Uuid, User>) -> Self {
+        Self {
+            id: message.id,
+            task_id: message.task_id,
+            text: message.text,
+            user_id: message.user_id,
+            user: authors.get(&message.user_id).cloned(),
+            reply_to: message.reply_to,
+            pinned_by: message.pinned_by,
+            created_at: message.created_at.and_utc(),
+            updated_at: message.updated_at.and_utc(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskChatMessageCountResponse {
+    pub task_id: Uuid,
+    pub total: i64,
+    pub pinned: i64,
+}
+
+impl From<TaskChatMessageCount> for TaskChatMessageCountResponse {
+    fn from(value: TaskChatMessageCount) -> Self {
+        Self {
+            task_id: value.task_id,
+            total: value.total,
+            pinned: value.pinned,
+        }
+    }
+}
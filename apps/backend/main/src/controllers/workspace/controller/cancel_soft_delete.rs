@@ -8,7 +8,7 @@ use error_handlers::handlers::ErrorResponse;
 use crate::services::workspaces::WorkspaceService;
 
 #[utoipa::path(
-    delete,
+    patch,
     path = "/workspaces/{workspace_id}/cancel-soft-delete",
     operation_id = "cancel_soft_delete_workspace",
     params(
@@ -40,17 +40,6 @@ pub async fn create_workspace_access(
     Path(workspace_id): Path<Uuid>,
     ValidatedJson(dto): ValidatedJson<CreateWorkspaceAccessRequest>,
 ) -> Result<Json<WorkspaceAccessResponse>, ErrorResponse> {
-    if user_workspace_access.role < sql::workspace::model::Role::Admin {
-        return Err(ErrorResponse::forbidden(
-            codes::ForbiddenErrorCode::InsufficientPermissions,
-            Some(HashMap::from([(
-                "message".to_string(),
-                "Insufficient permissions".to_string(),
-            )])),
-            None,
-        ));
-    }
-
     if dto.role > sql::workspace::model::Role::Admin
         && user_workspace_access.role < sql::workspace::model::Role::Owner
     {
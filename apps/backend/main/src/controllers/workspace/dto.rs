@@ -7,7 +7,10 @@ use sql::{
 };
 use uuid::Uuid;
 
-use crate::{controllers::page::dto::PageSummary, repos::workspaces::WorkspaceSortBy};
+use crate::{
+    controllers::page::dto::PageSummary, repos::workspaces::WorkspaceSortBy,
+    shared::validation::Validate,
+};
 
 // WORKSPACE
 
@@ -16,6 +19,8 @@ pub struct CreateWorkspaceRequest {
     pub name: String,
 }
 
+impl Validate for CreateWorkspaceRequest {}
+
 #[derive(Debug, Serialize, utoipa::ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspaceResponse {
@@ -79,6 +84,8 @@ pub struct CreateWorkspaceAccessRequest {
     pub role: Role,
 }
 
+impl Validate for CreateWorkspaceAccessRequest {}
+
 #[derive(Debug, serde::Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateWorkspaceAccessRequest {
@@ -86,6 +93,8 @@ pub struct UpdateWorkspaceAccessRequest {
     pub role: Option<Role>,
 }
 
+impl Validate for UpdateWorkspaceAccessRequest {}
+
 #[derive(Debug, serde::Serialize, utoipa::ToSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct WorkspaceAccessResponse {
@@ -1,7 +1,13 @@
-use axum::http::StatusCode;
-use error_handlers::handlers::ErrorResponse;
+use std::collections::HashMap;
+
+use axum::{
+    extract::MatchedPath,
+    http::{Method, StatusCode},
+};
+use error_handlers::{codes, handlers::ErrorResponse};
 
 pub mod page_access_guard;
+pub mod permissions;
 pub mod workspace_access_guard;
 
 pub fn json_error_response(
@@ -15,3 +21,40 @@ pub fn json_error_response(
         .body(axum::body::Body::from(body))
         .unwrap()
 }
+
+/// Whether `role` reaches the minimum role the matrix gives to the matched route of the request.
+/// Routes missing from the matrix are refused.
+fn route_allows<R, F>(
+    req: &axum::http::Request<axum::body::Body>,
+    role: &R,
+    required_role: F,
+) -> bool
+where
+    R: PartialOrd,
+    F: FnOnce(&Method, &str) -> Option<R>,
+{
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str())
+        .unwrap_or_else(|| req.uri().path());
+
+    let Some(required_role) = required_role(req.method(), route) else {
+        tracing::error!("No permission for {} {route}", req.method());
+
+        return false;
+    };
+
+    *role >= required_role
+}
+
+fn insufficient_permissions() -> ErrorResponse {
+    ErrorResponse::forbidden(
+        codes::ForbiddenErrorCode::InsufficientPermissions,
+        Some(HashMap::from([(
+            "message".to_string(),
+            "Insufficient permissions".to_string(),
+        )])),
+        None,
+    )
+}
@@ -5,7 +5,9 @@ use uuid::Uuid;
 
 use crate::{repos::pages::PageRepository, types::app_state::AppState};
 
-use super::json_error_response;
+use super::{
+    insufficient_permissions, json_error_response, permissions::required_page_role, route_allows,
+};
 
 // works only with path `/task/{task_id}/...`
 pub async fn page_access_guard_task_route(
@@ -67,6 +69,8 @@ pub async fn page_access_guard_by_page_route(
     validate_page_access(state, req, page_id, next).await
 }
 
+/// Inserts the effective `PageAccess`, inherited from parent groups or the workspace when there is no explicit grant.
+/// Its role must reach the minimum role of the route, see `permissions::required_page_role`.
 pub async fn validate_page_access(
     state: AppState,
     mut req: axum::http::Request<axum::body::Body>,
@@ -98,6 +102,10 @@ pub async fn validate_page_access(
         );
     };
 
+    if !route_allows(&req, &page_access.role, required_page_role) {
+        return json_error_response(StatusCode::FORBIDDEN, insufficient_permissions());
+    }
+
     req.extensions_mut().insert(page_access);
 
     next.run(req).await
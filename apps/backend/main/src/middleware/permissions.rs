@@ -0,0 +1,329 @@
+//! Minimum role of every route behind the access guards, keyed by method and route template.
+//! Services can require more than the route, e.g. being the author of a comment or the owner
+//! of a board view. Routes missing from a matrix are refused.
+
+use axum::http::Method;
+use sql::{page, workspace};
+
+/// Matrix of the routes behind `page_access_guard`, checked against the effective page role.
+pub fn required_page_role(method: &Method, route: &str) -> Option<page::model::Role> {
+    use page::model::Role::*;
+
+    let role = match (method.as_str(), route) {
+        // Pages
+        ("GET", "/pages/{page_id}") => Guest,
+        ("GET", "/pages/{page_id}/detailed") => Guest,
+        ("GET", "/pages/{page_id}/export") => Guest,
+        ("GET", "/pages/{page_id}/collaboration") => Guest,
+        ("PUT", "/pages/{page_id}") => Member,
+        ("DELETE", "/pages/{page_id}") => Member,
+        ("PUT", "/pages/{page_id}/position") => Member,
+        ("POST", "/pages/{page_id}/duplicate") => Member,
+        ("GET", "/pages/{page_id}/access") => Member,
+        ("POST", "/pages/{page_id}/access") => Admin,
+        ("PUT", "/pages/{page_id}/access") => Admin,
+
+        // Versions
+        ("GET", "/pages/{page_id}/versions") => Guest,
+        ("GET", "/pages/{page_id}/versions/{version_id}") => Guest,
+        ("GET", "/pages/{page_id}/versions/{version_id}/diff") => Guest,
+        ("POST", "/pages/{page_id}/versions/{version_id}/restore") => Member,
+
+        // Share links
+        ("GET", "/pages/{page_id}/share-links") => Admin,
+        ("POST", "/pages/{page_id}/share-links") => Admin,
+        ("DELETE", "/pages/{page_id}/share-links/{link_id}") => Admin,
+
+        // Comments, authors and page admins are checked by the service
+        ("GET", "/pages/{page_id}/comments") => Guest,
+        ("POST", "/pages/{page_id}/comments") => Commentator,
+        ("PUT", "/pages/{page_id}/comments/{comment_id}") => Commentator,
+        ("DELETE", "/pages/{page_id}/comments/{comment_id}") => Commentator,
+        ("POST", "/pages/{page_id}/comments/{comment_id}/replies") => Commentator,
+        ("POST", "/pages/{page_id}/comments/{comment_id}/resolve") => Commentator,
+        ("POST", "/pages/{page_id}/comments/{comment_id}/unresolve") => Commentator,
+
+        // Backlinks
+        ("GET", "/pages/{page_id}/backlinks") => Guest,
+        ("GET", "/tasks/{task_id}/backlinks") => Guest,
+
+        // Boards, private views only need read access, shared ones are checked by the service
+        ("GET", "/pages/{page_id}/board-statuses") => Guest,
+        ("POST", "/pages/{page_id}/board-statuses") => Member,
+        ("GET", "/pages/{page_id}/views") => Guest,
+        ("POST", "/pages/{page_id}/views") => Guest,
+        ("PUT", "/pages/{page_id}/views/{view_id}") => Guest,
+        ("DELETE", "/pages/{page_id}/views/{view_id}") => Guest,
+
+        // Tasks
+        ("GET", "/pages/{page_id}/tasks") => Guest,
+        ("POST", "/pages/{page_id}/tasks") => Member,
+        ("POST", "/pages/{page_id}/tasks/draft") => Member,
+        ("POST", "/pages/{page_id}/tasks/import") => Member,
+        ("GET", "/tasks/{task_id}") => Guest,
+        ("PUT", "/tasks/{task_id}") => Member,
+        ("DELETE", "/tasks/{task_id}") => Member,
+        ("GET", "/tasks/{task_id}/watchers") => Guest,
+        ("POST", "/tasks/{task_id}/watchers") => Guest,
+        ("DELETE", "/tasks/{task_id}/watchers") => Guest,
+
+        // Checklists
+        ("GET", "/tasks/{task_id}/checklist") => Guest,
+        ("POST", "/tasks/{task_id}/checklist") => Member,
+        ("PUT", "/tasks/{task_id}/checklist/{item_id}") => Member,
+        ("DELETE", "/tasks/{task_id}/checklist/{item_id}") => Member,
+        ("PUT", "/tasks/{task_id}/checklist/{item_id}/position") => Member,
+
+        // Task chats, messages are written through the socket service
+        ("GET", "/pages/{page_id}/messages/counts") => Guest,
+        ("GET", "/tasks/{task_id}/messages") => Guest,
+        ("GET", "/tasks/{task_id}/messages/pinned") => Guest,
+        ("GET", "/tasks/{task_id}/messages/count") => Guest,
+
+        _ => return None,
+    };
+
+    Some(role)
+}
+
+/// Matrix of the routes behind `workspace_access_guard`, checked against the workspace role.
+pub fn required_workspace_role(method: &Method, route: &str) -> Option<workspace::model::Role> {
+    use workspace::model::Role::*;
+
+    let role = match (method.as_str(), route) {
+        // Workspaces
+        ("GET", "/workspaces/{workspace_id}") => Guest,
+        ("GET", "/workspaces/{workspace_id}/detailed") => Guest,
+        ("PUT", "/workspaces/{workspace_id}") => Admin,
+        ("DELETE", "/workspaces/{workspace_id}") => Owner,
+        ("PATCH", "/workspaces/{workspace_id}/cancel-soft-delete") => Owner,
+        ("GET", "/workspaces/{workspace_id}/access") => Member,
+        ("POST", "/workspaces/{workspace_id}/access") => Admin,
+        ("PUT", "/workspaces/{workspace_id}/access") => Admin,
+        ("GET", "/workspaces/{workspace_id}/search") => Guest,
+
+        // Pages, child pages also need the Member role on their parent
+        ("GET", "/workspaces/{workspace_id}/pages") => Guest,
+        ("POST", "/workspaces/{workspace_id}/pages") => Member,
+        ("POST", "/workspaces/{workspace_id}/pages/import") => Member,
+        ("GET", "/workspaces/{workspace_id}/trash") => Member,
+        ("POST", "/workspaces/{workspace_id}/trash/{page_id}/restore") => Member,
+
+        // Templates, creators and admins are checked by the service
+        ("GET", "/workspaces/{workspace_id}/templates") => Guest,
+        ("GET", "/workspaces/{workspace_id}/templates/{template_id}") => Guest,
+        ("POST", "/workspaces/{workspace_id}/templates") => Member,
+        ("PATCH", "/workspaces/{workspace_id}/templates/{template_id}") => Member,
+        ("DELETE", "/workspaces/{workspace_id}/templates/{template_id}") => Member,
+
+        _ => return None,
+    };
+
+    Some(role)
+}
+
+#[cfg(test)]
+mod tests {
+    use utoipa::OpenApi;
+
+    use super::*;
+    use crate::swagger::ApiDoc;
+
+    fn method(method: &str) -> Method {
+        Method::from_bytes(method.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_page_routes() {
+        use page::model::Role::*;
+
+        for (method_name, route, role) in [
+            ("GET", "/pages/{page_id}", Guest),
+            ("GET", "/pages/{page_id}/detailed", Guest),
+            ("GET", "/pages/{page_id}/export", Guest),
+            ("GET", "/pages/{page_id}/collaboration", Guest),
+            ("PUT", "/pages/{page_id}", Member),
+            ("DELETE", "/pages/{page_id}", Member),
+            ("PUT", "/pages/{page_id}/position", Member),
+            ("POST", "/pages/{page_id}/duplicate", Member),
+            ("GET", "/pages/{page_id}/access", Member),
+            ("POST", "/pages/{page_id}/access", Admin),
+            ("PUT", "/pages/{page_id}/access", Admin),
+            ("GET", "/pages/{page_id}/versions", Guest),
+            ("GET", "/pages/{page_id}/versions/{version_id}", Guest),
+            ("GET", "/pages/{page_id}/versions/{version_id}/diff", Guest),
+            (
+                "POST",
+                "/pages/{page_id}/versions/{version_id}/restore",
+                Member,
+            ),
+            ("GET", "/pages/{page_id}/share-links", Admin),
+            ("POST", "/pages/{page_id}/share-links", Admin),
+            ("DELETE", "/pages/{page_id}/share-links/{link_id}", Admin),
+            ("GET", "/pages/{page_id}/comments", Guest),
+            ("POST", "/pages/{page_id}/comments", Commentator),
+            ("PUT", "/pages/{page_id}/comments/{comment_id}", Commentator),
+            (
+                "DELETE",
+                "/pages/{page_id}/comments/{comment_id}",
+                Commentator,
+            ),
+            (
+                "POST",
+                "/pages/{page_id}/comments/{comment_id}/replies",
+                Commentator,
+            ),
+            (
+                "POST",
+                "/pages/{page_id}/comments/{comment_id}/resolve",
+                Commentator,
+            ),
+            (
+                "POST",
+                "/pages/{page_id}/comments/{comment_id}/unresolve",
+                Commentator,
+            ),
+            ("GET", "/pages/{page_id}/backlinks", Guest),
+            ("GET", "/tasks/{task_id}/backlinks", Guest),
+            ("GET", "/pages/{page_id}/board-statuses", Guest),
+            ("POST", "/pages/{page_id}/board-statuses", Member),
+            ("GET", "/pages/{page_id}/views", Guest),
+            ("POST", "/pages/{page_id}/views", Guest),
+            ("PUT", "/pages/{page_id}/views/{view_id}", Guest),
+            ("DELETE", "/pages/{page_id}/views/{view_id}", Guest),
+            ("GET", "/pages/{page_id}/tasks", Guest),
+            ("POST", "/pages/{page_id}/tasks", Member),
+            ("POST", "/pages/{page_id}/tasks/draft", Member),
+            ("POST", "/pages/{page_id}/tasks/import", Member),
+            ("GET", "/tasks/{task_id}", Guest),
+            ("PUT", "/tasks/{task_id}", Member),
+            ("DELETE", "/tasks/{task_id}", Member),
+            ("GET", "/tasks/{task_id}/watchers", Guest),
+            ("POST", "/tasks/{task_id}/watchers", Guest),
+            ("DELETE", "/tasks/{task_id}/watchers", Guest),
+            ("GET", "/tasks/{task_id}/checklist", Guest),
+            ("POST", "/tasks/{task_id}/checklist", Member),
+            ("PUT", "/tasks/{task_id}/checklist/{item_id}", Member),
+            ("DELETE", "/tasks/{task_id}/checklist/{item_id}", Member),
+            (
+                "PUT",
+                "/tasks/{task_id}/checklist/{item_id}/position",
+                Member,
+            ),
+            ("GET", "/pages/{page_id}/messages/counts", Guest),
+            ("GET", "/tasks/{task_id}/messages", Guest),
+            ("GET", "/tasks/{task_id}/messages/pinned", Guest),
+            ("GET", "/tasks/{task_id}/messages/count", Guest),
+        ] {
+            assert_eq!(
+                required_page_role(&method(method_name), route),
+                Some(role),
+                "{method_name} {route}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_workspace_routes() {
+        use workspace::model::Role::*;
+
+        for (method_name, route, role) in [
+            ("GET", "/workspaces/{workspace_id}", Guest),
+            ("GET", "/workspaces/{workspace_id}/detailed", Guest),
+            ("PUT", "/workspaces/{workspace_id}", Admin),
+            ("DELETE", "/workspaces/{workspace_id}", Owner),
+            (
+                "PATCH",
+                "/workspaces/{workspace_id}/cancel-soft-delete",
+                Owner,
+            ),
+            ("GET", "/workspaces/{workspace_id}/access", Member),
+            ("POST", "/workspaces/{workspace_id}/access", Admin),
+            ("PUT", "/workspaces/{workspace_id}/access", Admin),
+            ("GET", "/workspaces/{workspace_id}/search", Guest),
+            ("GET", "/workspaces/{workspace_id}/pages", Guest),
+            ("POST", "/workspaces/{workspace_id}/pages", Member),
+            ("POST", "/workspaces/{workspace_id}/pages/import", Member),
+            ("GET", "/workspaces/{workspace_id}/trash", Member),
+            (
+                "POST",
+                "/workspaces/{workspace_id}/trash/{page_id}/restore",
+                Member,
+            ),
+            ("GET", "/workspaces/{workspace_id}/templates", Guest),
+            (
+                "GET",
+                "/workspaces/{workspace_id}/templates/{template_id}",
+                Guest,
+            ),
+            ("POST", "/workspaces/{workspace_id}/templates", Member),
+            (
+                "PATCH",
+                "/workspaces/{workspace_id}/templates/{template_id}",
+                Member,
+            ),
+            (
+                "DELETE",
+                "/workspaces/{workspace_id}/templates/{template_id}",
+                Member,
+            ),
+        ] {
+            assert_eq!(
+                required_workspace_role(&method(method_name), route),
+                Some(role),
+                "{method_name} {route}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_routes_are_refused() {
+        for (method_name, route) in [
+            ("PATCH", "/pages/{page_id}"),
+            ("GET", "/pages/{page_id}/unknown"),
+            ("GET", "/pages/00000000-0000-0000-0000-000000000000"),
+            ("DELETE", "/tasks/{task_id}/messages"),
+        ] {
+            assert_eq!(required_page_role(&method(method_name), route), None);
+        }
+
+        for (method_name, route) in [
+            ("POST", "/workspaces/{workspace_id}"),
+            ("GET", "/workspaces/{workspace_id}/unknown"),
+            ("GET", "/pages/{page_id}"),
+        ] {
+            assert_eq!(required_workspace_role(&method(method_name), route), None);
+        }
+    }
+
+    /// Every documented route behind the guards has an entry, so a new handler is not refused by default.
+    #[test]
+    fn test_documented_routes_are_listed() {
+        let paths = ApiDoc::openapi().paths.paths;
+
+        for (route, item) in paths {
+            let operations = [
+                ("GET", item.get.is_some()),
+                ("POST", item.post.is_some()),
+                ("PUT", item.put.is_some()),
+                ("PATCH", item.patch.is_some()),
+                ("DELETE", item.delete.is_some()),
+            ];
+
+            for (method_name, _) in operations.iter().filter(|(_, exists)| *exists) {
+                if route.starts_with("/pages/{page_id}") || route.starts_with("/tasks/{task_id}") {
+                    assert!(
+                        required_page_role(&method(method_name), &route).is_some(),
+                        "{method_name} {route}"
+                    );
+                }
+                if route.starts_with("/workspaces/{workspace_id}") {
+                    assert!(
+                        required_workspace_role(&method(method_name), &route).is_some(),
+                        "{method_name} {route}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -5,9 +5,13 @@ use uuid::Uuid;
 
 use crate::{repos::workspaces::WorkspaceRepository, types::app_state::AppState};
 
-use super::json_error_response;
+use super::{
+    insufficient_permissions, json_error_response, permissions::required_workspace_role,
+    route_allows,
+};
 
 /// works only with path `/workspace/{workspace_id}/...`
+/// The workspace role must reach the minimum role of the route, see `permissions::required_workspace_role`.
 pub async fn workspace_access_guard(
     State(state): State<AppState>,
     mut req: axum::http::Request<axum::body::Body>,
@@ -51,6 +55,10 @@ pub async fn workspace_access_guard(
         );
     };
 
+    if !route_allows(&req, &workspace_access.role, required_workspace_role) {
+        return json_error_response(StatusCode::FORBIDDEN, insufficient_permissions());
+    }
+
     req.extensions_mut().insert(workspace_access);
 
     next.run(req).await
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use sqlx::Postgres;
 use uuid::Uuid;
 
@@ -82,6 +83,82 @@ impl AssetsRepository {
             .await
     }
 
+    pub async fn get_by_entity<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        entity_type: EntityType,
+        entity_id: Uuid,
+    ) -> Result<Vec<Asset>, sqlx::Error> {
+        sqlx::query_as::<_, Asset>(
+            "SELECT * FROM assets WHERE entity_type = $1 AND entity_id = $2 ORDER BY created_at",
+        )
+        .bind(entity_type)
+        .bind(entity_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Assets of an entity type created before `created_before`, ordered by entity.
+    pub async fn get_by_entity_type_created_before<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        entity_type: EntityType,
+        created_before: DateTime<Utc>,
+    ) -> Result<Vec<Asset>, sqlx::Error> {
+        sqlx::query_as::<_, Asset>(
+            "SELECT * FROM assets WHERE entity_type = $1 AND created_at < $2 ORDER BY entity_id",
+        )
+        .bind(entity_type)
+        .bind(created_before)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Deletes the given page and task assets unless their document, a version of their page
+    /// or a pending collaborative update of it still refers to them, checked at delete time.
+    pub async fn delete_unreferenced<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        ids: &[Uuid],
+    ) -> Result<u64, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+        DELETE FROM assets
+        WHERE id = ANY($1)
+            AND NOT (entity_type = 'page_text' AND (
+                EXISTS (
+                    SELECT 1 FROM text_page_contents
+                    WHERE page_id = assets.entity_id
+                        AND jsonb_path_exists(content, 'strict $.**.id ? (@ == $id)', jsonb_build_object('id', assets.id))
+                )
+                OR EXISTS (
+                    SELECT 1 FROM page_versions
+                    WHERE page_id = assets.entity_id
+                        AND jsonb_path_exists(content, 'strict $.**.id ? (@ == $id)', jsonb_build_object('id', assets.id))
+                )
+                OR EXISTS (SELECT 1 FROM text_page_updates WHERE page_id = assets.entity_id)
+            ))
+            AND NOT (entity_type = 'task_description' AND EXISTS (
+                SELECT 1 FROM tasks
+                WHERE id = assets.entity_id
+                    AND jsonb_path_exists(description, 'strict $.**.id ? (@ == $id)', jsonb_build_object('id', assets.id))
+            ))
+        "#,
+        )
+        .bind(ids)
+        .execute(executor)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    pub async fn get_existing_ids<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        ids: &[Uuid],
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar::<_, Uuid>("SELECT id FROM assets WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(executor)
+            .await
+    }
+
     pub async fn get_existing_blobs<'a>(
         executor: impl sqlx::Executor<'a, Database = Postgres>,
         blob_ids: &[String],
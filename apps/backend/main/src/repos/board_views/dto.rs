@@ -0,0 +1,39 @@
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use sql::{
+    board_views::model::{BoardView, BoardViewFilters, BoardViewGroupBy, BoardViewSort},
+    shared::traits::UpdateDto,
+};
+
+#[derive(Debug)]
+pub struct CreateBoardViewDto {
+    pub page_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub is_shared: bool,
+    pub filters: Json<BoardViewFilters>,
+    pub sort: Json<BoardViewSort>,
+    pub group_by: BoardViewGroupBy,
+}
+
+#[derive(Debug)]
+pub struct UpdateBoardViewDto {
+    pub name: Option<String>,
+    pub is_shared: Option<bool>,
+    pub filters: Option<Json<BoardViewFilters>>,
+    pub sort: Option<Json<BoardViewSort>>,
+    pub group_by: Option<BoardViewGroupBy>,
+}
+
+impl UpdateDto for UpdateBoardViewDto {
+    type Model = BoardView;
+
+    fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.is_shared.is_none()
+            && self.filters.is_none()
+            && self.sort.is_none()
+            && self.group_by.is_none()
+    }
+}
@@ -0,0 +1,5 @@
+pub mod dto;
+pub mod repository;
+
+pub use dto::*;
+pub use repository::BoardViewRepository;
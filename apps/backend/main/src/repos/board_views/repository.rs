@@ -0,0 +1,121 @@
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use sql::{board_views::model::BoardView, shared::traits::UpdateDto};
+
+use super::dto::{CreateBoardViewDto, UpdateBoardViewDto};
+
+pub struct BoardViewRepository;
+
+impl BoardViewRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreateBoardViewDto,
+    ) -> Result<BoardView, sqlx::Error> {
+        sqlx::query_as::<_, BoardView>(
+            r#"
+            INSERT INTO board_views (page_id, owner_id, name, is_shared, filters, sort, group_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(dto.page_id)
+        .bind(dto.owner_id)
+        .bind(dto.name)
+        .bind(dto.is_shared)
+        .bind(dto.filters)
+        .bind(dto.sort)
+        .bind(dto.group_by)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        dto: UpdateBoardViewDto,
+    ) -> Result<BoardView, sqlx::Error> {
+        if dto.is_empty() {
+            return Self::get_one_by_id(executor, id).await;
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new("UPDATE board_views SET ");
+
+        let mut separated = query_builder.separated(", ");
+
+        if let Some(name) = dto.name {
+            separated.push("name = ").push_bind_unseparated(name);
+        }
+
+        if let Some(is_shared) = dto.is_shared {
+            separated
+                .push("is_shared = ")
+                .push_bind_unseparated(is_shared);
+        }
+
+        if let Some(filters) = dto.filters {
+            separated.push("filters = ").push_bind_unseparated(filters);
+        }
+
+        if let Some(sort) = dto.sort {
+            separated.push("sort = ").push_bind_unseparated(sort);
+        }
+
+        if let Some(group_by) = dto.group_by {
+            separated
+                .push("group_by = ")
+                .push_bind_unseparated(group_by);
+        }
+
+        separated
+            .push("updated_at = ")
+            .push_bind_unseparated(chrono::Utc::now());
+
+        query_builder
+            .push(" WHERE id = ")
+            .push_bind(id)
+            .push(" RETURNING *")
+            .build_query_as::<BoardView>()
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn delete<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<BoardView, sqlx::Error> {
+        sqlx::query_as::<_, BoardView>("DELETE FROM board_views WHERE id = $1 RETURNING *")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn get_one_by_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<BoardView, sqlx::Error> {
+        sqlx::query_as::<_, BoardView>("SELECT * FROM board_views WHERE id = $1")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    /// Shared views of the page plus the private views owned by `user_id`.
+    pub async fn get_visible_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<BoardView>, sqlx::Error> {
+        sqlx::query_as::<_, BoardView>(
+            r#"
+            SELECT * FROM board_views
+            WHERE page_id = $1 AND (is_shared = true OR owner_id = $2)
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(page_id)
+        .bind(user_id)
+        .fetch_all(executor)
+        .await
+    }
+}
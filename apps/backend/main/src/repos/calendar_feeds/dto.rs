@@ -0,0 +1,11 @@
+use sql::calendar_feeds::model::CalendarFeedScope;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CreateCalendarFeedDto {
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub scope: CalendarFeedScope,
+    pub workspace_id: Option<Uuid>,
+    pub page_id: Option<Uuid>,
+}
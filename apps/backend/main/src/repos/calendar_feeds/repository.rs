@@ -0,0 +1,161 @@
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use sql::calendar_feeds::model::{CalendarFeed, CalendarTask};
+
+use super::dto::CreateCalendarFeedDto;
+
+/// Shared part of the feed task queries, binds the user as `$1` and the status language as `$2`.
+/// Tasks of pages the user can no longer access are never published.
+const CALENDAR_TASKS_QUERY: &str = r#"
+    SELECT t.id, t.title, t.due_date, t.updated_at,
+        p.title AS page_title,
+        COALESCE(s.localizations->>$2, s.localizations->>'en', '') AS status_title
+    FROM tasks t
+    JOIN pages p ON p.id = t.page_id AND p.deleted_at IS NULL
+    JOIN board_statuses s ON s.id = t.status_id
+    CROSS JOIN LATERAL effective_page_access($1, p.id) pa
+    WHERE t.deleted_at IS NULL AND t.is_draft = false AND t.due_date IS NOT NULL
+"#;
+
+pub struct CalendarFeedRepository;
+
+impl CalendarFeedRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreateCalendarFeedDto,
+    ) -> Result<CalendarFeed, sqlx::Error> {
+        sqlx::query_as::<_, CalendarFeed>(
+            r#"
+            INSERT INTO calendar_feeds (user_id, token_hash, scope, workspace_id, page_id)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(dto.user_id)
+        .bind(dto.token_hash)
+        .bind(dto.scope)
+        .bind(dto.workspace_id)
+        .bind(dto.page_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn revoke<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<CalendarFeed, sqlx::Error> {
+        sqlx::query_as::<_, CalendarFeed>(
+            r#"
+            UPDATE calendar_feeds SET revoked_at = NOW()
+            WHERE id = $1 AND user_id = $2 AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn touch<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE calendar_feeds SET last_used_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(executor)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn get_active_by_token_hash<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        token_hash: &str,
+    ) -> Result<CalendarFeed, sqlx::Error> {
+        sqlx::query_as::<_, CalendarFeed>(
+            "SELECT * FROM calendar_feeds WHERE token_hash = $1 AND revoked_at IS NULL",
+        )
+        .bind(token_hash)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_active_by_user_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        user_id: Uuid,
+    ) -> Result<Vec<CalendarFeed>, sqlx::Error> {
+        sqlx::query_as::<_, CalendarFeed>(
+            r#"
+            SELECT * FROM calendar_feeds
+            WHERE user_id = $1 AND revoked_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_user_tasks<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        user_id: Uuid,
+        lang: &str,
+    ) -> Result<Vec<CalendarTask>, sqlx::Error> {
+        sqlx::query_as::<_, CalendarTask>(&format!(
+            r#"
+            {CALENDAR_TASKS_QUERY}
+            AND (
+                t.assignee_id = $1
+                OR EXISTS (SELECT 1 FROM task_watchers w WHERE w.task_id = t.id AND w.user_id = $1)
+            )
+            ORDER BY t.due_date ASC
+            "#
+        ))
+        .bind(user_id)
+        .bind(lang)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_workspace_tasks<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        user_id: Uuid,
+        lang: &str,
+        workspace_id: Uuid,
+    ) -> Result<Vec<CalendarTask>, sqlx::Error> {
+        sqlx::query_as::<_, CalendarTask>(&format!(
+            r#"
+            {CALENDAR_TASKS_QUERY}
+            AND p.workspace_id = $3
+            ORDER BY t.due_date ASC
+            "#
+        ))
+        .bind(user_id)
+        .bind(lang)
+        .bind(workspace_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_board_tasks<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        user_id: Uuid,
+        lang: &str,
+        page_id: Uuid,
+    ) -> Result<Vec<CalendarTask>, sqlx::Error> {
+        sqlx::query_as::<_, CalendarTask>(&format!(
+            r#"
+            {CALENDAR_TASKS_QUERY}
+            AND p.id = $3
+            ORDER BY t.due_date ASC
+            "#
+        ))
+        .bind(user_id)
+        .bind(lang)
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+}
@@ -0,0 +1,29 @@
+use uuid::Uuid;
+
+use sql::{checklist_items::model::ChecklistItem, shared::traits::UpdateDto};
+
+#[derive(Debug)]
+pub struct CreateChecklistItemDto {
+    pub task_id: Uuid,
+    pub text: String,
+    pub assignee_id: Option<Uuid>,
+}
+
+#[derive(Debug)]
+pub struct UpdateChecklistItemDto {
+    pub text: Option<String>,
+    pub is_done: Option<bool>,
+    pub assignee_id: Option<Option<Uuid>>,
+    pub position: Option<i32>,
+}
+
+impl UpdateDto for UpdateChecklistItemDto {
+    type Model = ChecklistItem;
+
+    fn is_empty(&self) -> bool {
+        self.text.is_none()
+            && self.is_done.is_none()
+            && self.assignee_id.is_none()
+            && self.position.is_none()
+    }
+}
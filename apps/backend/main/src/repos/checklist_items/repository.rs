@@ -0,0 +1,169 @@
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use sql::{
+    checklist_items::model::{ChecklistItem, ChecklistProgress},
+    shared::{traits::UpdateDto, types::ShiftAction},
+};
+
+use super::dto::{CreateChecklistItemDto, UpdateChecklistItemDto};
+
+pub struct ChecklistItemRepository;
+
+impl ChecklistItemRepository {
+    /// Appends the item after the last one of the task.
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreateChecklistItemDto,
+    ) -> Result<ChecklistItem, sqlx::Error> {
+        sqlx::query_as::<_, ChecklistItem>(
+            r#"
+            INSERT INTO task_checklist_items (task_id, text, assignee_id, position)
+            SELECT $1, $2, $3, COALESCE(MAX(position), 0) + 1
+            FROM task_checklist_items
+            WHERE task_id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(dto.task_id)
+        .bind(dto.text)
+        .bind(dto.assignee_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        dto: UpdateChecklistItemDto,
+    ) -> Result<ChecklistItem, sqlx::Error> {
+        if dto.is_empty() {
+            return Self::get_one_by_id(executor, id).await;
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new("UPDATE task_checklist_items SET ");
+
+        let mut separated = query_builder.separated(", ");
+
+        if let Some(text) = dto.text {
+            separated.push("text = ").push_bind_unseparated(text);
+        }
+
+        if let Some(is_done) = dto.is_done {
+            separated.push("is_done = ").push_bind_unseparated(is_done);
+        }
+
+        if let Some(assignee_id) = dto.assignee_id {
+            separated
+                .push("assignee_id = ")
+                .push_bind_unseparated(assignee_id);
+        }
+
+        if let Some(position) = dto.position {
+            separated
+                .push("position = ")
+                .push_bind_unseparated(position);
+        }
+
+        separated
+            .push("updated_at = ")
+            .push_bind_unseparated(chrono::Utc::now());
+
+        query_builder
+            .push(" WHERE id = ")
+            .push_bind(id)
+            .push(" RETURNING *")
+            .build_query_as::<ChecklistItem>()
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn delete<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<ChecklistItem, sqlx::Error> {
+        sqlx::query_as::<_, ChecklistItem>(
+            "DELETE FROM task_checklist_items WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_one_by_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<ChecklistItem, sqlx::Error> {
+        sqlx::query_as::<_, ChecklistItem>("SELECT * FROM task_checklist_items WHERE id = $1")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn get_by_task_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, sqlx::Error> {
+        sqlx::query_as::<_, ChecklistItem>(
+            "SELECT * FROM task_checklist_items WHERE task_id = $1 ORDER BY position ASC",
+        )
+        .bind(task_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Tasks without checklist items are omitted from the result.
+    pub async fn get_progress_by_task_ids<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_ids: &[Uuid],
+    ) -> Result<Vec<ChecklistProgress>, sqlx::Error> {
+        sqlx::query_as::<_, ChecklistProgress>(
+            r#"
+            SELECT task_id, COUNT(*) FILTER (WHERE is_done) AS done, COUNT(*) AS total
+            FROM task_checklist_items
+            WHERE task_id = ANY($1)
+            GROUP BY task_id
+            "#,
+        )
+        .bind(task_ids)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn get_last_position<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        sqlx::query_scalar::<_, Option<i32>>(
+            "SELECT MAX(position) FROM task_checklist_items WHERE task_id = $1",
+        )
+        .bind(task_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn shift_items_position<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+        start_position: Option<i32>,
+        end_position: Option<i32>,
+        action: ShiftAction,
+    ) -> Result<(), sqlx::Error> {
+        let mut query = sqlx::query_builder::QueryBuilder::new(
+            "UPDATE task_checklist_items SET position = position ",
+        );
+
+        query
+            .push(action.to_string())
+            .push(" 1 WHERE task_id = ")
+            .push_bind(task_id)
+            .push(" AND position >= ")
+            .push_bind(start_position.unwrap_or(1));
+
+        if let Some(end_position) = end_position {
+            query.push(" AND position <= ").push_bind(end_position);
+        }
+
+        query.build().execute(executor).await.map(|_| ())
+    }
+}
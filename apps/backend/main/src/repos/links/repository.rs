@@ -0,0 +1,74 @@
+use sql::{links::model::Backlink, shared::tiptap_content::MentionKind};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub struct LinkRepository;
+
+impl LinkRepository {
+    /// Replaces the outgoing links of one page content or task description with `targets`.
+    pub async fn sync<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        task_id: Option<Uuid>,
+        targets: &[(MentionKind, Uuid)],
+    ) -> Result<(), sqlx::Error> {
+        let kinds = targets
+            .iter()
+            .map(|(kind, _)| kind.as_str())
+            .collect::<Vec<_>>();
+        let ids = targets.iter().map(|(_, id)| *id).collect::<Vec<_>>();
+
+        sqlx::query(
+            r#"
+            WITH targets AS (
+                SELECT * FROM UNNEST($3::text[], $4::uuid[]) AS t (target_kind, target_id)
+            ),
+            removed AS (
+                DELETE FROM links l
+                WHERE l.page_id = $1
+                  AND l.task_id IS NOT DISTINCT FROM $2
+                  AND (l.target_kind, l.target_id) NOT IN (SELECT target_kind, target_id FROM targets)
+            )
+            INSERT INTO links (page_id, task_id, target_kind, target_id)
+            SELECT $1, $2, target_kind, target_id FROM targets
+            ON CONFLICT ON CONSTRAINT links_source_target_key DO NOTHING
+            "#,
+        )
+        .bind(page_id)
+        .bind(task_id)
+        .bind(kinds)
+        .bind(ids)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sources linking to the target, limited to pages `viewer_id` has access to.
+    /// A page linking to itself is not its own backlink.
+    pub async fn get_backlinks<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        target_kind: MentionKind,
+        target_id: Uuid,
+        viewer_id: Uuid,
+    ) -> Result<Vec<Backlink>, sqlx::Error> {
+        sqlx::query_as::<_, Backlink>(
+            r#"
+            SELECT l.page_id, p.title AS page_title, l.task_id, t.title AS task_title, l.created_at
+            FROM links l
+            JOIN pages p ON p.id = l.page_id AND p.deleted_at IS NULL
+            CROSS JOIN LATERAL effective_page_access($3, l.page_id) pa
+            LEFT JOIN tasks t ON t.id = l.task_id
+            WHERE l.target_kind = $1 AND l.target_id = $2
+              AND NOT (l.target_kind = 'page' AND l.page_id = $2 AND l.task_id IS NULL)
+              AND NOT (l.target_kind = 'task' AND l.task_id IS NOT DISTINCT FROM $2)
+            ORDER BY l.created_at DESC
+            "#,
+        )
+        .bind(target_kind)
+        .bind(target_id)
+        .bind(viewer_id)
+        .fetch_all(executor)
+        .await
+    }
+}
@@ -0,0 +1,111 @@
+use sql::{
+    mentions::model::{Mention, MentionSource},
+    shared::tiptap_content::{MentionAttributes, MentionKind},
+};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub struct MentionRepository;
+
+impl MentionRepository {
+    /// Replaces the mentions of one page content or task description with `mentions`.
+    /// Returns only the mentions that did not exist before, users mentioned for the first
+    /// time (except the author) are published on `channel`.
+    pub async fn sync<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        task_id: Option<Uuid>,
+        mentioned_by: Uuid,
+        mentions: &[MentionAttributes],
+        channel: &str,
+    ) -> Result<Vec<Mention>, sqlx::Error> {
+        let kinds = mentions.iter().map(|m| m.kind.as_str()).collect::<Vec<_>>();
+        let ids = mentions.iter().map(|m| m.id).collect::<Vec<_>>();
+
+        sqlx::query_as::<_, Mention>(
+            r#"
+            WITH targets AS (
+                SELECT * FROM UNNEST($4::text[], $5::uuid[]) AS t (target_kind, target_id)
+            ),
+            removed AS (
+                DELETE FROM mentions m
+                WHERE m.page_id = $1
+                  AND m.task_id IS NOT DISTINCT FROM $2
+                  AND (m.target_kind, m.target_id) NOT IN (SELECT target_kind, target_id FROM targets)
+            ),
+            created AS (
+                INSERT INTO mentions (page_id, task_id, target_kind, target_id, mentioned_by)
+                SELECT $1, $2, target_kind, target_id, $3 FROM targets
+                ON CONFLICT ON CONSTRAINT mentions_source_target_key DO NOTHING
+                RETURNING *
+            )
+            SELECT created.*,
+                CASE WHEN created.target_kind = 'user' AND created.target_id <> $3 THEN
+                    pg_notify(
+                        $6,
+                        json_build_object(
+                            'mentionId', created.id,
+                            'userId', created.target_id,
+                            'mentionedBy', created.mentioned_by,
+                            'pageId', created.page_id,
+                            'taskId', created.task_id
+                        )::text
+                    )::text
+                END AS notified
+            FROM created
+            "#,
+        )
+        .bind(page_id)
+        .bind(task_id)
+        .bind(mentioned_by)
+        .bind(kinds)
+        .bind(ids)
+        .bind(channel)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Places `target` was mentioned in, limited to pages `viewer_id` has access to.
+    pub async fn get_sources_by_target<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres> + Copy,
+        target_kind: MentionKind,
+        target_id: Uuid,
+        viewer_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<MentionSource>, i64), sqlx::Error> {
+        const FROM: &str = r#"
+            FROM mentions m
+            JOIN pages p ON p.id = m.page_id AND p.deleted_at IS NULL
+            CROSS JOIN LATERAL effective_page_access($3, m.page_id) pa
+            LEFT JOIN tasks t ON t.id = m.task_id
+            WHERE m.target_kind = $1 AND m.target_id = $2
+        "#;
+
+        let sources = sqlx::query_as::<_, MentionSource>(&format!(
+            r#"
+            SELECT m.id, m.page_id, p.title AS page_title, m.task_id, t.title AS task_title,
+                m.mentioned_by, m.created_at
+            {FROM}
+            ORDER BY m.created_at DESC, m.id
+            LIMIT $4 OFFSET $5
+            "#
+        ))
+        .bind(target_kind)
+        .bind(target_id)
+        .bind(viewer_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(executor)
+        .await?;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) {FROM}"))
+            .bind(target_kind)
+            .bind(target_id)
+            .bind(viewer_id)
+            .fetch_one(executor)
+            .await?;
+
+        Ok((sources, total))
+    }
+}
@@ -0,0 +1,10 @@
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct CreatePageCommentDto {
+    pub page_id: Uuid,
+    pub author_id: Uuid,
+    pub parent_comment_id: Option<Uuid>,
+    pub text: String,
+    pub quote: Option<String>,
+}
@@ -0,0 +1,136 @@
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use sql::page_comments::model::PageComment;
+
+use super::dto::CreatePageCommentDto;
+
+pub struct PageCommentRepository;
+
+impl PageCommentRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreatePageCommentDto,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>(
+            r#"
+            INSERT INTO page_comments (page_id, author_id, parent_comment_id, text, quote)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(dto.page_id)
+        .bind(dto.author_id)
+        .bind(dto.parent_comment_id)
+        .bind(dto.text)
+        .bind(dto.quote)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update_quote<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        quote: String,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>(
+            r#"
+            UPDATE page_comments SET quote = $2
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(quote)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update_text<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        text: String,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>(
+            r#"
+            UPDATE page_comments SET text = $2, updated_at = NOW()
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(text)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Resolves the thread when `resolved_by` is set, reopens it otherwise.
+    pub async fn set_resolved<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        resolved_by: Option<Uuid>,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>(
+            r#"
+            UPDATE page_comments
+            SET resolved_by = $2, resolved_at = CASE WHEN $2::uuid IS NULL THEN NULL ELSE NOW() END
+            WHERE id = $1
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(resolved_by)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Deleting the first comment of a thread deletes its replies.
+    pub async fn delete<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>("DELETE FROM page_comments WHERE id = $1 RETURNING *")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn get_one_by_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<PageComment, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>("SELECT * FROM page_comments WHERE id = $1")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    /// Comments and replies of the page, oldest first.
+    pub async fn get_all_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<PageComment>, sqlx::Error> {
+        sqlx::query_as::<_, PageComment>(
+            "SELECT * FROM page_comments WHERE page_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Threads of the page that are not resolved.
+    pub async fn count_open_threads<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM page_comments
+            WHERE page_id = $1 AND parent_comment_id IS NULL AND resolved_at IS NULL
+            "#,
+        )
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+}
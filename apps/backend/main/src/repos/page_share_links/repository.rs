@@ -0,0 +1,135 @@
+use sql::page_share_links::model::PageShareLink;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use super::dto::CreatePageShareLinkDto;
+
+pub struct PageShareLinkRepository;
+
+impl PageShareLinkRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreatePageShareLinkDto,
+    ) -> Result<PageShareLink, sqlx::Error> {
+        sqlx::query_as::<_, PageShareLink>(
+            r#"
+            INSERT INTO page_share_links (page_id, token_hash, password_hash, expires_at, created_by)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(dto.page_id)
+        .bind(dto.token_hash)
+        .bind(dto.password_hash)
+        .bind(dto.expires_at)
+        .bind(dto.created_by)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn revoke<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        page_id: Uuid,
+    ) -> Result<PageShareLink, sqlx::Error> {
+        sqlx::query_as::<_, PageShareLink>(
+            r#"
+            UPDATE page_share_links SET revoked_at = NOW()
+            WHERE id = $1 AND page_id = $2 AND revoked_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(id)
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn touch<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("UPDATE page_share_links SET last_used_at = NOW() WHERE id = $1")
+            .bind(id)
+            .execute(executor)
+            .await
+            .map(|_| ())
+    }
+
+    /// Counts a password attempt before it is checked, so parallel guesses are counted as well.
+    /// From `max_attempts` on, every attempt locks the link for twice as long, at most an hour.
+    /// Returns false without counting while the link is locked.
+    pub async fn start_password_attempt<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        max_attempts: i32,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            UPDATE page_share_links
+            SET failed_password_attempts = failed_password_attempts + 1,
+                password_locked_until = CASE
+                    WHEN failed_password_attempts + 1 >= $2
+                    THEN NOW() + make_interval(mins => LEAST(60, power(2, LEAST(failed_password_attempts + 1 - $2, 6)))::int)
+                    ELSE password_locked_until
+                END
+            WHERE id = $1 AND (password_locked_until IS NULL OR password_locked_until <= NOW())
+            RETURNING id
+            "#,
+        )
+        .bind(id)
+        .bind(max_attempts)
+        .fetch_optional(executor)
+        .await
+        .map(|id| id.is_some())
+    }
+
+    pub async fn reset_password_attempts<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE page_share_links SET failed_password_attempts = 0, password_locked_until = NULL WHERE id = $1",
+        )
+        .bind(id)
+        .execute(executor)
+        .await
+        .map(|_| ())
+    }
+
+    /// Links that are neither revoked nor expired.
+    pub async fn get_active_by_token_hash<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        token_hash: &str,
+    ) -> Result<PageShareLink, sqlx::Error> {
+        sqlx::query_as::<_, PageShareLink>(
+            r#"
+            SELECT * FROM page_share_links
+            WHERE token_hash = $1
+                AND revoked_at IS NULL
+                AND (expires_at IS NULL OR expires_at > NOW())
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_active_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<PageShareLink>, sqlx::Error> {
+        sqlx::query_as::<_, PageShareLink>(
+            r#"
+            SELECT * FROM page_share_links
+            WHERE page_id = $1
+                AND revoked_at IS NULL
+                AND (expires_at IS NULL OR expires_at > NOW())
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+}
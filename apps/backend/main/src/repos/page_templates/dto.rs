@@ -0,0 +1,38 @@
+use sqlx::types::Json;
+use uuid::Uuid;
+
+use sql::{
+    page::model::PageType,
+    page_templates::model::{PageTemplate, TemplateBoardStatus},
+    shared::{tiptap_content::TipTapContent, traits::UpdateDto},
+};
+
+#[derive(Debug)]
+pub struct CreatePageTemplateDto {
+    pub workspace_id: Uuid,
+    pub created_by: Uuid,
+    pub name: String,
+    pub r#type: PageType,
+    pub title_pattern: String,
+    pub content: Json<Option<TipTapContent>>,
+    pub statuses: Json<Vec<TemplateBoardStatus>>,
+}
+
+#[derive(Debug)]
+pub struct UpdatePageTemplateDto {
+    pub name: Option<String>,
+    pub title_pattern: Option<String>,
+    pub content: Option<Json<Option<TipTapContent>>>,
+    pub statuses: Option<Json<Vec<TemplateBoardStatus>>>,
+}
+
+impl UpdateDto for UpdatePageTemplateDto {
+    type Model = PageTemplate;
+
+    fn is_empty(&self) -> bool {
+        self.name.is_none()
+            && self.title_pattern.is_none()
+            && self.content.is_none()
+            && self.statuses.is_none()
+    }
+}
@@ -0,0 +1,113 @@
+use sql::{page_templates::model::PageTemplate, shared::traits::UpdateDto};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use super::dto::{CreatePageTemplateDto, UpdatePageTemplateDto};
+
+pub struct PageTemplateRepository;
+
+impl PageTemplateRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        dto: CreatePageTemplateDto,
+    ) -> Result<PageTemplate, sqlx::Error> {
+        sqlx::query_as::<_, PageTemplate>(
+            r#"
+            INSERT INTO page_templates (workspace_id, created_by, name, type, title_pattern, content, statuses)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(dto.workspace_id)
+        .bind(dto.created_by)
+        .bind(dto.name)
+        .bind(dto.r#type)
+        .bind(dto.title_pattern)
+        .bind(dto.content)
+        .bind(dto.statuses)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        dto: UpdatePageTemplateDto,
+    ) -> Result<PageTemplate, sqlx::Error> {
+        if dto.is_empty() {
+            return Self::get_one_by_id(executor, id).await;
+        }
+
+        let mut query_builder = sqlx::QueryBuilder::new("UPDATE page_templates SET ");
+
+        let mut separated = query_builder.separated(", ");
+
+        if let Some(name) = dto.name {
+            separated.push("name = ").push_bind_unseparated(name);
+        }
+
+        if let Some(title_pattern) = dto.title_pattern {
+            separated
+                .push("title_pattern = ")
+                .push_bind_unseparated(title_pattern);
+        }
+
+        if let Some(content) = dto.content {
+            separated.push("content = ").push_bind_unseparated(content);
+        }
+
+        if let Some(statuses) = dto.statuses {
+            separated
+                .push("statuses = ")
+                .push_bind_unseparated(statuses);
+        }
+
+        separated
+            .push("updated_at = ")
+            .push_bind_unseparated(chrono::Utc::now());
+
+        query_builder
+            .push(" WHERE id = ")
+            .push_bind(id)
+            .push(" RETURNING *")
+            .build_query_as::<PageTemplate>()
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn delete<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<PageTemplate, sqlx::Error> {
+        sqlx::query_as::<_, PageTemplate>("DELETE FROM page_templates WHERE id = $1 RETURNING *")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn get_one_by_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+    ) -> Result<PageTemplate, sqlx::Error> {
+        sqlx::query_as::<_, PageTemplate>("SELECT * FROM page_templates WHERE id = $1")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
+    pub async fn get_all_by_workspace_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        workspace_id: Uuid,
+    ) -> Result<Vec<PageTemplate>, sqlx::Error> {
+        sqlx::query_as::<_, PageTemplate>(
+            r#"
+            SELECT * FROM page_templates
+            WHERE workspace_id = $1
+            ORDER BY name ASC, created_at ASC
+            "#,
+        )
+        .bind(workspace_id)
+        .fetch_all(executor)
+        .await
+    }
+}
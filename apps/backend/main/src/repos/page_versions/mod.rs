@@ -0,0 +1,3 @@
+pub mod repository;
+
+pub use repository::PageVersionRepository;
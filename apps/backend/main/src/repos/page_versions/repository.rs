@@ -0,0 +1,116 @@
+use sql::{
+    page_versions::model::{PageVersion, PageVersionSummary},
+    shared::tiptap_content::TipTapContent,
+};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub struct PageVersionRepository;
+
+impl PageVersionRepository {
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        content: Option<&TipTapContent>,
+        created_by: Option<Uuid>,
+    ) -> Result<PageVersion, sqlx::Error> {
+        sqlx::query_as::<_, PageVersion>(
+            "INSERT INTO page_versions (page_id, content, created_by) VALUES ($1, $2, $3) RETURNING *",
+        )
+        .bind(page_id)
+        .bind(sqlx::types::Json(content))
+        .bind(created_by)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn update_content<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        id: Uuid,
+        content: Option<&TipTapContent>,
+    ) -> Result<PageVersion, sqlx::Error> {
+        sqlx::query_as::<_, PageVersion>(
+            "UPDATE page_versions SET content = $1, updated_at = $2 WHERE id = $3 RETURNING *",
+        )
+        .bind(sqlx::types::Json(content))
+        .bind(chrono::Utc::now())
+        .bind(id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_latest_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Option<PageVersion>, sqlx::Error> {
+        sqlx::query_as::<_, PageVersion>(
+            "SELECT * FROM page_versions WHERE page_id = $1 ORDER BY updated_at DESC LIMIT 1",
+        )
+        .bind(page_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Versions of other pages are reported as not found.
+    pub async fn get_one_by_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        id: Uuid,
+    ) -> Result<PageVersion, sqlx::Error> {
+        sqlx::query_as::<_, PageVersion>(
+            "SELECT * FROM page_versions WHERE id = $1 AND page_id = $2",
+        )
+        .bind(id)
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_list<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres> + Copy,
+        page_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<PageVersionSummary>, i64), sqlx::Error> {
+        let versions = sqlx::query_as::<_, PageVersionSummary>(
+            r#"
+            SELECT id, page_id, created_by, created_at, updated_at
+            FROM page_versions
+            WHERE page_id = $1
+            ORDER BY updated_at DESC
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(page_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(executor)
+        .await?;
+
+        let total =
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM page_versions WHERE page_id = $1")
+                .bind(page_id)
+                .fetch_one(executor)
+                .await?;
+
+        Ok((versions, total))
+    }
+
+    /// Content of every version of the page, versions without content are skipped.
+    pub async fn get_contents_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<TipTapContent>, sqlx::Error> {
+        let contents = sqlx::query_scalar::<_, sqlx::types::Json<Option<TipTapContent>>>(
+            "SELECT content FROM page_versions WHERE page_id = $1",
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await?;
+
+        Ok(contents
+            .into_iter()
+            .filter_map(|content| content.0)
+            .collect())
+    }
+}
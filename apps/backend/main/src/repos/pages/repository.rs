@@ -3,13 +3,22 @@ use uuid::Uuid;
 
 use sql::{
     page::model::{Page, PageAccess, PageType, Role, TextPageContent},
-    shared::{tiptap_content::TipTapContent, traits::UpdateDto},
+    shared::{tiptap_content::TipTapContent, traits::UpdateDto, types::ShiftAction},
 };
 
 use super::dto::{CreatePageAccessDto, CreatePageDto, UpdatePageAccessDto, UpdatePageDto};
 
 pub struct PageRepository;
 
+/// Resolution order lives in the `effective_page_access` SQL function so list queries can share it.
+const EFFECTIVE_PAGE_ACCESS_QUERY: &str = r#"
+    SELECT a.id, $1 AS user_id, p.id AS page_id, a.role, a.created_at, a.updated_at,
+        NULL::timestamptz AS deleted_at
+    FROM pages p
+    CROSS JOIN LATERAL effective_page_access($1, p.id) a
+    WHERE p.id = $2
+"#;
+
 impl PageRepository {
     pub async fn get_one_by_id<'a>(
         executor: impl Executor<'a, Database = Postgres>,
@@ -28,8 +37,14 @@ impl PageRepository {
         sqlx::query_as::<_, Page>(
             r#"
             WITH new_page AS (
-                INSERT INTO pages (title, parent_page_id, type, workspace_id, owner_id)
-                VALUES ($1, $2, $3, $4, $5)
+                INSERT INTO pages (title, parent_page_id, type, workspace_id, owner_id, position)
+                VALUES (
+                    $1, $2, $3, $4, $5,
+                    (
+                        SELECT COALESCE(MAX(position), 0) + 1 FROM pages
+                        WHERE workspace_id = $4 AND parent_page_id IS NOT DISTINCT FROM $2
+                    )
+                )
                 RETURNING *
             ),
             created_access AS (
@@ -85,34 +100,181 @@ impl PageRepository {
             .await
     }
 
-    pub async fn delete<'a>(
+    /// Moves the page and its live descendants to trash, they share one `deleted_at` so they can be restored together.
+    pub async fn trash<'a>(
         executor: impl Executor<'a, Database = Postgres>,
         id: Uuid,
     ) -> Result<Page, sqlx::Error> {
-        sqlx::query_as::<_, Page>("DELETE FROM pages WHERE id = $1 RETURNING *")
-            .bind(id)
+        sqlx::query_as::<_, Page>(
+            r#"
+            WITH RECURSIVE subtree AS (
+                SELECT id FROM pages WHERE id = $1 AND deleted_at IS NULL
+                UNION
+                SELECT p.id FROM pages p
+                INNER JOIN subtree s ON p.parent_page_id = s.id
+                WHERE p.deleted_at IS NULL
+            ),
+            trashed AS (
+                UPDATE pages SET deleted_at = NOW()
+                WHERE id IN (SELECT id FROM subtree)
+                RETURNING *
+            )
+            SELECT * FROM trashed WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Restores the page and the descendants trashed together with it, pages trashed on their own stay in trash.
+    pub async fn restore<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        id: Uuid,
+        parent_page_id: Option<Uuid>,
+        position: i32,
+    ) -> Result<Page, sqlx::Error> {
+        sqlx::query_as::<_, Page>(
+            r#"
+            WITH RECURSIVE root AS (
+                SELECT id, deleted_at FROM pages WHERE id = $1 AND deleted_at IS NOT NULL
+            ),
+            subtree AS (
+                SELECT id FROM root
+                UNION
+                SELECT p.id FROM pages p
+                INNER JOIN subtree s ON p.parent_page_id = s.id
+                WHERE p.deleted_at = (SELECT deleted_at FROM root)
+            ),
+            restored AS (
+                UPDATE pages SET
+                    deleted_at = NULL,
+                    parent_page_id = CASE WHEN id = $1 THEN $2 ELSE parent_page_id END,
+                    position = CASE WHEN id = $1 THEN $3 ELSE position END
+                WHERE id IN (SELECT id FROM subtree)
+                RETURNING *
+            )
+            SELECT * FROM restored WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(parent_page_id)
+        .bind(position)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Trashed pages that were deleted on their own rather than along with their parent, newest first.
+    pub async fn get_trash<'a>(
+        executor: impl Executor<'a, Database = Postgres> + Copy,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<Page>, i64), sqlx::Error> {
+        const TRASH_QUERY: &str = r#"
+            FROM pages p
+            CROSS JOIN LATERAL effective_page_access($2, p.id) pa
+            LEFT JOIN pages parent ON parent.id = p.parent_page_id
+            WHERE p.workspace_id = $1
+                AND p.deleted_at IS NOT NULL
+                AND parent.deleted_at IS DISTINCT FROM p.deleted_at
+        "#;
+
+        let pages = sqlx::query_as::<_, Page>(&format!(
+            "SELECT p.* {TRASH_QUERY} ORDER BY p.deleted_at DESC, p.id LIMIT $3 OFFSET $4"
+        ))
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(executor)
+        .await?;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!("SELECT COUNT(*) {TRASH_QUERY}"))
+            .bind(workspace_id)
+            .bind(user_id)
             .fetch_one(executor)
-            .await
+            .await?;
+
+        Ok((pages, total))
     }
 
     pub async fn get_all_in_workspace<'a>(
         executor: impl Executor<'a, Database = Postgres>,
         workspace_id: Uuid,
     ) -> Result<Vec<Page>, sqlx::Error> {
-        sqlx::query_as::<_, Page>("SELECT * FROM pages WHERE workspace_id = $1")
-            .bind(workspace_id)
-            .fetch_all(executor)
-            .await
+        sqlx::query_as::<_, Page>(
+            "SELECT * FROM pages WHERE workspace_id = $1 AND deleted_at IS NULL ORDER BY position ASC, created_at ASC",
+        )
+        .bind(workspace_id)
+        .fetch_all(executor)
+        .await
     }
 
     pub async fn get_child_pages<'a>(
         executor: impl Executor<'a, Database = Postgres>,
         page_id: Uuid,
     ) -> Result<Vec<Page>, sqlx::Error> {
-        sqlx::query_as::<_, Page>("SELECT * FROM pages WHERE parent_page_id = $1")
-            .bind(page_id)
-            .fetch_all(executor)
-            .await
+        sqlx::query_as::<_, Page>(
+            "SELECT * FROM pages WHERE parent_page_id = $1 AND deleted_at IS NULL ORDER BY position ASC, created_at ASC",
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Groups above the page that `user_id` can access, from the workspace root down to the parent.
+    pub async fn get_ancestors<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<Page>, sqlx::Error> {
+        sqlx::query_as::<_, Page>(
+            r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT parent.*, 1 AS depth FROM pages child
+                INNER JOIN pages parent ON parent.id = child.parent_page_id
+                WHERE child.id = $1
+                UNION ALL
+                SELECT parent.*, ancestors.depth + 1 FROM pages parent
+                INNER JOIN ancestors ON parent.id = ancestors.parent_page_id
+                WHERE ancestors.depth < 64
+            )
+            SELECT ancestors.* FROM ancestors
+            WHERE EXISTS (SELECT 1 FROM effective_page_access($2, ancestors.id))
+            ORDER BY ancestors.depth DESC
+            "#,
+        )
+        .bind(page_id)
+        .bind(user_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Every page below the page, depth first with siblings in `position` order.
+    /// Trashed pages and their subtrees are left out.
+    pub async fn get_descendants<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<Page>, sqlx::Error> {
+        sqlx::query_as::<_, Page>(
+            r#"
+            WITH RECURSIVE descendants AS (
+                SELECT p.*, ARRAY[p.position] AS path FROM pages p
+                WHERE p.parent_page_id = $1 AND p.deleted_at IS NULL
+                UNION ALL
+                SELECT p.*, descendants.path || p.position FROM pages p
+                INNER JOIN descendants ON p.parent_page_id = descendants.id
+                WHERE p.deleted_at IS NULL AND cardinality(descendants.path) < 64
+            )
+            SELECT * FROM descendants
+            ORDER BY path ASC, created_at ASC
+            "#,
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
     }
 
     pub async fn get_text_page_content<'a>(
@@ -125,6 +287,19 @@ impl PageRepository {
             .await
     }
 
+    /// Same as `get_text_page_content`, the row stays locked against concurrent saves until the transaction ends.
+    pub async fn get_text_page_content_for_update<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<TextPageContent, sqlx::Error> {
+        sqlx::query_as::<_, TextPageContent>(
+            "SELECT * FROM text_page_contents WHERE page_id = $1 FOR UPDATE",
+        )
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+
     pub async fn update_content<'a>(
         executor: impl Executor<'a, Database = Postgres>,
         page_id: Uuid,
@@ -155,6 +330,102 @@ impl PageRepository {
         .await
     }
 
+    /// Serializes the writes to the page tree of a workspace until the transaction ends, since
+    /// positions and parents are checked against the other pages before they are written.
+    pub async fn lock_tree<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        workspace_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('pages:' || $1::text, 0))")
+            .bind(workspace_id)
+            .execute(executor)
+            .await
+            .map(|_| ())
+    }
+
+    pub async fn move_to<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        id: Uuid,
+        parent_page_id: Option<Uuid>,
+        position: i32,
+    ) -> Result<Page, sqlx::Error> {
+        sqlx::query_as::<_, Page>(
+            "UPDATE pages SET parent_page_id = $2, position = $3 WHERE id = $1 RETURNING *",
+        )
+        .bind(id)
+        .bind(parent_page_id)
+        .bind(position)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_last_position<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        workspace_id: Uuid,
+        parent_page_id: Option<Uuid>,
+    ) -> Result<Option<i32>, sqlx::Error> {
+        sqlx::query_scalar::<_, Option<i32>>(
+            r#"
+            SELECT MAX(position) FROM pages
+            WHERE workspace_id = $1 AND parent_page_id IS NOT DISTINCT FROM $2 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(parent_page_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn shift_pages_position<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        workspace_id: Uuid,
+        parent_page_id: Option<Uuid>,
+        start_position: Option<i32>,
+        end_position: Option<i32>,
+        action: ShiftAction,
+    ) -> Result<(), sqlx::Error> {
+        let mut query =
+            sqlx::query_builder::QueryBuilder::new("UPDATE pages SET position = position ");
+
+        query
+            .push(action.to_string())
+            .push(" 1 WHERE workspace_id = ")
+            .push_bind(workspace_id)
+            .push(" AND parent_page_id IS NOT DISTINCT FROM ")
+            .push_bind(parent_page_id)
+            .push(" AND deleted_at IS NULL AND position >= ")
+            .push_bind(start_position.unwrap_or(1));
+
+        if let Some(end_position) = end_position {
+            query.push(" AND position <= ").push_bind(end_position);
+        }
+
+        query.build().execute(executor).await.map(|_| ())
+    }
+
+    /// Whether `ancestor_id` is `page_id` itself or one of the groups above it.
+    pub async fn is_ancestor_or_self<'a>(
+        executor: impl Executor<'a, Database = Postgres>,
+        ancestor_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE ancestors AS (
+                SELECT id, parent_page_id FROM pages WHERE id = $2
+                UNION
+                SELECT p.id, p.parent_page_id FROM pages p
+                INNER JOIN ancestors a ON p.id = a.parent_page_id
+            )
+            SELECT EXISTS (SELECT 1 FROM ancestors WHERE id = $1)
+            "#,
+        )
+        .bind(ancestor_id)
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+
     // PAGE ACCESS
 
     pub async fn create_page_access<'a>(
@@ -175,17 +446,32 @@ impl PageRepository {
         .await
     }
 
+    /// Role the user holds on a live page: an explicit grant on the page overrides the nearest grant
+    /// on a parent group, which overrides the workspace role. `id` and timestamps belong to the grant
+    /// or workspace membership the role was taken from. Pages in trash are treated as inaccessible,
+    /// see [`Self::get_trashed_page_access`].
     pub async fn get_one_page_access<'a>(
         executor: impl Executor<'a, Database = sqlx::Postgres>,
         user_id: Uuid,
         page_id: Uuid,
     ) -> Result<PageAccess, sqlx::Error> {
-        sqlx::query_as::<_, PageAccess>(
-            r#"
-        SELECT * FROM page_accesses
-        WHERE user_id = $1 AND page_id = $2
-        "#,
-        )
+        sqlx::query_as::<_, PageAccess>(&format!(
+            "{EFFECTIVE_PAGE_ACCESS_QUERY} AND p.deleted_at IS NULL"
+        ))
+        .bind(user_id)
+        .bind(page_id)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn get_trashed_page_access<'a>(
+        executor: impl Executor<'a, Database = sqlx::Postgres>,
+        user_id: Uuid,
+        page_id: Uuid,
+    ) -> Result<PageAccess, sqlx::Error> {
+        sqlx::query_as::<_, PageAccess>(&format!(
+            "{EFFECTIVE_PAGE_ACCESS_QUERY} AND p.deleted_at IS NOT NULL"
+        ))
         .bind(user_id)
         .bind(page_id)
         .fetch_one(executor)
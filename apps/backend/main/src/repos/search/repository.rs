@@ -0,0 +1,97 @@
+use sql::search::model::{SearchHit, SearchHitKind};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub struct SearchRepository;
+
+/// Matches of `$1` (a tsquery) in workspace `$2` on pages `$3` has access to, for the kinds in `$4`.
+/// `document` is the searched text, escaped so that the highlighted headline is safe HTML.
+const HITS_QUERY: &str = r#"
+    WITH q AS (
+        SELECT to_tsquery('simple', $1) AS query
+    ),
+    hits AS (
+        SELECT 'page' AS kind, p.id, p.id AS page_id, p.title AS page_title,
+            NULL::uuid AS task_id, NULL::varchar AS task_title,
+            ts_rank(p.search_vector || COALESCE(c.search_vector, ''::tsvector), q.query) AS rank,
+            concat_ws(' ', p.title, tiptap_text(c.content)) AS document
+        FROM pages p
+        CROSS JOIN q
+        CROSS JOIN LATERAL effective_page_access($3, p.id) pa
+        LEFT JOIN text_page_contents c ON c.page_id = p.id
+        WHERE 'page' = ANY($4) AND p.workspace_id = $2 AND p.deleted_at IS NULL
+          AND (p.search_vector @@ q.query OR c.search_vector @@ q.query)
+        UNION ALL
+        SELECT 'task', t.id, p.id, p.title, t.id, t.title,
+            ts_rank(t.search_vector, q.query),
+            concat_ws(' ', t.title, tiptap_text(t.description))
+        FROM tasks t
+        CROSS JOIN q
+        JOIN pages p ON p.id = t.page_id AND p.workspace_id = $2 AND p.deleted_at IS NULL
+        CROSS JOIN LATERAL effective_page_access($3, p.id) pa
+        WHERE 'task' = ANY($4) AND t.deleted_at IS NULL AND NOT t.is_draft
+          AND t.search_vector @@ q.query
+        UNION ALL
+        SELECT 'message', m.id, p.id, p.title, t.id, t.title,
+            ts_rank(m.search_vector, q.query),
+            m.text
+        FROM task_chat_messages m
+        CROSS JOIN q
+        JOIN tasks t ON t.id = m.task_id AND t.deleted_at IS NULL
+        JOIN pages p ON p.id = t.page_id AND p.workspace_id = $2 AND p.deleted_at IS NULL
+        CROSS JOIN LATERAL effective_page_access($3, p.id) pa
+        WHERE 'message' = ANY($4) AND m.deleted_at IS NULL
+          AND m.search_vector @@ q.query
+    )
+"#;
+
+impl SearchRepository {
+    pub async fn search<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres> + Copy,
+        ts_query: &str,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        kinds: &[SearchHitKind],
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<SearchHit>, i64), sqlx::Error> {
+        let kinds = kinds.iter().map(SearchHitKind::as_str).collect::<Vec<_>>();
+
+        let hits = sqlx::query_as::<_, SearchHit>(&format!(
+            r#"
+            {HITS_QUERY}
+            SELECT hits.kind, hits.id, hits.page_id, hits.page_title, hits.task_id, hits.task_title,
+                hits.rank,
+                ts_headline(
+                    'simple',
+                    replace(replace(replace(hits.document, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                    q.query,
+                    'StartSel=<mark>, StopSel=</mark>, MaxFragments=2, MaxWords=20, MinWords=5'
+                ) AS headline
+            FROM hits
+            CROSS JOIN q
+            ORDER BY hits.rank DESC, hits.id
+            LIMIT $5 OFFSET $6
+            "#
+        ))
+        .bind(ts_query)
+        .bind(workspace_id)
+        .bind(user_id)
+        .bind(&kinds)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(executor)
+        .await?;
+
+        let total =
+            sqlx::query_scalar::<_, i64>(&format!("{HITS_QUERY} SELECT COUNT(*) FROM hits"))
+                .bind(ts_query)
+                .bind(workspace_id)
+                .bind(user_id)
+                .bind(&kinds)
+                .fetch_one(executor)
+                .await?;
+
+        Ok((hits, total))
+    }
+}
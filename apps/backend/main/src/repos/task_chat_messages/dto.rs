@@ -0,0 +1,8 @@
+use sql::shared::types::SortOrder;
+
+#[derive(Debug, Default)]
+pub struct TaskChatMessageFilterBy {
+    /// Case-insensitive substring of the message text.
+    pub query: Option<String>,
+    pub sort_order: Option<SortOrder>,
+}
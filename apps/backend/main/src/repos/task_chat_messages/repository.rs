@@ -0,0 +1,114 @@
+use sql::{
+    shared::{types::SortOrder, utils::escape_like},
+    task_chat_messages::model::{TaskChatMessage, TaskChatMessageCount},
+};
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use super::dto::TaskChatMessageFilterBy;
+
+pub struct TaskChatMessageRepository;
+
+impl TaskChatMessageRepository {
+    fn apply_filter<'a>(
+        builder: &mut sqlx::QueryBuilder<'a, Postgres>,
+        task_id: Uuid,
+        filter: &TaskChatMessageFilterBy,
+    ) {
+        builder
+            .push(" WHERE task_id = ")
+            .push_bind(task_id)
+            .push(" AND deleted_at IS NULL");
+
+        if let Some(query) = &filter.query {
+            builder
+                .push(" AND text ILIKE ")
+                .push_bind(format!("%{}%", escape_like(query)))
+                .push(" ESCAPE '\\'");
+        }
+    }
+
+    pub async fn get_list<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres> + Copy,
+        task_id: Uuid,
+        limit: i64,
+        offset: i64,
+        filter: &TaskChatMessageFilterBy,
+    ) -> Result<(Vec<TaskChatMessage>, i64), sqlx::Error> {
+        let mut query_builder =
+            sqlx::QueryBuilder::<Postgres>::new("SELECT * FROM task_chat_messages");
+        let mut total_builder =
+            sqlx::QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM task_chat_messages");
+
+        Self::apply_filter(&mut query_builder, task_id, filter);
+        Self::apply_filter(&mut total_builder, task_id, filter);
+
+        query_builder.push(format!(
+            " ORDER BY created_at {order}, id {order} LIMIT {} OFFSET {}",
+            limit,
+            offset,
+            order = filter.sort_order.as_ref().unwrap_or(&SortOrder::Desc),
+        ));
+
+        let messages = query_builder
+            .build_query_as::<TaskChatMessage>()
+            .fetch_all(executor)
+            .await?;
+
+        let total = total_builder
+            .build_query_scalar::<i64>()
+            .fetch_one(executor)
+            .await?;
+
+        Ok((messages, total))
+    }
+
+    pub async fn get_pinned_by_task_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskChatMessage>, sqlx::Error> {
+        sqlx::query_as::<_, TaskChatMessage>(
+            "SELECT * FROM task_chat_messages WHERE task_id = $1 AND pinned_by IS NOT NULL AND deleted_at IS NULL ORDER BY created_at DESC",
+        )
+        .bind(task_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Tasks without messages are not returned.
+    pub async fn get_counts_by_task_ids<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_ids: &[Uuid],
+    ) -> Result<Vec<TaskChatMessageCount>, sqlx::Error> {
+        sqlx::query_as::<_, TaskChatMessageCount>(
+            r#"
+            SELECT task_id, COUNT(*) AS total, COUNT(pinned_by) AS pinned
+            FROM task_chat_messages
+            WHERE task_id = ANY($1) AND deleted_at IS NULL
+            GROUP BY task_id
+            "#,
+        )
+        .bind(task_ids)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Tasks without messages are not returned.
+    pub async fn get_counts_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<TaskChatMessageCount>, sqlx::Error> {
+        sqlx::query_as::<_, TaskChatMessageCount>(
+            r#"
+            SELECT m.task_id, COUNT(*) AS total, COUNT(m.pinned_by) AS pinned
+            FROM task_chat_messages m
+            JOIN tasks t ON t.id = m.task_id
+            WHERE t.page_id = $1 AND m.deleted_at IS NULL
+            GROUP BY m.task_id
+            "#,
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+}
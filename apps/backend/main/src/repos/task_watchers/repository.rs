@@ -0,0 +1,55 @@
+use sqlx::Postgres;
+use uuid::Uuid;
+
+use sql::task::model::TaskWatcher;
+
+pub struct TaskWatcherRepository;
+
+impl TaskWatcherRepository {
+    /// Watching an already watched task is a no-op.
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO task_watchers (task_id, user_id)
+            VALUES ($1, $2)
+            ON CONFLICT (task_id, user_id) DO NOTHING
+            "#,
+        )
+        .bind(task_id)
+        .bind(user_id)
+        .execute(executor)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn delete<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM task_watchers WHERE task_id = $1 AND user_id = $2")
+            .bind(task_id)
+            .bind(user_id)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_by_task_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskWatcher>, sqlx::Error> {
+        sqlx::query_as::<_, TaskWatcher>(
+            "SELECT * FROM task_watchers WHERE task_id = $1 ORDER BY created_at ASC",
+        )
+        .bind(task_id)
+        .fetch_all(executor)
+        .await
+    }
+}
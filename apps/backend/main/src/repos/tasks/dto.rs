@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::Deserialize;
 use uuid::Uuid;
@@ -7,6 +9,8 @@ use sql::{
     shared::{tiptap_content::TipTapContent, traits::UpdateDto},
 };
 
+use crate::shared::validation::{self, Validate, validate_tiptap_content};
+
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateTaskDto {
@@ -33,6 +37,19 @@ pub struct UpdateTaskDto {
     pub assignee_id: Option<Option<Uuid>>,
 }
 
+impl Validate for UpdateTaskDto {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        let mut errors = HashMap::new();
+        validate_tiptap_content(
+            "description",
+            self.description.as_ref().and_then(|d| d.as_ref()),
+            &mut errors,
+        );
+
+        validation::into_result(errors)
+    }
+}
+
 impl UpdateDto for UpdateTaskDto {
     type Model = Task;
 
@@ -45,3 +62,18 @@ impl UpdateDto for UpdateTaskDto {
             && self.assignee_id.is_none()
     }
 }
+
+/// Task joined with the names needed for exports.
+#[derive(Debug, sqlx::FromRow)]
+pub struct TaskExportRow {
+    pub id: Uuid,
+    pub title: String,
+    pub status_title: String,
+    pub assignee_name: Option<String>,
+    pub reporter_name: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    pub position: i32,
+    pub description: sqlx::types::Json<Option<TipTapContent>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
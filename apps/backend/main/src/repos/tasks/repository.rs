@@ -1,10 +1,11 @@
 use uuid::Uuid;
 
-use sql::shared::types::ShiftAction;
+use sql::board_views::model::{BoardViewFilters, BoardViewSort, BoardViewSortBy, DueDateFilter};
+use sql::shared::{types::ShiftAction, utils::escape_like};
 
 use sql::task::model::Task;
 
-use super::{CreateTaskDto, UpdateTaskDto};
+use super::{CreateTaskDto, TaskExportRow, UpdateTaskDto};
 
 pub struct TaskRepository;
 
@@ -98,6 +99,17 @@ impl TaskRepository {
             .await
     }
 
+    /// Same as `get_one_by_id`, the row stays locked against concurrent updates until the transaction ends.
+    pub async fn get_one_by_id_for_update<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        id: Uuid,
+    ) -> Result<Task, sqlx::Error> {
+        sqlx::query_as::<_, Task>("SELECT * FROM tasks WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_one(executor)
+            .await
+    }
+
     pub async fn delete<'a>(
         executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
         id: Uuid,
@@ -118,6 +130,112 @@ impl TaskRepository {
             .await
     }
 
+    /// Streams non-draft tasks ordered by status and position, status titles are taken for `lang` with an English fallback.
+    pub fn stream_export_rows_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres> + 'a,
+        page_id: Uuid,
+        lang: &str,
+    ) -> futures_util::stream::BoxStream<'a, Result<TaskExportRow, sqlx::Error>> {
+        sqlx::query_as::<_, TaskExportRow>(
+            r#"
+            SELECT t.id, t.title, t.due_date, t.position, t.description, t.created_at, t.updated_at,
+                COALESCE(s.localizations->>$2, s.localizations->>'en', '') AS status_title,
+                a.username AS assignee_name,
+                r.username AS reporter_name
+            FROM tasks t
+            JOIN board_statuses s ON s.id = t.status_id
+            LEFT JOIN users a ON a.id = t.assignee_id
+            LEFT JOIN users r ON r.id = t.reporter_id
+            WHERE t.page_id = $1 AND t.deleted_at IS NULL AND t.is_draft = false
+            ORDER BY s.position ASC, t.position ASC
+            "#,
+        )
+        .bind(page_id)
+        .bind(lang.to_string())
+        .fetch(executor)
+    }
+
+    pub async fn get_filtered_tasks_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        user_id: Uuid,
+        filters: &BoardViewFilters,
+        sort: &BoardViewSort,
+    ) -> Result<Vec<Task>, sqlx::Error> {
+        let mut query_builder = sqlx::QueryBuilder::new("SELECT * FROM tasks WHERE page_id = ");
+        query_builder.push_bind(page_id);
+
+        if filters.assigned_to_me {
+            query_builder.push(" AND assignee_id = ").push_bind(user_id);
+        }
+
+        if let Some(assignee_ids) = &filters.assignee_ids
+            && !assignee_ids.is_empty()
+        {
+            query_builder
+                .push(" AND assignee_id = ANY(")
+                .push_bind(assignee_ids.clone())
+                .push(")");
+        }
+
+        if let Some(reporter_ids) = &filters.reporter_ids
+            && !reporter_ids.is_empty()
+        {
+            query_builder
+                .push(" AND reporter_id = ANY(")
+                .push_bind(reporter_ids.clone())
+                .push(")");
+        }
+
+        if let Some(status_ids) = &filters.status_ids
+            && !status_ids.is_empty()
+        {
+            query_builder
+                .push(" AND status_id = ANY(")
+                .push_bind(status_ids.clone())
+                .push(")");
+        }
+
+        if let Some(due_date) = &filters.due_date {
+            query_builder.push(match due_date {
+                DueDateFilter::Overdue => " AND due_date < NOW()",
+                DueDateFilter::Today => " AND due_date::date = CURRENT_DATE",
+                DueDateFilter::ThisWeek => {
+                    " AND due_date >= date_trunc('week', NOW()) AND due_date < date_trunc('week', NOW()) + INTERVAL '1 week'"
+                }
+                DueDateFilter::NoDueDate => " AND due_date IS NULL",
+            });
+        }
+
+        if let Some(search) = &filters.search
+            && !search.trim().is_empty()
+        {
+            query_builder
+                .push(" AND title ILIKE ")
+                .push_bind(format!("%{}%", escape_like(search.trim())))
+                .push(" ESCAPE '\\'");
+        }
+
+        if filters.hide_drafts {
+            query_builder.push(" AND is_draft = false");
+        }
+
+        query_builder.push(format!(" ORDER BY {} {}", sort.by, sort.order));
+
+        if sort.by == BoardViewSortBy::DueDate {
+            query_builder.push(" NULLS LAST");
+        }
+
+        if sort.by != BoardViewSortBy::Position {
+            query_builder.push(", position ASC");
+        }
+
+        query_builder
+            .build_query_as::<Task>()
+            .fetch_all(executor)
+            .await
+    }
+
     pub async fn get_last_position<'a>(
         executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
         status_id: Uuid,
@@ -160,9 +278,10 @@ impl TaskRepository {
         sqlx::query_scalar(
             r#"
             SELECT EXISTS (
-                SELECT 1 FROM page_access pa
-                JOIN task t ON t.page_id = pa.page_id
-                WHERE pa.user_id = $1 AND t.id = $2
+                SELECT 1 FROM tasks t
+                JOIN pages p ON p.id = t.page_id AND p.deleted_at IS NULL
+                CROSS JOIN LATERAL effective_page_access($1, t.page_id) pa
+                WHERE t.id = $2
             )
             "#,
         )
@@ -0,0 +1,62 @@
+use sql::text_page_updates::model::TextPageUpdate;
+use sqlx::Postgres;
+use uuid::Uuid;
+
+pub struct TextPageUpdateRepository;
+
+impl TextPageUpdateRepository {
+    pub async fn get_all_by_page_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<TextPageUpdate>, sqlx::Error> {
+        sqlx::query_as::<_, TextPageUpdate>(
+            "SELECT * FROM text_page_updates WHERE page_id = $1 ORDER BY id ASC",
+        )
+        .bind(page_id)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn create<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        data: &[u8],
+    ) -> Result<TextPageUpdate, sqlx::Error> {
+        sqlx::query_as::<_, TextPageUpdate>(
+            "INSERT INTO text_page_updates (page_id, data) VALUES ($1, $2) RETURNING *",
+        )
+        .bind(page_id)
+        .bind(data)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Updates other than `known_ids`, stored since those were read.
+    pub async fn get_all_by_page_id_except<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        known_ids: &[i64],
+    ) -> Result<Vec<TextPageUpdate>, sqlx::Error> {
+        sqlx::query_as::<_, TextPageUpdate>(
+            "SELECT * FROM text_page_updates WHERE page_id = $1 AND id <> ALL($2) ORDER BY id ASC",
+        )
+        .bind(page_id)
+        .bind(known_ids)
+        .fetch_all(executor)
+        .await
+    }
+
+    pub async fn delete_many<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        page_id: Uuid,
+        ids: &[i64],
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM text_page_updates WHERE page_id = $1 AND id = ANY($2)")
+            .bind(page_id)
+            .bind(ids)
+            .execute(executor)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -216,4 +216,26 @@ impl UserRepository {
             .fetch_all(executor)
             .await
     }
+
+    /// Matches emails case-insensitively, `emails` are expected to be lowercased.
+    /// Members of the workspace with one of the given lowercase emails.
+    pub async fn get_workspace_members_by_emails<'a>(
+        executor: impl sqlx::Executor<'a, Database = Postgres>,
+        workspace_id: Uuid,
+        emails: &[String],
+    ) -> Result<Vec<User>, sqlx::Error> {
+        sqlx::query_as::<_, User>(
+            r#"
+        SELECT users.* FROM users
+        INNER JOIN workspace_accesses ON workspace_accesses.user_id = users.id
+        WHERE workspace_accesses.workspace_id = $1
+            AND LOWER(users.email) = ANY($2)
+            AND users.deleted_at IS NULL
+        "#,
+        )
+        .bind(workspace_id)
+        .bind(emails)
+        .fetch_all(executor)
+        .await
+    }
 }
@@ -3,12 +3,16 @@ use uuid::Uuid;
 
 use sql::{workspace::model::{Role, Workspace}, shared::traits::UpdateDto};
 
+use crate::shared::validation::Validate;
+
 #[derive(Debug, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateWorkspaceDto {
     pub name: Option<String>,
 }
 
+impl Validate for UpdateWorkspaceDto {}
+
 impl UpdateDto for UpdateWorkspaceDto {
     type Model = Workspace;
 
@@ -0,0 +1,30 @@
+use crate::{
+    controllers::board_views::controller::{
+        create_board_view::create_board_view, delete_board_view::delete_board_view,
+        get_board_views::get_board_views, update_board_view::update_board_view,
+    },
+    types::app_state::AppState,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/pages/{page_id}/views", get(get_board_views))
+        .route("/pages/{page_id}/views", post(create_board_view))
+        .route("/pages/{page_id}/views/{view_id}", put(update_board_view))
+        .route(
+            "/pages/{page_id}/views/{view_id}",
+            delete(delete_board_view),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
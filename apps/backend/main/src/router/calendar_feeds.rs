@@ -0,0 +1,27 @@
+use crate::{
+    controllers::calendar_feeds::controller::{
+        create_calendar_feed::create_calendar_feed, get_calendar_feed_ics::get_calendar_feed_ics,
+        get_calendar_feeds::get_calendar_feeds, revoke_calendar_feed::revoke_calendar_feed,
+    },
+    types::app_state::AppState,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    let authorized = Router::new()
+        .route("/calendar/feeds", get(get_calendar_feeds))
+        .route("/calendar/feeds", post(create_calendar_feed))
+        .route("/calendar/feeds/{feed_id}", delete(revoke_calendar_feed))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ));
+
+    // The feed token authenticates the request, see `get_calendar_feed_ics`
+    let public = Router::new().route("/calendar/ics/{token}", get(get_calendar_feed_ics));
+
+    Router::new().merge(authorized).merge(public)
+}
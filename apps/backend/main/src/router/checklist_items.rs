@@ -0,0 +1,38 @@
+use crate::{
+    controllers::checklist_items::controller::{
+        create_checklist_item::create_checklist_item, delete_checklist_item::delete_checklist_item,
+        get_checklist_items::get_checklist_items, move_checklist_item::move_checklist_item,
+        update_checklist_item::update_checklist_item,
+    },
+    types::app_state::AppState,
+};
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/tasks/{task_id}/checklist", get(get_checklist_items))
+        .route("/tasks/{task_id}/checklist", post(create_checklist_item))
+        .route(
+            "/tasks/{task_id}/checklist/{item_id}",
+            put(update_checklist_item),
+        )
+        .route(
+            "/tasks/{task_id}/checklist/{item_id}",
+            delete(delete_checklist_item),
+        )
+        .route(
+            "/tasks/{task_id}/checklist/{item_id}/position",
+            put(move_checklist_item),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_task_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
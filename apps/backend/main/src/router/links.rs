@@ -0,0 +1,32 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    controllers::links::controller::{
+        get_page_backlinks::get_page_backlinks, get_task_backlinks::get_task_backlinks,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    let general = Router::new()
+        .route("/pages/{page_id}/backlinks", get(get_page_backlinks))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ));
+
+    let scoped = Router::new()
+        .route("/tasks/{task_id}/backlinks", get(get_task_backlinks))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_task_route,
+        ));
+
+    Router::new()
+        .merge(general)
+        .merge(scoped)
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
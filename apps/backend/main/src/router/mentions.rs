@@ -0,0 +1,14 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    controllers::mentions::controller::get_my_mentions::get_my_mentions, types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/mentions", get(get_my_mentions))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
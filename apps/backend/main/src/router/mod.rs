@@ -4,8 +4,20 @@ use crate::types::app_state::AppState;
 
 pub mod assets;
 pub mod board_statuses;
+pub mod board_views;
+pub mod calendar_feeds;
+pub mod checklist_items;
+pub mod links;
+pub mod mentions;
+pub mod page_collaboration;
+pub mod page_comments;
+pub mod page_share_links;
+pub mod page_templates;
+pub mod page_versions;
 pub mod pages;
 pub mod profile;
+pub mod search;
+pub mod task_chat_messages;
 pub mod tasks;
 pub mod users;
 pub mod workspaces;
@@ -14,9 +26,21 @@ pub fn init_router(state: AppState) -> Router<AppState> {
     Router::new()
         .merge(assets::init(state.clone()))
         .merge(pages::init(state.clone()))
+        .merge(page_collaboration::init(state.clone()))
+        .merge(page_comments::init(state.clone()))
+        .merge(page_share_links::init(state.clone()))
+        .merge(page_templates::init(state.clone()))
+        .merge(page_versions::init(state.clone()))
         .merge(tasks::init(state.clone()))
+        .merge(task_chat_messages::init(state.clone()))
         .merge(board_statuses::init(state.clone()))
+        .merge(board_views::init(state.clone()))
+        .merge(calendar_feeds::init(state.clone()))
+        .merge(checklist_items::init(state.clone()))
+        .merge(links::init(state.clone()))
+        .merge(mentions::init(state.clone()))
         .merge(profile::init(state.clone()))
+        .merge(search::init(state.clone()))
         .merge(users::init(state.clone()))
         .merge(workspaces::init(state.clone()))
         .merge(crate::controllers::internal::init())
@@ -0,0 +1,19 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    controllers::page_collaboration::controller::collaborate_page::collaborate_page,
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/pages/{page_id}/collaboration", get(collaborate_page))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
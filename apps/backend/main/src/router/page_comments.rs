@@ -0,0 +1,48 @@
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
+
+use crate::{
+    controllers::page_comments::controller::{
+        create_page_comment::create_page_comment, delete_page_comment::delete_page_comment,
+        get_page_comments::get_page_comments, reply_to_page_comment::reply_to_page_comment,
+        resolve_page_comment::resolve_page_comment, unresolve_page_comment::unresolve_page_comment,
+        update_page_comment::update_page_comment,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/pages/{page_id}/comments", get(get_page_comments))
+        .route("/pages/{page_id}/comments", post(create_page_comment))
+        .route(
+            "/pages/{page_id}/comments/{comment_id}",
+            put(update_page_comment),
+        )
+        .route(
+            "/pages/{page_id}/comments/{comment_id}",
+            delete(delete_page_comment),
+        )
+        .route(
+            "/pages/{page_id}/comments/{comment_id}/replies",
+            post(reply_to_page_comment),
+        )
+        .route(
+            "/pages/{page_id}/comments/{comment_id}/resolve",
+            post(resolve_page_comment),
+        )
+        .route(
+            "/pages/{page_id}/comments/{comment_id}/unresolve",
+            post(unresolve_page_comment),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
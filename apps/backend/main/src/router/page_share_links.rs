@@ -0,0 +1,41 @@
+use axum::{
+    Router,
+    routing::{delete, get, post},
+};
+
+use crate::{
+    controllers::page_share_links::controller::{
+        create_page_share_link::create_page_share_link, get_page_share_links::get_page_share_links,
+        get_shared_asset_blob_id::get_shared_asset_blob_id, get_shared_page::get_shared_page,
+        revoke_page_share_link::revoke_page_share_link,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    let authorized = Router::new()
+        .route("/pages/{page_id}/share-links", get(get_page_share_links))
+        .route("/pages/{page_id}/share-links", post(create_page_share_link))
+        .route(
+            "/pages/{page_id}/share-links/{link_id}",
+            delete(revoke_page_share_link),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ));
+
+    // The share token authenticates the request, see `get_shared_page`
+    let public = Router::new()
+        .route("/shared/{token}", get(get_shared_page))
+        .route(
+            "/shared/{token}/assets/{asset_id}/blob-id",
+            get(get_shared_asset_blob_id),
+        );
+
+    Router::new().merge(authorized).merge(public)
+}
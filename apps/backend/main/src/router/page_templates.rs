@@ -0,0 +1,45 @@
+use axum::{
+    Router,
+    routing::{delete, get, patch, post},
+};
+
+use crate::{
+    controllers::page_templates::controller::{
+        create_page_template::create_page_template, delete_page_template::delete_page_template,
+        get_page_template::get_page_template, get_page_templates::get_page_templates,
+        update_page_template::update_page_template,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/workspaces/{workspace_id}/templates",
+            get(get_page_templates),
+        )
+        .route(
+            "/workspaces/{workspace_id}/templates",
+            post(create_page_template),
+        )
+        .route(
+            "/workspaces/{workspace_id}/templates/{template_id}",
+            get(get_page_template),
+        )
+        .route(
+            "/workspaces/{workspace_id}/templates/{template_id}",
+            patch(update_page_template),
+        )
+        .route(
+            "/workspaces/{workspace_id}/templates/{template_id}",
+            delete(delete_page_template),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::workspace_access_guard::workspace_access_guard,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
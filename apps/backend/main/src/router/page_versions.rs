@@ -0,0 +1,37 @@
+use axum::{
+    Router,
+    routing::{get, post},
+};
+
+use crate::{
+    controllers::page_versions::controller::{
+        get_page_version::get_page_version, get_page_version_diff::get_page_version_diff,
+        get_page_versions::get_page_versions, restore_page_version::restore_page_version,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/pages/{page_id}/versions", get(get_page_versions))
+        .route(
+            "/pages/{page_id}/versions/{version_id}",
+            get(get_page_version),
+        )
+        .route(
+            "/pages/{page_id}/versions/{version_id}/diff",
+            get(get_page_version_diff),
+        )
+        .route(
+            "/pages/{page_id}/versions/{version_id}/restore",
+            post(restore_page_version),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
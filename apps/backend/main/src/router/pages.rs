@@ -1,13 +1,15 @@
 use crate::{
     controllers::page::controller::{
         create_page::create_page, create_page_access::create_page_access, delete_page::delete_page,
-        get_page_list::get_page_list, get_page::get_page,
+        duplicate_page::duplicate_page, export_page::export_page, get_page::get_page,
         get_page_access_list::get_page_access_list, get_page_details::get_page_details,
-        update_page::update_page, update_page_access::update_page_access,
+        get_page_list::get_page_list, get_page_trash::get_page_trash, import_page::import_page,
+        move_page::move_page, restore_page::restore_page, update_page::update_page,
+        update_page_access::update_page_access,
     },
     types::app_state::AppState,
 };
-use axum::{Router, routing};
+use axum::{Router, extract::DefaultBodyLimit, routing};
 
 pub fn init(state: AppState) -> Router<AppState> {
     let general = Router::new()
@@ -19,6 +21,18 @@ pub fn init(state: AppState) -> Router<AppState> {
             "/workspaces/{workspace_id}/pages",
             routing::get(get_page_list),
         )
+        .route(
+            "/workspaces/{workspace_id}/pages/import",
+            routing::post(import_page).layer(DefaultBodyLimit::max(20 * 1024 * 1024)),
+        )
+        .route(
+            "/workspaces/{workspace_id}/trash",
+            routing::get(get_page_trash),
+        )
+        .route(
+            "/workspaces/{workspace_id}/trash/{page_id}/restore",
+            routing::post(restore_page),
+        )
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             crate::middleware::workspace_access_guard::workspace_access_guard,
@@ -28,7 +42,10 @@ pub fn init(state: AppState) -> Router<AppState> {
         .route("/pages/{page_id}", routing::get(get_page))
         .route("/pages/{page_id}", routing::put(update_page))
         .route("/pages/{page_id}", routing::delete(delete_page))
+        .route("/pages/{page_id}/position", routing::put(move_page))
+        .route("/pages/{page_id}/duplicate", routing::post(duplicate_page))
         .route("/pages/{page_id}/detailed", routing::get(get_page_details))
+        .route("/pages/{page_id}/export", routing::get(export_page))
         .route(
             "/pages/{page_id}/access",
             axum::routing::get(get_page_access_list),
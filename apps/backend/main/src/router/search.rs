@@ -0,0 +1,18 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    controllers::search::controller::search_workspace::search_workspace, types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route("/workspaces/{workspace_id}/search", get(search_workspace))
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::workspace_access_guard::workspace_access_guard,
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
@@ -0,0 +1,46 @@
+use axum::{Router, routing::get};
+
+use crate::{
+    controllers::task_chat_messages::controller::{
+        get_page_task_chat_message_counts::get_page_task_chat_message_counts,
+        get_pinned_task_chat_messages::get_pinned_task_chat_messages,
+        get_task_chat_message_count::get_task_chat_message_count,
+        get_task_chat_messages::get_task_chat_messages,
+    },
+    types::app_state::AppState,
+};
+
+pub fn init(state: AppState) -> Router<AppState> {
+    let general = Router::new()
+        .route(
+            "/pages/{page_id}/messages/counts",
+            get(get_page_task_chat_message_counts),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_by_page_route,
+        ));
+
+    let scoped = Router::new()
+        .route("/tasks/{task_id}/messages", get(get_task_chat_messages))
+        .route(
+            "/tasks/{task_id}/messages/pinned",
+            get(get_pinned_task_chat_messages),
+        )
+        .route(
+            "/tasks/{task_id}/messages/count",
+            get(get_task_chat_message_count),
+        )
+        .layer(axum::middleware::from_fn_with_state(
+            state.clone(),
+            crate::middleware::page_access_guard::page_access_guard_task_route,
+        ));
+
+    Router::new()
+        .merge(general)
+        .merge(scoped)
+        .layer(axum::middleware::from_fn_with_state(
+            state,
+            utils::auth_middleware::auth_guard,
+        ))
+}
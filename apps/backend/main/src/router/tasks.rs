@@ -6,7 +6,9 @@ use axum::{
 use crate::{
     controllers::task::controller::{
         create_draft_task::create_draft_task, create_task::create_task, delete_task::delete_task,
-        get_task::get_task, get_tasks_in_page::get_tasks_in_page, update_task::update_task,
+        get_task::get_task, get_task_watchers::get_task_watchers,
+        get_tasks_in_page::get_tasks_in_page, import_tasks::import_tasks,
+        unwatch_task::unwatch_task, update_task::update_task, watch_task::watch_task,
     },
     types::app_state::AppState,
 };
@@ -16,6 +18,7 @@ pub fn init(state: AppState) -> Router<AppState> {
         .route("/pages/{page_id}/tasks", post(create_task))
         .route("/pages/{page_id}/tasks/draft", post(create_draft_task))
         .route("/pages/{page_id}/tasks", get(get_tasks_in_page))
+        .route("/pages/{page_id}/tasks/import", post(import_tasks))
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             crate::middleware::page_access_guard::page_access_guard_by_page_route,
@@ -25,6 +28,9 @@ pub fn init(state: AppState) -> Router<AppState> {
         .route("/tasks/{task_id}", delete(delete_task))
         .route("/tasks/{task_id}", put(update_task))
         .route("/tasks/{task_id}", get(get_task))
+        .route("/tasks/{task_id}/watchers", get(get_task_watchers))
+        .route("/tasks/{task_id}/watchers", post(watch_task))
+        .route("/tasks/{task_id}/watchers", delete(unwatch_task))
         .layer(axum::middleware::from_fn_with_state(
             state.clone(),
             crate::middleware::page_access_guard::page_access_guard_task_route,
@@ -1,19 +1,31 @@
+use std::collections::HashSet;
+
 use chrono::{Duration, Utc};
 use error_handlers::handlers::ErrorResponse;
 use serde::{Deserialize, Serialize};
-use sql::assets::model::{Asset, EntityType};
+use sql::{
+    assets::model::{Asset, EntityType},
+    shared::tiptap_content::{FileAttributes, TipTapContent},
+};
 use uuid::Uuid;
 
-use crate::repos::{
-    assets::{AssetsRepository, CreateAssetDto as RepoCreateAssetDto},
-    pages::PageRepository,
-    tasks::{TaskRepository, UpdateTaskDto},
+use crate::{
+    repos::{
+        assets::{AssetsRepository, CreateAssetDto as RepoCreateAssetDto},
+        page_versions::PageVersionRepository,
+        pages::PageRepository,
+        tasks::{TaskRepository, UpdateTaskDto},
+    },
+    services::page_collaboration::PageCollaborationService,
 };
 
+/// Assets younger than this are never collected, their document may not be saved yet.
+const UNREFERENCED_ASSET_GRACE_HOURS: i64 = 24;
+
 use super::dto::{AssetTarget, CreateAssetDto as ServiceCreateAssetDto, CreateUploadTokenDto};
 
 fn hydrate_asset(
-    content: &mut sql::shared::tiptap_content::TipTapContent,
+    content: &mut TipTapContent,
     asset_id: Uuid,
     mime_type: &str,
     size: i64,
@@ -21,15 +33,7 @@ fn hydrate_asset(
     storage_url: &str,
 ) -> bool {
     content.hydrate_file_node(asset_id, |attrs| {
-        attrs.r#type = Some(mime_type.to_string());
-        attrs.size = Some(size as u64);
-        attrs.alt = Some(name.to_string());
-        attrs.width = Some("100%".to_string());
-        attrs.height = Some("auto".to_string());
-        attrs.title = Some(name.to_string());
-        attrs.href = Some(format!("{}/files/{}", storage_url, asset_id));
-        attrs.src = Some(format!("{}/files/{}", storage_url, asset_id));
-        attrs.id = Some(asset_id);
+        *attrs = AssetsService::file_attributes(asset_id, mime_type, size, name, storage_url);
     })
 }
 
@@ -46,6 +50,27 @@ pub struct UploadToken {
 }
 
 impl AssetsService {
+    /// Attributes of a file node showing an uploaded asset.
+    pub fn file_attributes(
+        asset_id: Uuid,
+        mime_type: &str,
+        size: i64,
+        name: &str,
+        storage_url: &str,
+    ) -> FileAttributes {
+        FileAttributes {
+            id: Some(asset_id),
+            src: Some(format!("{}/files/{}", storage_url, asset_id)),
+            href: Some(format!("{}/files/{}", storage_url, asset_id)),
+            alt: Some(name.to_string()),
+            title: Some(name.to_string()),
+            height: Some("auto".to_string()),
+            width: Some("100%".to_string()),
+            size: Some(size as u64),
+            r#type: Some(mime_type.to_string()),
+        }
+    }
+
     // COMMANDS
 
     /// Needs AppState for jwt_secret + postgres
@@ -75,7 +100,7 @@ impl AssetsService {
             }
 
             AssetTarget::TaskDescription(task_id) => {
-                let result = TaskRepository::has_access(executor, user_id, task_id).await?;
+                let result = TaskRepository::has_access(executor, task_id, user_id).await?;
 
                 if !result {
                     return Err(ErrorResponse::forbidden(
@@ -128,9 +153,11 @@ impl AssetsService {
 
         match token.claims.entity_type {
             EntityType::PageText => {
-                let page_content =
-                    PageRepository::get_text_page_content(&mut *executor, token.claims.entity_id)
-                        .await?;
+                let page_content = PageRepository::get_text_page_content_for_update(
+                    &mut *executor,
+                    token.claims.entity_id,
+                )
+                .await?;
 
                 let Some(content_json) = page_content.content.0 else {
                     return Err(ErrorResponse::not_found(
@@ -152,6 +179,12 @@ impl AssetsService {
                 );
 
                 if found {
+                    PageCollaborationService::replace_content(
+                        &mut *executor,
+                        token.claims.entity_id,
+                        Some(&content),
+                    )
+                    .await?;
                     PageRepository::update_content(
                         &mut *executor,
                         token.claims.entity_id,
@@ -266,4 +299,77 @@ impl AssetsService {
 
         Ok(asset)
     }
+
+    /// Deletes page and task assets no file node refers to anymore, the storage service then
+    /// removes their unused blobs. Every version of a page counts as a reference, so restoring
+    /// a version keeps its files. Recent assets are kept while their document is being saved.
+    /// Each document is locked while its assets are checked and deleted, so a concurrent save
+    /// either lands first and keeps its files or waits for the deletion.
+    pub async fn collect_garbage(pool: &sqlx::PgPool) -> Result<u64, ErrorResponse> {
+        let created_before = Utc::now() - Duration::hours(UNREFERENCED_ASSET_GRACE_HOURS);
+        let mut deleted = 0;
+
+        for entity_type in [EntityType::PageText, EntityType::TaskDescription] {
+            let assets = AssetsRepository::get_by_entity_type_created_before(
+                pool,
+                entity_type.clone(),
+                created_before,
+            )
+            .await?;
+
+            for entity_assets in assets.chunk_by(|a, b| a.entity_id == b.entity_id) {
+                let entity_id = entity_assets[0].entity_id;
+                let mut tx = pool.begin().await?;
+
+                let documents = match entity_type {
+                    EntityType::PageText => {
+                        let content = match PageRepository::get_text_page_content_for_update(
+                            &mut *tx, entity_id,
+                        )
+                        .await
+                        {
+                            Ok(page_content) => page_content.content.0,
+                            Err(sqlx::Error::RowNotFound) => None,
+                            Err(error) => return Err(ErrorResponse::from(error)),
+                        };
+
+                        let mut documents =
+                            PageVersionRepository::get_contents_by_page_id(&mut *tx, entity_id)
+                                .await?;
+                        documents.extend(content);
+
+                        documents
+                    }
+                    _ => {
+                        match TaskRepository::get_one_by_id_for_update(&mut *tx, entity_id).await {
+                            Ok(task) => task.description.0.into_iter().collect(),
+                            Err(sqlx::Error::RowNotFound) => vec![],
+                            Err(error) => return Err(ErrorResponse::from(error)),
+                        }
+                    }
+                };
+
+                let referenced = documents
+                    .iter()
+                    .flat_map(TipTapContent::file_asset_ids)
+                    .collect::<HashSet<_>>();
+
+                let unreferenced = entity_assets
+                    .iter()
+                    .filter(|asset| !referenced.contains(&asset.id))
+                    .map(|asset| asset.id)
+                    .collect::<Vec<_>>();
+
+                if unreferenced.is_empty() {
+                    continue;
+                }
+
+                deleted += AssetsRepository::delete_unreferenced(&mut *tx, &unreferenced).await?;
+
+                tx.commit().await?;
+            }
+        }
+
+        Ok(deleted)
+    }
 }
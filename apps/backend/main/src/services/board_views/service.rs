@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    board_views::model::BoardView,
+    page::model::{PageAccess, PageType, Role},
+};
+use uuid::Uuid;
+
+use crate::{
+    controllers::board_views::dto::{CreateBoardViewRequest, UpdateBoardViewRequest},
+    repos::{
+        board_views::{BoardViewRepository, CreateBoardViewDto, UpdateBoardViewDto},
+        pages::PageRepository,
+    },
+};
+
+fn insufficient_permissions() -> ErrorResponse {
+    ErrorResponse::forbidden(
+        codes::ForbiddenErrorCode::InsufficientPermissions,
+        Some(HashMap::from([(
+            "message".to_string(),
+            "Insufficient permissions".to_string(),
+        )])),
+        None,
+    )
+}
+
+/// Owners can always manage their views, shared views can also be managed by page admins.
+fn can_manage(view: &BoardView, page_access: &PageAccess) -> bool {
+    view.owner_id == page_access.user_id || (view.is_shared && page_access.role >= Role::Admin)
+}
+
+pub struct BoardViewService;
+
+impl BoardViewService {
+    // QUERIES
+
+    pub async fn get_visible_views<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<BoardView>, ErrorResponse> {
+        BoardViewRepository::get_visible_by_page_id(executor, page_id, user_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Private views of other users are reported as not found.
+    pub async fn get_visible_view<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        view_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<BoardView, ErrorResponse> {
+        let view = BoardViewRepository::get_one_by_id(executor, view_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        if view.page_id != page_id || (!view.is_shared && view.owner_id != user_id) {
+            return Err(ErrorResponse::not_found(
+                codes::NotFoundErrorCode::NotFound,
+                None,
+                Some("Board view not found".to_string()),
+            ));
+        }
+
+        Ok(view)
+    }
+
+    // COMMANDS
+
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        dto: CreateBoardViewRequest,
+    ) -> Result<BoardView, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(pool, page_access.page_id).await?;
+
+        if page.r#type != PageType::Board {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "pageId".to_string(),
+                    "Views can only be saved for board pages".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let is_shared = dto.is_shared.unwrap_or(false);
+
+        if is_shared && page_access.role < Role::Member {
+            return Err(insufficient_permissions());
+        }
+
+        BoardViewRepository::create(
+            pool,
+            CreateBoardViewDto {
+                page_id: page.id,
+                owner_id: page_access.user_id,
+                name: dto.name,
+                is_shared,
+                filters: sqlx::types::Json(dto.filters.unwrap_or_default()),
+                sort: sqlx::types::Json(dto.sort.unwrap_or_default()),
+                group_by: dto.group_by.unwrap_or_default(),
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    pub async fn update(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        view_id: Uuid,
+        dto: UpdateBoardViewRequest,
+    ) -> Result<BoardView, ErrorResponse> {
+        let view =
+            Self::get_visible_view(pool, page_access.page_id, view_id, page_access.user_id).await?;
+
+        if !can_manage(&view, page_access)
+            || (dto.is_shared == Some(true) && page_access.role < Role::Member)
+        {
+            return Err(insufficient_permissions());
+        }
+
+        BoardViewRepository::update(
+            pool,
+            view.id,
+            UpdateBoardViewDto {
+                name: dto.name,
+                is_shared: dto.is_shared,
+                filters: dto.filters.map(sqlx::types::Json),
+                sort: dto.sort.map(sqlx::types::Json),
+                group_by: dto.group_by,
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    pub async fn delete(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        view_id: Uuid,
+    ) -> Result<BoardView, ErrorResponse> {
+        let view =
+            Self::get_visible_view(pool, page_access.page_id, view_id, page_access.user_id).await?;
+
+        if !can_manage(&view, page_access) {
+            return Err(insufficient_permissions());
+        }
+
+        BoardViewRepository::delete(pool, view.id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+}
@@ -0,0 +1,86 @@
+//! Minimal RFC 5545 writer for the task calendar feeds.
+
+use chrono::{DateTime, Utc};
+use sql::calendar_feeds::model::CalendarTask;
+
+/// Content lines longer than this many octets must be folded.
+const MAX_LINE_OCTETS: usize = 75;
+
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn format_date_time(value: &DateTime<Utc>) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Appends a content line, folding it without splitting UTF-8 sequences.
+fn push_line(output: &mut String, line: &str) {
+    let mut octets = 0;
+
+    for c in line.chars() {
+        if octets + c.len_utf8() > MAX_LINE_OCTETS {
+            output.push_str("\r\n ");
+            // The leading space of a continuation line counts towards its length
+            octets = 1;
+        }
+
+        output.push(c);
+        octets += c.len_utf8();
+    }
+
+    output.push_str("\r\n");
+}
+
+pub fn render_calendar(name: &str, tasks: &[CalendarTask]) -> String {
+    let mut output = String::new();
+
+    push_line(&mut output, "BEGIN:VCALENDAR");
+    push_line(&mut output, "VERSION:2.0");
+    push_line(&mut output, "PRODID:-//Tasks//Due dates//EN");
+    push_line(&mut output, "CALSCALE:GREGORIAN");
+    push_line(&mut output, "METHOD:PUBLISH");
+    push_line(&mut output, &format!("X-WR-CALNAME:{}", escape_text(name)));
+
+    for task in tasks {
+        push_line(&mut output, "BEGIN:VEVENT");
+        push_line(&mut output, &format!("UID:{}", task.id));
+        push_line(
+            &mut output,
+            &format!("DTSTAMP:{}", format_date_time(&task.updated_at)),
+        );
+        push_line(
+            &mut output,
+            &format!("DTSTART:{}", format_date_time(&task.due_date)),
+        );
+        push_line(
+            &mut output,
+            &format!("SUMMARY:{}", escape_text(&task.title)),
+        );
+        push_line(
+            &mut output,
+            &format!(
+                "DESCRIPTION:{}",
+                escape_text(&format!("{}\n{}", task.page_title, task.status_title))
+            ),
+        );
+        push_line(&mut output, "END:VEVENT");
+    }
+
+    push_line(&mut output, "END:VCALENDAR");
+
+    output
+}
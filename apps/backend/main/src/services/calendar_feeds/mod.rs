@@ -0,0 +1,4 @@
+mod ics;
+mod service;
+
+pub use service::CalendarFeedService;
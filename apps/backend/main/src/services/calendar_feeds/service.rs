@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    calendar_feeds::model::{CalendarFeed, CalendarFeedScope},
+    page::model::PageType,
+};
+use uuid::Uuid;
+
+use crate::{
+    controllers::calendar_feeds::dto::CreateCalendarFeedRequest,
+    repos::{
+        calendar_feeds::{CalendarFeedRepository, CreateCalendarFeedDto},
+        pages::PageRepository,
+        workspaces::WorkspaceRepository,
+    },
+    shared::{
+        extractors::x_user_language::DEFAULT_LANGUAGE,
+        tokens::{generate_token, hash_token},
+    },
+};
+
+use super::ics;
+
+fn invalid_params(field: &str, message: &str) -> ErrorResponse {
+    ErrorResponse::bad_request(
+        codes::BadRequestErrorCode::InvalidParams,
+        Some(HashMap::from([(field.to_string(), message.to_string())])),
+        None,
+    )
+}
+
+fn feed_not_found() -> ErrorResponse {
+    ErrorResponse::not_found(
+        codes::NotFoundErrorCode::NotFound,
+        None,
+        Some("Calendar feed not found".to_string()),
+    )
+}
+
+pub struct CalendarFeedService;
+
+impl CalendarFeedService {
+    // QUERIES
+
+    pub async fn get_active<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        user_id: Uuid,
+    ) -> Result<Vec<CalendarFeed>, ErrorResponse> {
+        CalendarFeedRepository::get_active_by_user_id(executor, user_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Renders the `.ics` document of a feed token. Access of the feed owner is re-checked on every
+    /// request, so losing access to a workspace or board also silences its feeds.
+    pub async fn render(
+        pool: &sqlx::PgPool,
+        token: &str,
+        lang: Option<String>,
+    ) -> Result<String, ErrorResponse> {
+        let feed = CalendarFeedRepository::get_active_by_token_hash(pool, &hash_token(token))
+            .await
+            .map_err(|_| feed_not_found())?;
+
+        let lang = lang.unwrap_or(DEFAULT_LANGUAGE.to_string());
+
+        let (name, tasks) = match (feed.scope, feed.workspace_id, feed.page_id) {
+            (CalendarFeedScope::Workspace, Some(workspace_id), _) => {
+                WorkspaceRepository::get_one_workspace_access(pool, feed.user_id, workspace_id)
+                    .await
+                    .map_err(|_| feed_not_found())?;
+
+                let workspace = WorkspaceRepository::get_one_by_id(pool, workspace_id).await?;
+                let tasks = CalendarFeedRepository::get_workspace_tasks(
+                    pool,
+                    feed.user_id,
+                    &lang,
+                    workspace_id,
+                )
+                .await?;
+
+                (workspace.name, tasks)
+            }
+            (CalendarFeedScope::Board, _, Some(page_id)) => {
+                PageRepository::get_one_page_access(pool, feed.user_id, page_id)
+                    .await
+                    .map_err(|_| feed_not_found())?;
+
+                let page = PageRepository::get_one_by_id(pool, page_id).await?;
+                let tasks =
+                    CalendarFeedRepository::get_board_tasks(pool, feed.user_id, &lang, page_id)
+                        .await?;
+
+                (page.title, tasks)
+            }
+            _ => {
+                let tasks =
+                    CalendarFeedRepository::get_user_tasks(pool, feed.user_id, &lang).await?;
+
+                ("My tasks".to_string(), tasks)
+            }
+        };
+
+        CalendarFeedRepository::touch(pool, feed.id).await?;
+
+        Ok(ics::render_calendar(&name, &tasks))
+    }
+
+    // COMMANDS
+
+    /// Returns the feed together with its token, the token cannot be retrieved again later.
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        user_id: Uuid,
+        dto: CreateCalendarFeedRequest,
+    ) -> Result<(CalendarFeed, String), ErrorResponse> {
+        let (workspace_id, page_id) = match dto.scope {
+            CalendarFeedScope::User => (None, None),
+            CalendarFeedScope::Workspace => {
+                let workspace_id = dto
+                    .workspace_id
+                    .ok_or_else(|| invalid_params("workspaceId", "Required for workspace feeds"))?;
+
+                WorkspaceRepository::get_one_workspace_access(pool, user_id, workspace_id)
+                    .await
+                    .map_err(|_| {
+                        ErrorResponse::forbidden(
+                            codes::ForbiddenErrorCode::AccessDenied,
+                            None,
+                            None,
+                        )
+                    })?;
+
+                (Some(workspace_id), None)
+            }
+            CalendarFeedScope::Board => {
+                let page_id = dto
+                    .page_id
+                    .ok_or_else(|| invalid_params("pageId", "Required for board feeds"))?;
+
+                PageRepository::get_one_page_access(pool, user_id, page_id)
+                    .await
+                    .map_err(|_| {
+                        ErrorResponse::forbidden(
+                            codes::ForbiddenErrorCode::AccessDenied,
+                            None,
+                            None,
+                        )
+                    })?;
+
+                let page = PageRepository::get_one_by_id(pool, page_id).await?;
+
+                if page.r#type != PageType::Board {
+                    return Err(invalid_params(
+                        "pageId",
+                        "Feeds can only be created for board pages",
+                    ));
+                }
+
+                (None, Some(page_id))
+            }
+        };
+
+        let token = generate_token();
+
+        let feed = CalendarFeedRepository::create(
+            pool,
+            CreateCalendarFeedDto {
+                user_id,
+                token_hash: hash_token(&token),
+                scope: dto.scope,
+                workspace_id,
+                page_id,
+            },
+        )
+        .await?;
+
+        Ok((feed, token))
+    }
+
+    pub async fn revoke<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        user_id: Uuid,
+        feed_id: Uuid,
+    ) -> Result<CalendarFeed, ErrorResponse> {
+        CalendarFeedRepository::revoke(executor, feed_id, user_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+}
@@ -0,0 +1,200 @@
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{checklist_items::model::ChecklistItem, shared::types::ShiftAction};
+use uuid::Uuid;
+
+use crate::{
+    controllers::checklist_items::dto::{
+        CreateChecklistItemRequest, MoveChecklistItemRequest, UpdateChecklistItemRequest,
+    },
+    repos::{
+        checklist_items::{
+            ChecklistItemRepository, CreateChecklistItemDto, UpdateChecklistItemDto,
+        },
+        tasks::TaskRepository,
+    },
+};
+
+pub struct ChecklistItemService;
+
+impl ChecklistItemService {
+    // QUERIES
+
+    pub async fn get_by_task_id<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        task_id: Uuid,
+    ) -> Result<Vec<ChecklistItem>, ErrorResponse> {
+        ChecklistItemRepository::get_by_task_id(executor, task_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Items of other tasks are reported as not found.
+    async fn get_task_item<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        task_id: Uuid,
+        item_id: Uuid,
+    ) -> Result<ChecklistItem, ErrorResponse> {
+        let item = ChecklistItemRepository::get_one_by_id(executor, item_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        if item.task_id != task_id {
+            return Err(ErrorResponse::not_found(
+                codes::NotFoundErrorCode::NotFound,
+                None,
+                Some("Checklist item not found".to_string()),
+            ));
+        }
+
+        Ok(item)
+    }
+
+    // COMMANDS
+
+    /// Positions are computed from the other items of the task, so writes that change them
+    /// hold the task row until they commit.
+    async fn lock_task(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        task_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        TaskRepository::get_one_by_id_for_update(&mut **tx, task_id)
+            .await
+            .map(|_| ())
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        dto: CreateChecklistItemRequest,
+    ) -> Result<ChecklistItem, ErrorResponse> {
+        let mut tx = pool.begin().await?;
+
+        Self::lock_task(&mut tx, task_id).await?;
+
+        let item = ChecklistItemRepository::create(
+            &mut *tx,
+            CreateChecklistItemDto {
+                task_id,
+                text: dto.text,
+                assignee_id: dto.assignee_id,
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        tx.commit().await?;
+
+        Ok(item)
+    }
+
+    pub async fn update(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        item_id: Uuid,
+        dto: UpdateChecklistItemRequest,
+    ) -> Result<ChecklistItem, ErrorResponse> {
+        let item = Self::get_task_item(pool, task_id, item_id).await?;
+
+        ChecklistItemRepository::update(
+            pool,
+            item.id,
+            UpdateChecklistItemDto {
+                text: dto.text,
+                is_done: dto.is_done,
+                assignee_id: dto.assignee_id,
+                position: None,
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    pub async fn move_to_position(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        item_id: Uuid,
+        dto: MoveChecklistItemRequest,
+    ) -> Result<ChecklistItem, ErrorResponse> {
+        let mut tx = pool.begin().await?;
+
+        Self::lock_task(&mut tx, task_id).await?;
+
+        let item = Self::get_task_item(&mut *tx, task_id, item_id).await?;
+
+        let last_position = ChecklistItemRepository::get_last_position(&mut *tx, task_id)
+            .await
+            .map_err(ErrorResponse::from)?
+            .unwrap_or(item.position);
+
+        let position = dto.position.clamp(1, last_position);
+
+        if position == item.position {
+            tx.rollback().await?;
+            return Ok(item);
+        }
+
+        let (start, end, action) = if position > item.position {
+            (item.position + 1, position, ShiftAction::Minus)
+        } else {
+            (position, item.position - 1, ShiftAction::Plus)
+        };
+
+        ChecklistItemRepository::shift_items_position(
+            &mut *tx,
+            task_id,
+            Some(start),
+            Some(end),
+            action,
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        let item = ChecklistItemRepository::update(
+            &mut *tx,
+            item.id,
+            UpdateChecklistItemDto {
+                text: None,
+                is_done: None,
+                assignee_id: None,
+                position: Some(position),
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        tx.commit().await?;
+
+        Ok(item)
+    }
+
+    pub async fn delete(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        item_id: Uuid,
+    ) -> Result<ChecklistItem, ErrorResponse> {
+        let mut tx = pool.begin().await?;
+
+        Self::lock_task(&mut tx, task_id).await?;
+
+        let item = Self::get_task_item(&mut *tx, task_id, item_id).await?;
+
+        let item = ChecklistItemRepository::delete(&mut *tx, item.id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        ChecklistItemRepository::shift_items_position(
+            &mut *tx,
+            task_id,
+            Some(item.position + 1),
+            None,
+            ShiftAction::Minus,
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        tx.commit().await?;
+
+        Ok(item)
+    }
+}
@@ -0,0 +1,42 @@
+use error_handlers::handlers::ErrorResponse;
+use sql::{
+    links::model::Backlink,
+    shared::tiptap_content::{MentionKind, TipTapContent},
+};
+use uuid::Uuid;
+
+use crate::repos::links::LinkRepository;
+
+pub struct LinkService;
+
+impl LinkService {
+    // QUERIES
+
+    pub async fn get_backlinks(
+        pool: &sqlx::PgPool,
+        target_kind: MentionKind,
+        target_id: Uuid,
+        viewer_id: Uuid,
+    ) -> Result<Vec<Backlink>, ErrorResponse> {
+        LinkRepository::get_backlinks(pool, target_kind, target_id, viewer_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    // COMMANDS
+
+    /// Indexes the pages and tasks referenced by saved content, `None` content removes them.
+    /// `task_id` is set when the content is a task description.
+    pub async fn sync<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        task_id: Option<Uuid>,
+        content: Option<&TipTapContent>,
+    ) -> Result<(), ErrorResponse> {
+        let references = content.map(TipTapContent::references).unwrap_or_default();
+
+        LinkRepository::sync(executor, page_id, task_id, &references)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+}
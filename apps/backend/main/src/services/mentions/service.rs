@@ -0,0 +1,63 @@
+use error_handlers::handlers::ErrorResponse;
+use sql::{
+    mentions::model::{Mention, MentionSource},
+    shared::tiptap_content::{MentionKind, TipTapContent},
+};
+use uuid::Uuid;
+
+use crate::repos::mentions::MentionRepository;
+
+/// Channel new user mentions are published on, one JSON payload per mentioned user.
+pub const MENTIONS_CHANNEL: &str = "mentions";
+
+pub struct MentionService;
+
+impl MentionService {
+    // QUERIES
+
+    pub async fn get_user_mentions(
+        pool: &sqlx::PgPool,
+        user_id: Uuid,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<(Vec<MentionSource>, i64), ErrorResponse> {
+        let limit = limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT);
+        let offset = offset.unwrap_or(sql::shared::constants::DEFAULT_OFFSET);
+
+        MentionRepository::get_sources_by_target(
+            pool,
+            MentionKind::User,
+            user_id,
+            user_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    // COMMANDS
+
+    /// Stores the mentions of saved content, `None` content removes them.
+    /// `task_id` is set when the content is a task description.
+    pub async fn sync<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        task_id: Option<Uuid>,
+        author_id: Uuid,
+        content: Option<&TipTapContent>,
+    ) -> Result<Vec<Mention>, ErrorResponse> {
+        let mentions = content.map(TipTapContent::mentions).unwrap_or_default();
+
+        MentionRepository::sync(
+            executor,
+            page_id,
+            task_id,
+            author_id,
+            &mentions,
+            MENTIONS_CHANNEL,
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+}
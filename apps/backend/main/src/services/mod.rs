@@ -1,6 +1,22 @@
 pub mod assets;
 pub mod board_statuses;
+pub mod board_views;
+pub mod calendar_feeds;
+pub mod checklist_items;
+pub mod links;
+pub mod mentions;
+pub mod page_collaboration;
+pub mod page_comments;
+pub mod page_duplication;
+pub mod page_export;
+pub mod page_import;
+pub mod page_share_links;
+pub mod page_templates;
+pub mod page_versions;
 pub mod pages;
+pub mod search;
+pub mod task_chat_messages;
+pub mod task_import;
 pub mod tasks;
 pub mod users;
 pub mod workspaces;
@@ -0,0 +1,459 @@
+//! The collaborative document, in the layout of y-prosemirror which the TipTap collaboration
+//! extension uses: an XML element per node, named after the node type with its attributes,
+//! and an XML text per run of text nodes, with one formatting attribute per mark.
+
+use serde_json::{Map, Value};
+use sql::shared::tiptap_content::TipTapContent;
+use yrs::{
+    Any, Doc, Out, ReadTxn, StateVector, Text, Transact, TransactionMut, Update, WriteTxn, Xml,
+    XmlElementPrelim, XmlFragment, XmlOut, XmlTextPrelim, XmlTextRef,
+    types::{Attrs, text::YChange},
+    updates::{decoder::Decode, encoder::Encode},
+};
+
+/// Fragment the editor binds to, the default field of the TipTap collaboration extension.
+const FRAGMENT: &str = "default";
+
+/// Applies an update in the lib0 v1 encoding, returns false when it cannot be decoded.
+pub(super) fn apply_update(doc: &Doc, update: &[u8]) -> bool {
+    let Ok(update) = Update::decode_v1(update) else {
+        return false;
+    };
+
+    doc.transact_mut().apply_update(update).is_ok()
+}
+
+pub(super) fn state_vector(doc: &Doc) -> Vec<u8> {
+    doc.transact().state_vector().encode_v1()
+}
+
+/// Update bringing a document at `state_vector` up to date, `None` when it cannot be decoded.
+pub(super) fn diff(doc: &Doc, state_vector: &[u8]) -> Option<Vec<u8>> {
+    let state_vector = StateVector::decode_v1(state_vector).ok()?;
+
+    Some(doc.transact().encode_state_as_update_v1(&state_vector))
+}
+
+/// The whole document as a single update.
+pub(super) fn state(doc: &Doc) -> Vec<u8> {
+    doc.transact()
+        .encode_state_as_update_v1(&StateVector::default())
+}
+
+/// TipTap JSON of the document. An empty document gets an empty paragraph, as in the editor.
+pub(super) fn to_content(doc: &Doc) -> Result<TipTapContent, serde_json::Error> {
+    let txn = doc.transact();
+
+    let mut content = match txn.get_xml_fragment(FRAGMENT) {
+        Some(fragment) => fragment
+            .children(&txn)
+            .flat_map(|child| to_nodes(&txn, child))
+            .collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+    if content.is_empty() {
+        content.push(serde_json::json!({ "type": "paragraph" }));
+    }
+
+    serde_json::from_value(serde_json::json!({ "type": "doc", "content": content }))
+}
+
+/// Changes the document into `content`, returns the update of the change. Blocks the document
+/// starts and ends with are kept as they are, so concurrent edits of other blocks survive.
+pub(super) fn replace_content(
+    doc: &Doc,
+    content: Option<&TipTapContent>,
+) -> Result<Vec<u8>, serde_json::Error> {
+    let nodes = match content {
+        Some(content) => match serde_json::to_value(content)?.get_mut("content") {
+            Some(Value::Array(nodes)) => std::mem::take(nodes),
+            _ => Vec::new(),
+        },
+        None => Vec::new(),
+    };
+
+    let mut txn = doc.transact_mut();
+    let fragment = txn.get_or_insert_xml_fragment(FRAGMENT);
+
+    let current = fragment
+        .children(&txn)
+        .map(|child| {
+            to_nodes(&txn, child)
+                .iter()
+                .map(normalize)
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let is_kept = |block: &Vec<Value>, node: &Value| *block == [normalize(node)];
+
+    let prefix = current
+        .iter()
+        .zip(&nodes)
+        .take_while(|(block, node)| is_kept(block, node))
+        .count();
+    let suffix = current[prefix..]
+        .iter()
+        .rev()
+        .zip(nodes[prefix..].iter().rev())
+        .take_while(|(block, node)| is_kept(block, node))
+        .count();
+
+    let removed = current.len() - prefix - suffix;
+    if removed > 0 {
+        fragment.remove_range(&mut txn, prefix as u32, removed as u32);
+    }
+    insert_nodes(
+        &mut txn,
+        &fragment,
+        prefix as u32,
+        &nodes[prefix..nodes.len() - suffix],
+    )?;
+
+    Ok(txn.encode_update_v1())
+}
+
+fn to_nodes<T: ReadTxn>(txn: &T, node: XmlOut) -> Vec<Value> {
+    match node {
+        XmlOut::Element(element) => {
+            let mut node = Map::new();
+            node.insert("type".to_string(), Value::from(element.tag().as_ref()));
+
+            let attrs = element
+                .attributes(txn)
+                .filter_map(|(key, value)| match value {
+                    Out::Any(value) => Some((key.to_string(), serde_json::to_value(value).ok()?)),
+                    _ => None,
+                })
+                .collect::<Map<_, _>>();
+            if !attrs.is_empty() {
+                node.insert("attrs".to_string(), Value::Object(attrs));
+            }
+
+            let content = element
+                .children(txn)
+                .flat_map(|child| to_nodes(txn, child))
+                .collect::<Vec<_>>();
+            if !content.is_empty() {
+                node.insert("content".to_string(), Value::Array(content));
+            }
+
+            vec![Value::Object(node)]
+        }
+        XmlOut::Text(text) => text_nodes(txn, &text),
+        XmlOut::Fragment(_) => Vec::new(),
+    }
+}
+
+fn text_nodes<T: ReadTxn>(txn: &T, text: &XmlTextRef) -> Vec<Value> {
+    text.diff(txn, YChange::identity)
+        .into_iter()
+        .filter_map(|chunk| {
+            let Out::Any(Any::String(text)) = chunk.insert else {
+                return None;
+            };
+
+            let mut node = Map::new();
+            node.insert("type".to_string(), Value::from("text"));
+            node.insert("text".to_string(), Value::from(text.as_ref()));
+
+            let mut marks = chunk
+                .attributes
+                .iter()
+                .flat_map(|attrs| attrs.iter())
+                .map(|(name, attrs)| {
+                    // Overlapping marks of one type are told apart by a hash suffix
+                    let name = name
+                        .split_once("--")
+                        .map_or(name.as_ref(), |(name, _)| name);
+
+                    let mut mark = Map::new();
+                    mark.insert("type".to_string(), Value::from(name));
+                    if let Ok(Value::Object(attrs)) = serde_json::to_value(attrs)
+                        && !attrs.is_empty()
+                    {
+                        mark.insert("attrs".to_string(), Value::Object(attrs));
+                    }
+
+                    Value::Object(mark)
+                })
+                .collect::<Vec<_>>();
+            marks.sort_by(|a, b| a["type"].as_str().cmp(&b["type"].as_str()));
+            if !marks.is_empty() {
+                node.insert("marks".to_string(), Value::Array(marks));
+            }
+
+            Some(Value::Object(node))
+        })
+        .collect()
+}
+
+fn insert_nodes<F: XmlFragment>(
+    txn: &mut TransactionMut,
+    parent: &F,
+    mut index: u32,
+    nodes: &[Value],
+) -> Result<(), serde_json::Error> {
+    let mut nodes = nodes.iter().peekable();
+
+    while let Some(node) = nodes.next() {
+        if node["type"] == "text" {
+            let text = parent.insert(txn, index, XmlTextPrelim::new(""));
+
+            let mut run = vec![node];
+            while let Some(node) = nodes.next_if(|node| node["type"] == "text") {
+                run.push(node);
+            }
+
+            for node in run {
+                let attrs = node["marks"]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|mark| {
+                        let name = mark["type"].as_str()?;
+                        let attrs = match mark.get("attrs") {
+                            Some(attrs) if !attrs.is_null() => attrs.clone(),
+                            _ => Value::Object(Map::new()),
+                        };
+
+                        Some(serde_json::from_value(attrs).map(|attrs| (name.into(), attrs)))
+                    })
+                    .collect::<Result<Attrs, _>>()?;
+
+                let offset = text.len(txn);
+                text.insert_with_attributes(
+                    txn,
+                    offset,
+                    node["text"].as_str().unwrap_or_default(),
+                    attrs,
+                );
+            }
+        } else {
+            let element = parent.insert(
+                txn,
+                index,
+                XmlElementPrelim::empty(node["type"].as_str().unwrap_or_default()),
+            );
+
+            for (key, value) in node["attrs"].as_object().into_iter().flatten() {
+                if !value.is_null() {
+                    element.insert_attribute(
+                        txn,
+                        key.as_str(),
+                        serde_json::from_value::<Any>(value.clone())?,
+                    );
+                }
+            }
+
+            if let Some(content) = node["content"].as_array() {
+                insert_nodes(txn, &element, 0, content)?;
+            }
+        }
+
+        index += 1;
+    }
+
+    Ok(())
+}
+
+/// Shape a node takes once stored in the document: no null attributes, no empty attributes,
+/// marks nor content, marks in order and adjacent texts with the same marks merged.
+fn normalize(node: &Value) -> Value {
+    let mut normalized = Map::new();
+
+    for key in ["type", "text"] {
+        if let Some(value) = node.get(key) {
+            normalized.insert(key.to_string(), value.clone());
+        }
+    }
+
+    let attrs = node["attrs"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect::<Map<_, _>>();
+    if !attrs.is_empty() {
+        normalized.insert("attrs".to_string(), Value::Object(attrs));
+    }
+
+    let mut marks = node["marks"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(normalize)
+        .collect::<Vec<_>>();
+    marks.sort_by(|a, b| a["type"].as_str().cmp(&b["type"].as_str()));
+    if !marks.is_empty() {
+        normalized.insert("marks".to_string(), Value::Array(marks));
+    }
+
+    let mut content = Vec::<Value>::new();
+    for child in node["content"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(normalize)
+    {
+        match (content.last_mut(), child["text"].as_str()) {
+            (Some(last), Some(text))
+                if last["type"] == "text" && last["marks"] == child["marks"] =>
+            {
+                let merged = format!("{}{}", last["text"].as_str().unwrap_or_default(), text);
+                last["text"] = Value::from(merged);
+            }
+            _ => content.push(child),
+        }
+    }
+    if !content.is_empty() {
+        normalized.insert("content".to_string(), Value::Array(content));
+    }
+
+    Value::Object(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content(value: Value) -> TipTapContent {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn sample() -> TipTapContent {
+        content(serde_json::json!({
+            "type": "doc",
+            "content": [
+                {
+                    "type": "heading",
+                    "attrs": { "level": 2 },
+                    "content": [{ "type": "text", "text": "Title" }],
+                },
+                {
+                    "type": "paragraph",
+                    "content": [
+                        { "type": "text", "text": "Plain, " },
+                        { "type": "text", "text": "bold", "marks": [{ "type": "bold" }] },
+                        {
+                            "type": "text",
+                            "text": " link",
+                            "marks": [
+                                { "type": "italic" },
+                                { "type": "link", "attrs": { "href": "https://example.com" } },
+                            ],
+                        },
+                        {
+                            "type": "mention",
+                            "attrs": { "id": "0b4a9a8e-6e0b-4c5e-9d3e-2f9c1d7b8a61", "label": "Ann" },
+                        },
+                        { "type": "text", "text": " end" },
+                    ],
+                },
+            ],
+        }))
+    }
+
+    fn to_value(content: &TipTapContent) -> Value {
+        serde_json::to_value(content).unwrap()
+    }
+
+    #[test]
+    fn test_content_round_trip() {
+        let doc = Doc::new();
+        replace_content(&doc, Some(&sample())).unwrap();
+
+        assert_eq!(to_value(&to_content(&doc).unwrap()), to_value(&sample()));
+    }
+
+    #[test]
+    fn test_empty_document() {
+        let doc = Doc::new();
+
+        assert_eq!(
+            to_value(&to_content(&doc).unwrap()),
+            serde_json::json!({ "type": "doc", "content": [{ "type": "paragraph" }] })
+        );
+    }
+
+    #[test]
+    fn test_sync_through_state_vectors() {
+        let server = Doc::new();
+        replace_content(&server, Some(&sample())).unwrap();
+
+        let client = Doc::new();
+        let missing = diff(&server, &state_vector(&client)).unwrap();
+        assert!(apply_update(&client, &missing));
+
+        assert_eq!(to_value(&to_content(&client).unwrap()), to_value(&sample()));
+        // Nothing left to send once in sync
+        assert_eq!(diff(&client, &state_vector(&server)).unwrap(), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let doc = Doc::new();
+
+        assert!(!apply_update(&doc, &[0xff, 0xff, 0xff]));
+        assert!(diff(&doc, &[5]).is_none());
+    }
+
+    #[test]
+    fn test_replace_content_keeps_unchanged_blocks() {
+        let server = Doc::new();
+        replace_content(&server, Some(&sample())).unwrap();
+
+        // A client appends a block meanwhile
+        let client = Doc::new();
+        apply_update(&client, &state(&server));
+        let mut edited = to_value(&sample());
+        edited["content"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({ "type": "paragraph", "content": [{ "type": "text", "text": "Client" }] }));
+        let client_update = replace_content(&client, Some(&content(edited))).unwrap();
+
+        // The heading changes outside of the session
+        let mut changed = to_value(&sample());
+        changed["content"][0]["content"][0]["text"] = Value::from("New title");
+        let server_update = replace_content(&server, Some(&content(changed.clone()))).unwrap();
+
+        assert!(apply_update(&server, &client_update));
+        assert!(apply_update(&client, &server_update));
+
+        let mut expected = changed;
+        expected["content"]
+            .as_array_mut()
+            .unwrap()
+            .push(serde_json::json!({ "type": "paragraph", "content": [{ "type": "text", "text": "Client" }] }));
+
+        assert_eq!(to_value(&to_content(&server).unwrap()), expected);
+        assert_eq!(to_value(&to_content(&client).unwrap()), expected);
+    }
+
+    #[test]
+    fn test_replace_content_without_changes() {
+        let doc = Doc::new();
+        replace_content(&doc, Some(&sample())).unwrap();
+        let before = state_vector(&doc);
+
+        // Null attributes are not stored, so they do not count as a change
+        let mut same = to_value(&sample());
+        same["content"][1]["attrs"] = serde_json::json!({ "textAlign": null });
+        replace_content(&doc, Some(&content(same))).unwrap();
+
+        assert_eq!(state_vector(&doc), before);
+    }
+
+    #[test]
+    fn test_replace_content_with_nothing() {
+        let doc = Doc::new();
+        replace_content(&doc, Some(&sample())).unwrap();
+        replace_content(&doc, None).unwrap();
+
+        assert_eq!(
+            to_value(&to_content(&doc).unwrap()),
+            serde_json::json!({ "type": "doc", "content": [{ "type": "paragraph" }] })
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod document;
+mod protocol;
+mod service;
+
+pub use service::{CollaborationRooms, PageCollaborationService};
@@ -0,0 +1,279 @@
+//! Messages of the y-websocket protocol (y-protocols sync, awareness and auth), in lib0 encoding.
+//! Documents and updates are carried as they are, see `document` for their content.
+
+const MESSAGE_SYNC: u64 = 0;
+const MESSAGE_AWARENESS: u64 = 1;
+const MESSAGE_AUTH: u64 = 2;
+const MESSAGE_QUERY_AWARENESS: u64 = 3;
+
+const SYNC_STEP_1: u64 = 0;
+const SYNC_STEP_2: u64 = 1;
+const SYNC_UPDATE: u64 = 2;
+
+const AUTH_PERMISSION_DENIED: u64 = 0;
+
+/// Update without structs nor deletions.
+const EMPTY_UPDATE: &[u8] = &[0, 0];
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(super) enum Message {
+    /// State vector of the sender, answered with the updates it misses.
+    SyncStep1(Vec<u8>),
+    SyncStep2(Vec<u8>),
+    Update(Vec<u8>),
+    Awareness(Vec<u8>),
+    QueryAwareness,
+}
+
+#[cfg_attr(test, derive(Debug, PartialEq))]
+pub(super) struct AwarenessEntry {
+    pub client_id: u64,
+    pub clock: u64,
+    /// JSON state, `null` when the client left.
+    pub state: String,
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn var_uint(&mut self) -> Option<u64> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = *self.bytes.get(self.position)?;
+            self.position += 1;
+
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Some(value);
+            }
+        }
+
+        None
+    }
+
+    fn var_bytes(&mut self) -> Option<&'a [u8]> {
+        let length = usize::try_from(self.var_uint()?).ok()?;
+        let end = self.position.checked_add(length)?;
+        let bytes = self.bytes.get(self.position..end)?;
+        self.position = end;
+
+        Some(bytes)
+    }
+
+    fn var_string(&mut self) -> Option<String> {
+        String::from_utf8(self.var_bytes()?.to_vec()).ok()
+    }
+}
+
+#[derive(Default)]
+struct Encoder {
+    bytes: Vec<u8>,
+}
+
+impl Encoder {
+    fn var_uint(mut self, mut value: u64) -> Self {
+        while value > 0x7f {
+            self.bytes.push((value & 0x7f) as u8 | 0x80);
+            value >>= 7;
+        }
+        self.bytes.push(value as u8);
+
+        self
+    }
+
+    fn var_bytes(self, bytes: &[u8]) -> Self {
+        let mut encoder = self.var_uint(bytes.len() as u64);
+        encoder.bytes.extend_from_slice(bytes);
+
+        encoder
+    }
+
+    fn var_string(self, value: &str) -> Self {
+        self.var_bytes(value.as_bytes())
+    }
+}
+
+/// Returns `None` for malformed and unknown messages.
+pub(super) fn decode(bytes: &[u8]) -> Option<Message> {
+    let mut decoder = Decoder::new(bytes);
+
+    let message = match decoder.var_uint()? {
+        MESSAGE_SYNC => match decoder.var_uint()? {
+            SYNC_STEP_1 => Message::SyncStep1(decoder.var_bytes()?.to_vec()),
+            SYNC_STEP_2 => Message::SyncStep2(decoder.var_bytes()?.to_vec()),
+            SYNC_UPDATE => Message::Update(decoder.var_bytes()?.to_vec()),
+            _ => return None,
+        },
+        MESSAGE_AWARENESS => Message::Awareness(decoder.var_bytes()?.to_vec()),
+        MESSAGE_QUERY_AWARENESS => Message::QueryAwareness,
+        _ => return None,
+    };
+
+    Some(message)
+}
+
+pub(super) fn is_empty_update(update: &[u8]) -> bool {
+    update == EMPTY_UPDATE
+}
+
+/// Asks the client for the updates missing from a document at `state_vector`.
+pub(super) fn encode_sync_step_1(state_vector: &[u8]) -> Vec<u8> {
+    Encoder::default()
+        .var_uint(MESSAGE_SYNC)
+        .var_uint(SYNC_STEP_1)
+        .var_bytes(state_vector)
+        .bytes
+}
+
+/// Answers a sync step 1 with the updates the client misses, which marks it as synced.
+pub(super) fn encode_sync_step_2(update: &[u8]) -> Vec<u8> {
+    Encoder::default()
+        .var_uint(MESSAGE_SYNC)
+        .var_uint(SYNC_STEP_2)
+        .var_bytes(update)
+        .bytes
+}
+
+pub(super) fn encode_update(update: &[u8]) -> Vec<u8> {
+    Encoder::default()
+        .var_uint(MESSAGE_SYNC)
+        .var_uint(SYNC_UPDATE)
+        .var_bytes(update)
+        .bytes
+}
+
+pub(super) fn encode_awareness(update: &[u8]) -> Vec<u8> {
+    Encoder::default()
+        .var_uint(MESSAGE_AWARENESS)
+        .var_bytes(update)
+        .bytes
+}
+
+pub(super) fn encode_permission_denied(reason: &str) -> Vec<u8> {
+    Encoder::default()
+        .var_uint(MESSAGE_AUTH)
+        .var_uint(AUTH_PERMISSION_DENIED)
+        .var_string(reason)
+        .bytes
+}
+
+pub(super) fn decode_awareness_update(update: &[u8]) -> Option<Vec<AwarenessEntry>> {
+    let mut decoder = Decoder::new(update);
+    let count = decoder.var_uint()?;
+
+    (0..count)
+        .map(|_| {
+            Some(AwarenessEntry {
+                client_id: decoder.var_uint()?,
+                clock: decoder.var_uint()?,
+                state: decoder.var_string()?,
+            })
+        })
+        .collect()
+}
+
+pub(super) fn encode_awareness_update<'a>(
+    entries: impl ExactSizeIterator<Item = &'a AwarenessEntry>,
+) -> Vec<u8> {
+    let encoder = Encoder::default().var_uint(entries.len() as u64);
+
+    entries
+        .fold(encoder, |encoder, entry| {
+            encoder
+                .var_uint(entry.client_id)
+                .var_uint(entry.clock)
+                .var_string(&entry.state)
+        })
+        .bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_messages_round_trip() {
+        let update = vec![1, 2, 3, 0xff];
+
+        assert_eq!(
+            decode(&encode_sync_step_1(&[1, 5, 7])),
+            Some(Message::SyncStep1(vec![1, 5, 7]))
+        );
+        assert_eq!(
+            decode(&encode_sync_step_2(&update)),
+            Some(Message::SyncStep2(update.clone()))
+        );
+        assert_eq!(
+            decode(&encode_update(&update)),
+            Some(Message::Update(update))
+        );
+    }
+
+    #[test]
+    fn test_awareness_round_trip() {
+        let entries = vec![
+            AwarenessEntry {
+                client_id: 3_000_000_000,
+                clock: 7,
+                state: r#"{"user":{"name":"Zoé"}}"#.to_string(),
+            },
+            AwarenessEntry {
+                client_id: 1,
+                clock: 0,
+                state: "null".to_string(),
+            },
+        ];
+
+        let update = encode_awareness_update(entries.iter());
+
+        assert_eq!(
+            decode(&encode_awareness(&update)),
+            Some(Message::Awareness(update.clone()))
+        );
+        assert_eq!(decode_awareness_update(&update), Some(entries));
+    }
+
+    #[test]
+    fn test_var_uint_round_trip() {
+        for value in [0, 1, 0x7f, 0x80, 0x3fff, 0x4000, u32::MAX as u64, u64::MAX] {
+            let bytes = Encoder::default().var_uint(value).bytes;
+
+            assert_eq!(Decoder::new(&bytes).var_uint(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_query_awareness() {
+        assert_eq!(decode(&[3]), Some(Message::QueryAwareness));
+    }
+
+    #[test]
+    fn test_malformed_messages() {
+        // Unknown message and sync types
+        assert_eq!(decode(&[100, 1, b'x']), None);
+        assert_eq!(decode(&[0, 3, 0]), None);
+        // Length past the end of the message
+        assert_eq!(decode(&[0, 2, 5, 1, 2]), None);
+        // Unterminated var uint
+        assert_eq!(decode(&[0x80]), None);
+        assert_eq!(decode(&[]), None);
+        // Auth messages are only sent by the server
+        assert_eq!(decode(&encode_permission_denied("denied")), None);
+
+        assert_eq!(decode_awareness_update(&[2, 1, 0, 0]), None);
+    }
+
+    #[test]
+    fn test_empty_update() {
+        assert!(is_empty_update(&[0, 0]));
+        assert!(!is_empty_update(&[1, 0]));
+    }
+}
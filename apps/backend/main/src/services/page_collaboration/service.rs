@@ -0,0 +1,665 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::extract::ws::{Message as WsMessage, WebSocket};
+use error_handlers::{codes, handlers::ErrorResponse};
+use futures_util::{SinkExt, StreamExt};
+use sql::shared::tiptap_content::TipTapContent;
+use sqlx::{PgConnection, PgPool};
+use tokio::{
+    sync::{OnceCell, broadcast, mpsc},
+    time::Instant,
+};
+use tracing::warn;
+use uuid::Uuid;
+use yrs::Doc;
+
+use crate::{
+    repos::{pages::PageRepository, text_page_updates::TextPageUpdateRepository},
+    services::{links::LinkService, mentions::MentionService, page_versions::PageVersionService},
+    types::app_state::AppState,
+};
+
+use super::{
+    document,
+    protocol::{self, AwarenessEntry, Message},
+};
+
+/// Messages a room buffers for slow clients, a client lagging further behind is disconnected
+/// and resyncs when it reconnects.
+const ROOM_CAPACITY: usize = 256;
+
+/// Time without changes after which the document is saved as the page content.
+const SAVE_DELAY: Duration = Duration::from_secs(2);
+/// Longest time changes go unsaved while the document keeps changing.
+const MAX_SAVE_DELAY: Duration = Duration::from_secs(30);
+
+struct Document {
+    doc: Doc,
+    /// Stored updates the document contains, saving replaces them with the whole document.
+    update_ids: Vec<i64>,
+    /// Last user who changed the document since it was saved, the author of the next version.
+    author_id: Option<Uuid>,
+}
+
+/// Loaded by the first connection of the room.
+type SharedDocument = Arc<OnceCell<tokio::sync::Mutex<Document>>>;
+
+#[derive(Clone)]
+struct Broadcast {
+    connection_id: Uuid,
+    message: Arc<[u8]>,
+}
+
+struct AwarenessState {
+    connection_id: Uuid,
+    clock: u64,
+    state: String,
+}
+
+struct Room {
+    sender: broadcast::Sender<Broadcast>,
+    /// Latest awareness state of every client in the room, by Yjs client id.
+    awareness: HashMap<u64, AwarenessState>,
+    document: SharedDocument,
+    /// Wakes the task saving the document. Dropped with the room, the task then saves one
+    /// last time and ends.
+    changes: mpsc::Sender<()>,
+}
+
+impl Room {
+    fn awareness_message(&self) -> Option<Vec<u8>> {
+        if self.awareness.is_empty() {
+            return None;
+        }
+
+        let entries = self
+            .awareness
+            .iter()
+            .map(|(client_id, state)| AwarenessEntry {
+                client_id: *client_id,
+                clock: state.clock,
+                state: state.state.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        Some(protocol::encode_awareness(
+            &protocol::encode_awareness_update(entries.iter()),
+        ))
+    }
+}
+
+struct Membership {
+    receiver: broadcast::Receiver<Broadcast>,
+    awareness: Option<Vec<u8>>,
+    document: SharedDocument,
+    changes: mpsc::Sender<()>,
+}
+
+/// Clients editing a page together, rooms only live in the memory of this instance.
+#[derive(Clone, Default)]
+pub struct CollaborationRooms(Arc<Mutex<HashMap<Uuid, Room>>>);
+
+impl CollaborationRooms {
+    fn rooms(&self) -> std::sync::MutexGuard<'_, HashMap<Uuid, Room>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the room subscription, the current awareness of the room, its document and
+    /// the sender of its changes. Opening a room starts the task saving its document.
+    fn join(&self, postgres: &PgPool, page_id: Uuid) -> Membership {
+        let mut rooms = self.rooms();
+        let room = rooms.entry(page_id).or_insert_with(|| {
+            let document = SharedDocument::default();
+            let (changes, receiver) = mpsc::channel(1);
+
+            tokio::spawn(PageCollaborationService::save_changes(
+                postgres.clone(),
+                self.clone(),
+                page_id,
+                document.clone(),
+                receiver,
+            ));
+
+            Room {
+                sender: broadcast::channel(ROOM_CAPACITY).0,
+                awareness: HashMap::new(),
+                document,
+                changes,
+            }
+        });
+
+        Membership {
+            receiver: room.sender.subscribe(),
+            awareness: room.awareness_message(),
+            document: room.document.clone(),
+            changes: room.changes.clone(),
+        }
+    }
+
+    fn document(&self, page_id: Uuid) -> Option<SharedDocument> {
+        Some(self.rooms().get(&page_id)?.document.clone())
+    }
+
+    fn broadcast(&self, page_id: Uuid, connection_id: Uuid, message: Vec<u8>) {
+        if let Some(room) = self.rooms().get(&page_id) {
+            // Fails only when nobody else is in the room
+            let _ = room.sender.send(Broadcast {
+                connection_id,
+                message: message.into(),
+            });
+        }
+    }
+
+    fn awareness(&self, page_id: Uuid) -> Option<Vec<u8>> {
+        self.rooms().get(&page_id)?.awareness_message()
+    }
+
+    fn update_awareness(&self, page_id: Uuid, connection_id: Uuid, entries: Vec<AwarenessEntry>) {
+        let mut rooms = self.rooms();
+        let Some(room) = rooms.get_mut(&page_id) else {
+            return;
+        };
+
+        for entry in entries {
+            if entry.state == "null" {
+                room.awareness.remove(&entry.client_id);
+            } else {
+                room.awareness.insert(
+                    entry.client_id,
+                    AwarenessState {
+                        connection_id,
+                        clock: entry.clock,
+                        state: entry.state,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Clears the awareness of the connection, telling the others it left, and closes
+    /// the room once empty. The receiver of the connection must be dropped first.
+    fn leave(&self, page_id: Uuid, connection_id: Uuid) {
+        let mut rooms = self.rooms();
+        let Some(room) = rooms.get_mut(&page_id) else {
+            return;
+        };
+
+        let left = room
+            .awareness
+            .extract_if(|_, state| state.connection_id == connection_id)
+            .map(|(client_id, state)| AwarenessEntry {
+                client_id,
+                clock: state.clock + 1,
+                state: "null".to_string(),
+            })
+            .collect::<Vec<_>>();
+
+        if room.sender.receiver_count() == 0 {
+            rooms.remove(&page_id);
+            return;
+        }
+
+        if !left.is_empty() {
+            let _ = room.sender.send(Broadcast {
+                connection_id,
+                message: protocol::encode_awareness(&protocol::encode_awareness_update(
+                    left.iter(),
+                ))
+                .into(),
+            });
+        }
+    }
+}
+
+struct Connection {
+    state: AppState,
+    page_id: Uuid,
+    user_id: Uuid,
+    id: Uuid,
+    can_edit: bool,
+    document: SharedDocument,
+    changes: mpsc::Sender<()>,
+}
+
+impl Connection {
+    async fn document(&self) -> Result<tokio::sync::MutexGuard<'_, Document>, ErrorResponse> {
+        PageCollaborationService::document(&self.state.postgres, self.page_id, &self.document).await
+    }
+
+    /// Applies an update to the document, stores it and relays it to the other clients.
+    async fn update(&self, update: Vec<u8>) -> Result<(), ErrorResponse> {
+        if protocol::is_empty_update(&update) {
+            return Ok(());
+        }
+
+        let mut document = self.document().await?;
+
+        if !document::apply_update(&document.doc, &update) {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidBody,
+                None,
+                Some("Malformed update".to_string()),
+            ));
+        }
+
+        let stored =
+            TextPageUpdateRepository::create(&self.state.postgres, self.page_id, &update).await?;
+        document.update_ids.push(stored.id);
+        document.author_id = Some(self.user_id);
+
+        drop(document);
+
+        // A save is already pending when full
+        let _ = self.changes.try_send(());
+
+        self.state.collaboration_rooms.broadcast(
+            self.page_id,
+            self.id,
+            protocol::encode_update(&update),
+        );
+
+        Ok(())
+    }
+
+    /// Handles a client message, returns the answer to send back if any.
+    async fn message(&mut self, message: Message) -> Result<Option<Vec<u8>>, ErrorResponse> {
+        let rooms = self.state.collaboration_rooms.clone();
+
+        match message {
+            Message::SyncStep1(state_vector) => {
+                let document = self.document().await?;
+
+                let Some(update) = document::diff(&document.doc, &state_vector) else {
+                    return Err(ErrorResponse::bad_request(
+                        codes::BadRequestErrorCode::InvalidBody,
+                        None,
+                        Some("Malformed state vector".to_string()),
+                    ));
+                };
+
+                return Ok(Some(protocol::encode_sync_step_2(&update)));
+            }
+            Message::SyncStep2(_) | Message::Update(_) if !self.can_edit => {
+                return Ok(Some(protocol::encode_permission_denied(
+                    "Insufficient permissions",
+                )));
+            }
+            Message::SyncStep2(update) | Message::Update(update) => self.update(update).await?,
+            Message::Awareness(update) => {
+                if let Some(entries) = protocol::decode_awareness_update(&update) {
+                    rooms.update_awareness(self.page_id, self.id, entries);
+                    rooms.broadcast(self.page_id, self.id, protocol::encode_awareness(&update));
+                }
+            }
+            Message::QueryAwareness => return Ok(rooms.awareness(self.page_id)),
+        }
+
+        Ok(None)
+    }
+}
+
+pub struct PageCollaborationService;
+
+impl PageCollaborationService {
+    // QUERIES
+
+    /// Locks the document of a room, loading it first if needed.
+    async fn document<'a>(
+        postgres: &PgPool,
+        page_id: Uuid,
+        document: &'a SharedDocument,
+    ) -> Result<tokio::sync::MutexGuard<'a, Document>, ErrorResponse> {
+        let document = document
+            .get_or_try_init(|| async {
+                Self::load(postgres, page_id)
+                    .await
+                    .map(tokio::sync::Mutex::new)
+            })
+            .await?;
+
+        Ok(document.lock().await)
+    }
+
+    /// Latest content of a text page: the collaborative document when sessions recorded
+    /// changes, the saved content otherwise. Locks the content row like `replace_content`.
+    pub async fn get_latest_content(
+        executor: &mut PgConnection,
+        page_id: Uuid,
+    ) -> Result<Option<TipTapContent>, ErrorResponse> {
+        let content =
+            PageRepository::get_text_page_content_for_update(&mut *executor, page_id).await?;
+        let updates = TextPageUpdateRepository::get_all_by_page_id(&mut *executor, page_id).await?;
+
+        if updates.is_empty() {
+            return Ok(content.content.0);
+        }
+
+        let doc = Doc::new();
+        for update in &updates {
+            document::apply_update(&doc, &update.data);
+        }
+
+        Ok(Some(document::to_content(&doc)?))
+    }
+
+    /// Builds the document from the stored updates. The first session of a page starts from
+    /// the page content, stored as the first update.
+    async fn load(postgres: &PgPool, page_id: Uuid) -> Result<Document, ErrorResponse> {
+        let mut tx = postgres.begin().await?;
+
+        // Keeps two instances from both starting the document
+        let content = PageRepository::get_text_page_content_for_update(&mut *tx, page_id).await?;
+        let updates = TextPageUpdateRepository::get_all_by_page_id(&mut *tx, page_id).await?;
+
+        let doc = Doc::new();
+
+        let update_ids = if updates.is_empty() {
+            let update = document::replace_content(&doc, content.content.0.as_ref())?;
+
+            vec![
+                TextPageUpdateRepository::create(&mut *tx, page_id, &update)
+                    .await?
+                    .id,
+            ]
+        } else {
+            for update in &updates {
+                if !document::apply_update(&doc, &update.data) {
+                    warn!("Skipped malformed update {} of page {}", update.id, page_id);
+                }
+            }
+
+            updates.iter().map(|update| update.id).collect()
+        };
+
+        tx.commit().await?;
+
+        Ok(Document {
+            doc,
+            update_ids,
+            author_id: None,
+        })
+    }
+
+    // COMMANDS
+
+    /// Records content written outside of a collaboration session as a change of the
+    /// collaborative document, so sessions do not revert it. Must run in the transaction
+    /// writing the content, open rooms pick the change up with `refresh` once committed.
+    pub async fn replace_content(
+        executor: &mut PgConnection,
+        page_id: Uuid,
+        content: Option<&TipTapContent>,
+    ) -> Result<(), ErrorResponse> {
+        // Keeps sessions from saving the document meanwhile
+        PageRepository::get_text_page_content_for_update(&mut *executor, page_id).await?;
+
+        let updates = TextPageUpdateRepository::get_all_by_page_id(&mut *executor, page_id).await?;
+
+        // The first session of the page starts from its content
+        if updates.is_empty() {
+            return Ok(());
+        }
+
+        let doc = Doc::new();
+        for update in &updates {
+            document::apply_update(&doc, &update.data);
+        }
+
+        let update = document::replace_content(&doc, content)?;
+
+        if !protocol::is_empty_update(&update) {
+            TextPageUpdateRepository::create(&mut *executor, page_id, &update).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends the changes recorded by `replace_content` to the open room of the page.
+    pub async fn refresh(state: &AppState, page_id: Uuid) {
+        let rooms = &state.collaboration_rooms;
+
+        let Some(document) = rooms.document(page_id) else {
+            return;
+        };
+        let Some(document) = document.get() else {
+            return;
+        };
+        let mut document = document.lock().await;
+
+        if let Err(e) = Self::pick_up(&state.postgres, rooms, page_id, &mut document).await {
+            warn!(
+                "Failed to refresh the document of page {}: {}",
+                page_id,
+                e.dev_details.unwrap_or(e.error.to_string())
+            );
+        }
+    }
+
+    /// Applies the updates stored outside of the session to the document and relays them
+    /// to the room.
+    async fn pick_up<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        rooms: &CollaborationRooms,
+        page_id: Uuid,
+        document: &mut Document,
+    ) -> Result<(), ErrorResponse> {
+        let updates = TextPageUpdateRepository::get_all_by_page_id_except(
+            executor,
+            page_id,
+            &document.update_ids,
+        )
+        .await?;
+
+        for update in updates {
+            if document::apply_update(&document.doc, &update.data) {
+                rooms.broadcast(page_id, Uuid::nil(), protocol::encode_update(&update.data));
+            }
+            document.update_ids.push(update.id);
+        }
+
+        Ok(())
+    }
+
+    /// Serves one client of the collaborative editing of a text page until it disconnects.
+    /// Editors are asked for the changes the server misses, every client is answered with
+    /// the changes it misses.
+    pub async fn serve(
+        state: AppState,
+        socket: WebSocket,
+        page_id: Uuid,
+        user_id: Uuid,
+        can_edit: bool,
+    ) {
+        let rooms = state.collaboration_rooms.clone();
+
+        // Joining first, so no update applied meanwhile is missed
+        let Membership {
+            mut receiver,
+            awareness,
+            document,
+            changes,
+        } = rooms.join(&state.postgres, page_id);
+
+        let connection = Connection {
+            state,
+            page_id,
+            user_id,
+            id: Uuid::new_v4(),
+            can_edit,
+            document,
+            changes,
+        };
+        let connection_id = connection.id;
+
+        Self::relay(socket, &mut receiver, awareness, connection).await;
+
+        drop(receiver);
+        rooms.leave(page_id, connection_id);
+    }
+
+    async fn relay(
+        socket: WebSocket,
+        receiver: &mut broadcast::Receiver<Broadcast>,
+        awareness: Option<Vec<u8>>,
+        mut connection: Connection,
+    ) {
+        let (mut sink, mut stream) = socket.split();
+        let page_id = connection.page_id;
+
+        let state_vector = match connection.document().await {
+            Ok(document) => document::state_vector(&document.doc),
+            Err(e) => {
+                warn!(
+                    "Failed to load the document of page {}: {}",
+                    page_id,
+                    e.dev_details.unwrap_or(e.error.to_string())
+                );
+                return;
+            }
+        };
+
+        let mut initial = awareness.into_iter().collect::<Vec<_>>();
+        if connection.can_edit {
+            initial.push(protocol::encode_sync_step_1(&state_vector));
+        }
+
+        for message in initial {
+            if sink.send(WsMessage::Binary(message.into())).await.is_err() {
+                return;
+            }
+        }
+
+        loop {
+            tokio::select! {
+                incoming = stream.next() => {
+                    let bytes = match incoming {
+                        Some(Ok(WsMessage::Binary(bytes))) => bytes,
+                        Some(Ok(WsMessage::Close(_))) | Some(Err(_)) | None => return,
+                        Some(Ok(_)) => continue,
+                    };
+
+                    let Some(message) = protocol::decode(&bytes) else {
+                        continue;
+                    };
+
+                    match connection.message(message).await {
+                        Ok(Some(answer)) => {
+                            if sink.send(WsMessage::Binary(answer.into())).await.is_err() {
+                                return;
+                            }
+                        }
+                        Ok(None) => {}
+                        Err(e) => warn!(
+                            "Collaboration message of page {} rejected: {}",
+                            page_id, e.dev_details.unwrap_or(e.error.to_string())
+                        ),
+                    }
+                }
+                broadcast = receiver.recv() => {
+                    let broadcast = match broadcast {
+                        Ok(broadcast) => broadcast,
+                        // Missed updates cannot be recovered, the client resyncs on reconnection
+                        Err(broadcast::error::RecvError::Lagged(_)) => {
+                            let _ = sink.send(WsMessage::Close(None)).await;
+                            return;
+                        }
+                        Err(broadcast::error::RecvError::Closed) => return,
+                    };
+
+                    if broadcast.connection_id == connection.id {
+                        continue;
+                    }
+
+                    if sink.send(WsMessage::Binary(broadcast.message.to_vec().into())).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Saves the document once its changes settle, until the room closes.
+    async fn save_changes(
+        postgres: PgPool,
+        rooms: CollaborationRooms,
+        page_id: Uuid,
+        document: SharedDocument,
+        mut changes: mpsc::Receiver<()>,
+    ) {
+        while changes.recv().await.is_some() {
+            let deadline = Instant::now() + MAX_SAVE_DELAY;
+
+            while let Ok(Some(())) =
+                tokio::time::timeout_at((Instant::now() + SAVE_DELAY).min(deadline), changes.recv())
+                    .await
+            {}
+
+            if let Err(e) = Self::save(&postgres, &rooms, page_id, &document).await {
+                warn!(
+                    "Failed to save the document of page {}: {}",
+                    page_id,
+                    e.dev_details.unwrap_or(e.error.to_string())
+                );
+            }
+        }
+    }
+
+    /// Saves the document as the page content, with its version, mentions and links, and
+    /// replaces the stored updates it contains with the whole document.
+    async fn save(
+        postgres: &PgPool,
+        rooms: &CollaborationRooms,
+        page_id: Uuid,
+        document: &SharedDocument,
+    ) -> Result<(), ErrorResponse> {
+        let Some(document) = document.get() else {
+            return Ok(());
+        };
+        // Held until saved, updates of the clients wait meanwhile
+        let mut document = document.lock().await;
+
+        let mut tx = postgres.begin().await?;
+
+        PageRepository::get_text_page_content_for_update(&mut *tx, page_id).await?;
+
+        // Content written outside of the session meanwhile is part of the saved document
+        Self::pick_up(&mut *tx, rooms, page_id, &mut document).await?;
+
+        if let Some(author_id) = document.author_id {
+            let content = document::to_content(&document.doc)?;
+
+            match content.validate() {
+                Ok(()) => {
+                    PageVersionService::record(&mut tx, page_id, author_id, Some(&content), true)
+                        .await?;
+                    MentionService::sync(&mut *tx, page_id, None, author_id, Some(&content))
+                        .await?;
+                    LinkService::sync(&mut *tx, page_id, None, Some(&content)).await?;
+
+                    PageRepository::update_content(&mut *tx, page_id, Some(content)).await?;
+                }
+                Err(errors) => warn!(
+                    "Document of page {} not saved as its content, {} schema errors",
+                    page_id,
+                    errors.len()
+                ),
+            }
+        }
+
+        TextPageUpdateRepository::delete_many(&mut *tx, page_id, &document.update_ids).await?;
+        let stored =
+            TextPageUpdateRepository::create(&mut *tx, page_id, &document::state(&document.doc))
+                .await?;
+
+        tx.commit().await?;
+
+        document.update_ids = vec![stored.id];
+        document.author_id = None;
+
+        Ok(())
+    }
+}
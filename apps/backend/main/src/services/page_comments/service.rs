@@ -0,0 +1,306 @@
+use std::collections::{HashMap, HashSet};
+
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    page::model::{PageAccess, PageType, Role},
+    page_comments::model::PageComment,
+    user::model::User,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_comments::dto::{
+        CreatePageCommentReplyRequest, CreatePageCommentRequest, PageCommentResponse,
+        PageCommentThreadResponse, UpdatePageCommentRequest,
+    },
+    repos::{
+        page_comments::{CreatePageCommentDto, PageCommentRepository},
+        pages::PageRepository,
+        users::UserRepository,
+    },
+    services::page_collaboration::PageCollaborationService,
+};
+
+fn insufficient_permissions() -> ErrorResponse {
+    ErrorResponse::forbidden(
+        codes::ForbiddenErrorCode::InsufficientPermissions,
+        Some(HashMap::from([(
+            "message".to_string(),
+            "Insufficient permissions".to_string(),
+        )])),
+        None,
+    )
+}
+
+fn not_a_thread() -> ErrorResponse {
+    ErrorResponse::bad_request(
+        codes::BadRequestErrorCode::InvalidParams,
+        Some(HashMap::from([(
+            "commentId".to_string(),
+            "Replies are not threads, use the first comment of the thread".to_string(),
+        )])),
+        None,
+    )
+}
+
+pub struct PageCommentService;
+
+impl PageCommentService {
+    // QUERIES
+
+    /// Threads of the page with their replies, oldest first. `resolved` filters the threads by
+    /// their resolution, threads anchored in the current content are flagged.
+    pub async fn get_threads(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        resolved: Option<bool>,
+    ) -> Result<Vec<PageCommentThreadResponse>, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(pool, page_id).await?;
+        let comments = PageCommentRepository::get_all_by_page_id(pool, page_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let anchored = match page.r#type {
+            PageType::Text => PageRepository::get_text_page_content(pool, page_id)
+                .await?
+                .content
+                .0
+                .map(|content| content.comment_ids())
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            _ => HashSet::new(),
+        };
+
+        let authors = Self::get_authors(pool, &comments).await?;
+
+        let (threads, replies): (Vec<_>, Vec<_>) = comments
+            .into_iter()
+            .partition(|comment| comment.parent_comment_id.is_none());
+
+        let mut replies_by_thread: HashMap<Uuid, Vec<PageCommentResponse>> = HashMap::new();
+        for reply in replies {
+            if let Some(thread_id) = reply.parent_comment_id {
+                replies_by_thread
+                    .entry(thread_id)
+                    .or_default()
+                    .push(PageCommentResponse::new(reply, &authors));
+            }
+        }
+
+        Ok(threads
+            .into_iter()
+            .filter(|thread| {
+                resolved.is_none_or(|resolved| thread.resolved_at.is_some() == resolved)
+            })
+            .map(|thread| PageCommentThreadResponse {
+                is_anchored: anchored.contains(&thread.id),
+                replies: replies_by_thread.remove(&thread.id).unwrap_or_default(),
+                comment: PageCommentResponse::new(thread, &authors),
+            })
+            .collect())
+    }
+
+    /// Comments of other pages are reported as not found.
+    pub async fn get_one<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        comment_id: Uuid,
+    ) -> Result<PageComment, ErrorResponse> {
+        let comment = PageCommentRepository::get_one_by_id(executor, comment_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        if comment.page_id != page_id {
+            return Err(ErrorResponse::not_found(
+                codes::NotFoundErrorCode::NotFound,
+                None,
+                Some("Page comment not found".to_string()),
+            ));
+        }
+
+        Ok(comment)
+    }
+
+    /// Authors of the given comments, keyed by user id.
+    pub async fn get_authors(
+        pool: &sqlx::PgPool,
+        comments: &[PageComment],
+    ) -> Result<HashMap<Uuid, User>, ErrorResponse> {
+        let mut user_ids = comments.iter().map(|c| c.author_id).collect::<Vec<_>>();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+
+        let users = UserRepository::get_users_by_ids(pool, &user_ids)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(users.into_iter().map(|u| (u.id, u)).collect())
+    }
+
+    // COMMANDS
+
+    /// Starts a thread on a text page, commentators and above can comment. The anchor is
+    /// marked in the content in the same transaction, open rooms pick it up with
+    /// `PageCollaborationService::refresh` once committed.
+    pub async fn create(
+        executor: &mut PgConnection,
+        page_access: &PageAccess,
+        dto: CreatePageCommentRequest,
+    ) -> Result<PageComment, ErrorResponse> {
+        if page_access.role < Role::Commentator {
+            return Err(insufficient_permissions());
+        }
+
+        let page = PageRepository::get_one_by_id(&mut *executor, page_access.page_id).await?;
+
+        if page.r#type != PageType::Text {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "pageId".to_string(),
+                    "Comments can only be added to text pages".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let comment = PageCommentRepository::create(
+            &mut *executor,
+            CreatePageCommentDto {
+                page_id: page.id,
+                author_id: page_access.user_id,
+                parent_comment_id: None,
+                text: dto.text,
+                quote: dto.quote.filter(|quote| !quote.trim().is_empty()),
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        let Some(anchor) = dto.anchor else {
+            return Ok(comment);
+        };
+
+        let mut content =
+            PageCollaborationService::get_latest_content(&mut *executor, page.id).await?;
+        let quote = content
+            .as_mut()
+            .and_then(|content| content.add_comment_mark(comment.id, anchor.from, anchor.to))
+            .ok_or_else(|| {
+                ErrorResponse::bad_request(
+                    codes::BadRequestErrorCode::InvalidBody,
+                    Some(HashMap::from([(
+                        "anchor".to_string(),
+                        "The range holds no text of the page".to_string(),
+                    )])),
+                    None,
+                )
+            })?;
+
+        PageCollaborationService::replace_content(&mut *executor, page.id, content.as_ref())
+            .await?;
+        PageRepository::update_content(&mut *executor, page.id, content)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        PageCommentRepository::update_quote(&mut *executor, comment.id, quote)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn reply(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        comment_id: Uuid,
+        dto: CreatePageCommentReplyRequest,
+    ) -> Result<PageComment, ErrorResponse> {
+        if page_access.role < Role::Commentator {
+            return Err(insufficient_permissions());
+        }
+
+        let thread = Self::get_one(pool, page_access.page_id, comment_id).await?;
+
+        if thread.parent_comment_id.is_some() {
+            return Err(not_a_thread());
+        }
+
+        PageCommentRepository::create(
+            pool,
+            CreatePageCommentDto {
+                page_id: thread.page_id,
+                author_id: page_access.user_id,
+                parent_comment_id: Some(thread.id),
+                text: dto.text,
+                quote: None,
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    /// Only authors can edit their comments.
+    pub async fn update(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        comment_id: Uuid,
+        dto: UpdatePageCommentRequest,
+    ) -> Result<PageComment, ErrorResponse> {
+        let comment = Self::get_one(pool, page_access.page_id, comment_id).await?;
+
+        if page_access.role < Role::Commentator || comment.author_id != page_access.user_id {
+            return Err(insufficient_permissions());
+        }
+
+        PageCommentRepository::update_text(pool, comment.id, dto.text)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Authors can delete their comments, page admins can delete every comment.
+    /// Deleting the first comment of a thread deletes the thread.
+    pub async fn delete(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        comment_id: Uuid,
+    ) -> Result<PageComment, ErrorResponse> {
+        let comment = Self::get_one(pool, page_access.page_id, comment_id).await?;
+
+        let is_author =
+            comment.author_id == page_access.user_id && page_access.role >= Role::Commentator;
+        if !is_author && page_access.role < Role::Admin {
+            return Err(insufficient_permissions());
+        }
+
+        PageCommentRepository::delete(pool, comment.id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Resolves or reopens a thread, commentators and above can resolve any thread.
+    pub async fn set_resolved(
+        pool: &sqlx::PgPool,
+        page_access: &PageAccess,
+        comment_id: Uuid,
+        resolved: bool,
+    ) -> Result<PageComment, ErrorResponse> {
+        if page_access.role < Role::Commentator {
+            return Err(insufficient_permissions());
+        }
+
+        let thread = Self::get_one(pool, page_access.page_id, comment_id).await?;
+
+        if thread.parent_comment_id.is_some() {
+            return Err(not_a_thread());
+        }
+
+        PageCommentRepository::set_resolved(
+            pool,
+            thread.id,
+            resolved.then_some(page_access.user_id),
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+}
@@ -0,0 +1,361 @@
+use std::collections::{HashMap, VecDeque};
+
+use error_handlers::handlers::ErrorResponse;
+use sql::{
+    assets::model::{Asset, EntityType},
+    board_statuses::model::BoardStatus,
+    page::model::{Page, PageType},
+    page_templates::model::TemplateBoardStatus,
+    shared::tiptap_content::TipTapContent,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{DuplicatePageRequest, MovePageRequest},
+    repos::{
+        assets::{AssetsRepository, CreateAssetDto},
+        board_statuses::BoardStatusRepository,
+        checklist_items::{
+            ChecklistItemRepository, CreateChecklistItemDto, UpdateChecklistItemDto,
+        },
+        pages::{CreatePageDto, PageRepository},
+        tasks::{CreateTaskDto, TaskRepository},
+    },
+    services::{links::LinkService, mentions::MentionService, pages::PageService},
+};
+
+/// Assets referenced by a copied document, each with the id of its copy.
+struct AssetCopies(Vec<(Asset, Uuid)>);
+
+impl AssetCopies {
+    async fn plan(
+        executor: &mut PgConnection,
+        entity_type: EntityType,
+        entity_id: Uuid,
+        content: Option<&TipTapContent>,
+    ) -> Result<Self, ErrorResponse> {
+        let Some(content) = content else {
+            return Ok(Self(Vec::new()));
+        };
+
+        let referenced = content.file_asset_ids();
+        if referenced.is_empty() {
+            return Ok(Self(Vec::new()));
+        }
+
+        let assets = AssetsRepository::get_by_entity(&mut *executor, entity_type, entity_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(Self(
+            assets
+                .into_iter()
+                .filter(|asset| referenced.contains(&asset.id))
+                .map(|asset| (asset, Uuid::new_v4()))
+                .collect(),
+        ))
+    }
+
+    fn copy_of(&self, asset_id: Uuid) -> Option<Uuid> {
+        self.0
+            .iter()
+            .find(|(asset, _)| asset.id == asset_id)
+            .map(|(_, copy_id)| *copy_id)
+    }
+
+    /// Points the file nodes of the copied document to the asset copies.
+    fn remap(&self, content: Option<&mut TipTapContent>) {
+        let Some(content) = content else {
+            return;
+        };
+        if self.0.is_empty() {
+            return;
+        }
+
+        content.update_file_nodes(|attrs| {
+            let Some(id) = attrs.id else {
+                return;
+            };
+            let Some(copy_id) = self.copy_of(id) else {
+                return;
+            };
+
+            attrs.id = Some(copy_id);
+            for url in [&mut attrs.src, &mut attrs.href].into_iter().flatten() {
+                *url = url.replace(&id.to_string(), &copy_id.to_string());
+            }
+        });
+    }
+
+    /// The copies share the blobs of the copied assets.
+    async fn create(
+        self,
+        executor: &mut PgConnection,
+        entity_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        for (asset, copy_id) in self.0 {
+            AssetsRepository::create(
+                &mut *executor,
+                CreateAssetDto {
+                    id: Some(copy_id),
+                    name: asset.name,
+                    blob_id: asset.blob_id,
+                    entity_id,
+                    entity_type: asset.entity_type,
+                },
+            )
+            .await
+            .map_err(ErrorResponse::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub struct PageDuplicationService;
+
+impl PageDuplicationService {
+    // COMMANDS
+
+    /// Copies a page next to it, with its descendants for group pages. The duplicating user owns
+    /// the copies, page accesses, versions, views and task chats are not copied.
+    pub async fn duplicate(
+        executor: &mut PgConnection,
+        page_id: Uuid,
+        user_id: Uuid,
+        dto: DuplicatePageRequest,
+    ) -> Result<Page, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(&mut *executor, page_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let include_tasks = dto.include_tasks.unwrap_or(false);
+        let title = dto
+            .title
+            .unwrap_or_else(|| format!("{} (copy)", page.title));
+
+        let copy = Self::copy_page(
+            executor,
+            &page,
+            title,
+            page.parent_page_id,
+            user_id,
+            include_tasks,
+        )
+        .await?;
+
+        let mut pending = VecDeque::from([(page.id, copy.id)]);
+        while let Some((source_id, copy_id)) = pending.pop_front() {
+            let children = PageRepository::get_child_pages(&mut *executor, source_id)
+                .await
+                .map_err(ErrorResponse::from)?;
+
+            for child in children {
+                let child_copy = Self::copy_page(
+                    executor,
+                    &child,
+                    child.title.clone(),
+                    Some(copy_id),
+                    user_id,
+                    include_tasks,
+                )
+                .await?;
+
+                pending.push_back((child.id, child_copy.id));
+            }
+        }
+
+        PageService::move_to_position(
+            executor,
+            copy.id,
+            user_id,
+            MovePageRequest {
+                parent_page_id: page.parent_page_id,
+                position: Some(page.position + 1),
+            },
+        )
+        .await
+    }
+
+    /// Copies a page without its child pages, it is added after its new siblings.
+    async fn copy_page(
+        executor: &mut PgConnection,
+        source: &Page,
+        title: String,
+        parent_page_id: Option<Uuid>,
+        owner_id: Uuid,
+        include_tasks: bool,
+    ) -> Result<Page, ErrorResponse> {
+        let dto = |content| CreatePageDto {
+            title,
+            parent_page_id,
+            r#type: source.r#type.clone(),
+            content,
+            workspace_id: source.workspace_id,
+            owner_id,
+        };
+
+        match source.r#type {
+            PageType::Text => {
+                let mut content = PageRepository::get_text_page_content(&mut *executor, source.id)
+                    .await
+                    .map_err(ErrorResponse::from)?
+                    .content
+                    .0;
+
+                let assets =
+                    AssetCopies::plan(executor, EntityType::PageText, source.id, content.as_ref())
+                        .await?;
+                assets.remap(content.as_mut());
+
+                let page = PageService::create(executor, dto(content)).await?;
+                assets.create(executor, page.id).await?;
+
+                Ok(page)
+            }
+            PageType::Board => {
+                let statuses =
+                    BoardStatusRepository::get_board_statuses_by_page_id(&mut *executor, source.id)
+                        .await
+                        .map_err(ErrorResponse::from)?
+                        .into_iter()
+                        .filter(|status| status.deleted_at.is_none())
+                        .collect::<Vec<_>>();
+
+                let page = PageService::create_with_statuses(
+                    executor,
+                    dto(None),
+                    statuses
+                        .iter()
+                        .map(|status| TemplateBoardStatus {
+                            initial: status.initial,
+                            localizations: status.localizations.0.clone(),
+                        })
+                        .collect(),
+                )
+                .await?;
+
+                if include_tasks {
+                    Self::copy_tasks(executor, source.id, &statuses, page.id, owner_id).await?;
+                }
+
+                Ok(page)
+            }
+            PageType::Group => PageService::create(executor, dto(None)).await,
+        }
+    }
+
+    /// Copies the tasks of a board with their checklists. `statuses` are the statuses of the
+    /// source board, in the order they were copied. Mentions of the copies are authored by
+    /// `author_id`, the user duplicating the board.
+    async fn copy_tasks(
+        executor: &mut PgConnection,
+        source_page_id: Uuid,
+        statuses: &[BoardStatus],
+        page_id: Uuid,
+        author_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        let copied_statuses =
+            BoardStatusRepository::get_board_statuses_by_page_id(&mut *executor, page_id)
+                .await
+                .map_err(ErrorResponse::from)?;
+
+        let initial_status_id = copied_statuses
+            .iter()
+            .find(|status| status.initial)
+            .map(|status| status.id);
+        let status_ids = statuses
+            .iter()
+            .zip(&copied_statuses)
+            .map(|(status, copy)| (status.id, copy.id))
+            .collect::<HashMap<_, _>>();
+
+        let tasks = TaskRepository::get_all_tasks_by_page_id(&mut *executor, source_page_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        for task in tasks.into_iter().filter(|task| task.deleted_at.is_none()) {
+            // Tasks of a removed status go to the initial one
+            let Some(status_id) = status_ids
+                .get(&task.status_id)
+                .copied()
+                .or(initial_status_id)
+            else {
+                continue;
+            };
+
+            let mut description = task.description.0;
+            let assets = AssetCopies::plan(
+                executor,
+                EntityType::TaskDescription,
+                task.id,
+                description.as_ref(),
+            )
+            .await?;
+            assets.remap(description.as_mut());
+
+            let copy = TaskRepository::create(
+                &mut *executor,
+                CreateTaskDto {
+                    title: task.title,
+                    status_id,
+                    position: task.position,
+                    description: description.clone(),
+                    due_date: task.due_date,
+                    assignee_id: task.assignee_id,
+                    reporter_id: task.reporter_id,
+                    is_draft: task.is_draft,
+                    page_id,
+                },
+            )
+            .await
+            .map_err(ErrorResponse::from)?;
+            assets.create(executor, copy.id).await?;
+
+            MentionService::sync(
+                &mut *executor,
+                page_id,
+                Some(copy.id),
+                author_id,
+                description.as_ref(),
+            )
+            .await?;
+            LinkService::sync(&mut *executor, page_id, Some(copy.id), description.as_ref()).await?;
+
+            let items = ChecklistItemRepository::get_by_task_id(&mut *executor, task.id)
+                .await
+                .map_err(ErrorResponse::from)?;
+
+            for item in items {
+                let item_copy = ChecklistItemRepository::create(
+                    &mut *executor,
+                    CreateChecklistItemDto {
+                        task_id: copy.id,
+                        text: item.text,
+                        assignee_id: item.assignee_id,
+                    },
+                )
+                .await
+                .map_err(ErrorResponse::from)?;
+
+                if item.is_done {
+                    ChecklistItemRepository::update(
+                        &mut *executor,
+                        item_copy.id,
+                        UpdateChecklistItemDto {
+                            text: None,
+                            is_done: Some(true),
+                            assignee_id: None,
+                            position: None,
+                        },
+                    )
+                    .await
+                    .map_err(ErrorResponse::from)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
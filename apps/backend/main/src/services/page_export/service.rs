@@ -0,0 +1,436 @@
+use std::{collections::HashMap, io::Write};
+
+use axum::body::Body;
+use chrono::{Duration, Utc};
+use error_handlers::{codes, handlers::ErrorResponse};
+use futures_util::StreamExt;
+use serde::Serialize;
+use sql::{
+    assets::model::EntityType,
+    page::model::PageType,
+    shared::tiptap_content::{DocType, FileAttributes, NodeWrapper, TipTapContent, escape_html},
+};
+use tokio::sync::mpsc;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page::dto::{PageExportFormat, TaskExportRecord},
+    repos::{assets::AssetsRepository, pages::PageRepository, tasks::TaskRepository},
+    shared::extractors::x_user_language::DEFAULT_LANGUAGE,
+};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Number of encoded rows buffered ahead of a slow client.
+const EXPORT_BUFFER_SIZE: usize = 64;
+
+const CSV_HEADER: [&str; 10] = [
+    "id",
+    "title",
+    "status",
+    "assignee",
+    "reporter",
+    "dueDate",
+    "position",
+    "description",
+    "createdAt",
+    "updatedAt",
+];
+
+pub struct PageExport {
+    pub file_name: String,
+    pub content_type: &'static str,
+    pub body: Body,
+}
+
+/// Formats of a board export, rows are streamed as they are encoded.
+#[derive(Clone, Copy)]
+pub enum BoardExportFormat {
+    Csv,
+    Json,
+}
+
+/// Formats a text page is rendered to.
+#[derive(Clone, Copy)]
+pub enum TextExportFormat {
+    Md,
+    Html,
+}
+
+/// Requested export format, split by the page type it applies to.
+pub enum ExportFormat {
+    Board(BoardExportFormat),
+    Text(TextExportFormat),
+}
+
+impl From<PageExportFormat> for ExportFormat {
+    fn from(format: PageExportFormat) -> Self {
+        match format {
+            PageExportFormat::Csv => Self::Board(BoardExportFormat::Csv),
+            PageExportFormat::Json => Self::Board(BoardExportFormat::Json),
+            PageExportFormat::Md => Self::Text(TextExportFormat::Md),
+            PageExportFormat::Html => Self::Text(TextExportFormat::Html),
+        }
+    }
+}
+
+impl BoardExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+impl TextExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Md => "md",
+            Self::Html => "html",
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            Self::Md => "text/markdown; charset=utf-8",
+            Self::Html => "text/html; charset=utf-8",
+        }
+    }
+}
+
+enum ExportEncoder {
+    Csv,
+    Json { is_first: bool },
+}
+
+impl ExportEncoder {
+    fn new(format: BoardExportFormat) -> Self {
+        match format {
+            BoardExportFormat::Csv => Self::Csv,
+            BoardExportFormat::Json => Self::Json { is_first: true },
+        }
+    }
+
+    fn csv_writer() -> csv::Writer<Vec<u8>> {
+        csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(vec![])
+    }
+
+    fn start(&mut self) -> Result<Vec<u8>, BoxError> {
+        match self {
+            Self::Csv => {
+                let mut writer = Self::csv_writer();
+                writer.write_record(CSV_HEADER)?;
+                Ok(writer.into_inner().map_err(|e| e.into_error())?)
+            }
+            Self::Json { .. } => Ok(b"[".to_vec()),
+        }
+    }
+
+    fn encode(&mut self, record: &TaskExportRecord) -> Result<Vec<u8>, BoxError> {
+        match self {
+            Self::Csv => {
+                let mut writer = Self::csv_writer();
+                writer.serialize(record)?;
+                Ok(writer.into_inner().map_err(|e| e.into_error())?)
+            }
+            Self::Json { is_first } => {
+                let mut chunk = if *is_first { vec![] } else { b",".to_vec() };
+                *is_first = false;
+                serde_json::to_writer(&mut chunk, record)?;
+                Ok(chunk)
+            }
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        match self {
+            Self::Csv => vec![],
+            Self::Json { .. } => b"]".to_vec(),
+        }
+    }
+}
+
+fn file_name(title: &str, extension: &str) -> String {
+    let name: String = title
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let name = if name.trim_matches('_').is_empty() {
+        "export".to_string()
+    } else {
+        name
+    };
+
+    format!("{name}.{extension}")
+}
+
+fn html_document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{body}\n</body>\n</html>\n",
+        escape_html(title)
+    )
+}
+
+/// Path of a bundled file inside the zip, the asset id keeps equal names apart.
+fn bundled_asset_path(asset_id: Uuid, name: &str) -> String {
+    format!("assets/{asset_id}/{}", name.replace(['/', '\\'], "_"))
+}
+
+fn zip_bundle(
+    document_name: &str,
+    document: &[u8],
+    assets: &[(String, Vec<u8>)],
+) -> zip::result::ZipResult<Vec<u8>> {
+    let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let options = zip::write::SimpleFileOptions::default();
+
+    writer.start_file(document_name, options)?;
+    writer.write_all(document)?;
+
+    for (path, bytes) in assets {
+        writer.start_file(path.as_str(), options)?;
+        writer.write_all(bytes)?;
+    }
+
+    Ok(writer.finish()?.into_inner())
+}
+
+/// Checked by the storage service, so upload tokens signed with the same secret can't be used
+/// to download blobs.
+const BLOB_DOWNLOAD_AUDIENCE: &str = "storage:blob-download";
+
+/// Grants the download of one blob from the storage service, signed with the shared JWT secret.
+#[derive(Serialize)]
+struct BlobDownloadToken {
+    sub: Uuid,
+    aud: &'static str,
+    exp: usize,
+}
+
+async fn download_blob(
+    storage_service_url: &str,
+    jwt_secret: &str,
+    blob_id: Uuid,
+) -> Result<Vec<u8>, ErrorResponse> {
+    let token = jsonwebtoken::encode(
+        &jsonwebtoken::Header::default(),
+        &BlobDownloadToken {
+            sub: blob_id,
+            aud: BLOB_DOWNLOAD_AUDIENCE,
+            exp: (Utc::now() + Duration::minutes(1)).timestamp() as usize,
+        },
+        &jsonwebtoken::EncodingKey::from_secret(jwt_secret.as_bytes()),
+    )
+    .map_err(|error| ErrorResponse::internal_server_error(Some(error.to_string())))?;
+
+    let resp = reqwest::Client::new()
+        .get(format!(
+            "{}/internal/blobs/{}",
+            storage_service_url, blob_id
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(|e| {
+            ErrorResponse::internal_server_error(Some(format!(
+                "Failed to download from storage: {}",
+                e
+            )))
+        })?;
+
+    if resp.status() != reqwest::StatusCode::OK {
+        return Err(resp
+            .json::<ErrorResponse>()
+            .await
+            .unwrap_or_else(|e| ErrorResponse::internal_server_error(Some(e.to_string()))));
+    }
+
+    resp.bytes()
+        .await
+        .map(|bytes| bytes.to_vec())
+        .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))
+}
+
+pub struct PageExportService;
+
+impl PageExportService {
+    /// CSV or JSON export of the board tasks.
+    /// Rows are encoded while they are read from the database, so the export is never held in memory.
+    pub async fn export_board(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        lang: Option<String>,
+        format: BoardExportFormat,
+    ) -> Result<PageExport, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(pool, page_id).await?;
+
+        if page.r#type != PageType::Board {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "pageId".to_string(),
+                    "Only board pages can be exported in this format".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let lang = lang.unwrap_or(DEFAULT_LANGUAGE.to_string());
+        let (sender, receiver) = mpsc::channel::<Result<Vec<u8>, BoxError>>(EXPORT_BUFFER_SIZE);
+        let pool = pool.clone();
+
+        tokio::spawn(async move {
+            let mut encoder = ExportEncoder::new(format);
+
+            if sender.send(encoder.start()).await.is_err() {
+                return;
+            }
+
+            let mut rows = TaskRepository::stream_export_rows_by_page_id(&pool, page_id, &lang);
+
+            while let Some(row) = rows.next().await {
+                let chunk = row
+                    .map_err(BoxError::from)
+                    .and_then(|row| encoder.encode(&TaskExportRecord::from(row)));
+
+                if let Err(e) = &chunk {
+                    error!("Failed to export tasks of page {}: {}", page_id, e);
+                }
+
+                // Either the client went away or the body is aborted with the error
+                let is_err = chunk.is_err();
+                if sender.send(chunk).await.is_err() || is_err {
+                    return;
+                }
+            }
+
+            let _ = sender.send(Ok(encoder.finish())).await;
+        });
+
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            receiver.recv().await.map(|chunk| (chunk, receiver))
+        });
+
+        Ok(PageExport {
+            file_name: file_name(&page.title, format.extension()),
+            content_type: format.content_type(),
+            body: Body::from_stream(stream),
+        })
+    }
+
+    /// Renders a text page as Markdown or HTML, headed by its title. With `bundle_assets` the document
+    /// is zipped together with the files of the page and links to them point at the bundled copies.
+    pub async fn export_text(
+        pool: &sqlx::PgPool,
+        storage_service_url: &str,
+        jwt_secret: &str,
+        page_id: Uuid,
+        format: TextExportFormat,
+        bundle_assets: bool,
+    ) -> Result<PageExport, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(pool, page_id).await?;
+
+        if page.r#type != PageType::Text {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "pageId".to_string(),
+                    "Only text pages can be exported in this format".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let content = PageRepository::get_text_page_content(pool, page_id)
+            .await?
+            .content
+            .0;
+
+        let mut document = TipTapContent {
+            r#type: DocType::Doc,
+            content: Some(vec![NodeWrapper::Generic(serde_json::json!({
+                "type": "heading",
+                "attrs": { "level": 1 },
+                "content": [{ "type": "text", "text": page.title }],
+            }))]),
+        };
+        if let (Some(nodes), Some(content)) = (&mut document.content, content) {
+            nodes.extend(content.content.into_iter().flatten());
+        }
+
+        let mut bundled = HashMap::new();
+        if bundle_assets {
+            for asset_id in document.file_asset_ids() {
+                let asset = match AssetsRepository::get_one_by_id(pool, asset_id).await {
+                    Ok(asset) => asset,
+                    Err(sqlx::Error::RowNotFound) => continue,
+                    Err(e) => return Err(ErrorResponse::from(e)),
+                };
+
+                // Files pasted from elsewhere stay links, their access is checked on download
+                if !matches!(asset.entity_type, EntityType::PageText) || asset.entity_id != page_id
+                {
+                    continue;
+                }
+
+                let bytes = download_blob(storage_service_url, jwt_secret, asset.blob_id).await?;
+                bundled.insert(asset_id, (bundled_asset_path(asset_id, &asset.name), bytes));
+            }
+        }
+
+        let file_url = |attrs: &FileAttributes| {
+            attrs
+                .id
+                .and_then(|id| bundled.get(&id))
+                .map(|(path, _)| path.clone())
+                .or_else(|| attrs.href.clone())
+                .or_else(|| attrs.src.clone())
+        };
+
+        let rendered = match format {
+            TextExportFormat::Html => html_document(&page.title, &document.to_html(file_url)),
+            TextExportFormat::Md => document.to_markdown(file_url) + "\n",
+        };
+        let document_name = file_name(&page.title, format.extension());
+
+        if !bundle_assets {
+            return Ok(PageExport {
+                file_name: document_name,
+                content_type: format.content_type(),
+                body: Body::from(rendered),
+            });
+        }
+
+        let zip_name = file_name(&page.title, "zip");
+        let assets = bundled.into_values().collect::<Vec<_>>();
+        let archive = tokio::task::spawn_blocking(move || {
+            zip_bundle(&document_name, rendered.as_bytes(), &assets)
+        })
+        .await
+        .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?
+        .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?;
+
+        Ok(PageExport {
+            file_name: zip_name,
+            content_type: "application/zip",
+            body: Body::from(archive),
+        })
+    }
+}
@@ -0,0 +1,364 @@
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read},
+};
+
+use error_handlers::{codes, handlers::ErrorResponse};
+use serde::Deserialize;
+use sql::{
+    assets::model::EntityType,
+    page::model::{Page, PageType},
+    shared::tiptap_content::{FileAttributes, TipTapContent},
+};
+use uuid::Uuid;
+
+use crate::{
+    repos::{
+        assets::{AssetsRepository, CreateAssetDto},
+        pages::CreatePageDto,
+    },
+    services::{assets::AssetsService, pages::PageService},
+    shared::validation,
+};
+
+/// Limit of the uncompressed content of an uploaded archive.
+const MAX_BUNDLE_SIZE: u64 = 50 * 1024 * 1024;
+const ZIP_SIGNATURE: &[u8] = b"PK\x03\x04";
+
+fn invalid_file(message: &str) -> ErrorResponse {
+    ErrorResponse::bad_request(
+        codes::BadRequestErrorCode::InvalidBody,
+        Some(HashMap::from([("file".to_string(), message.to_string())])),
+        None,
+    )
+}
+
+#[derive(Deserialize)]
+struct StorageBlobResponse {
+    id: Uuid,
+    size: i64,
+    mime_type: String,
+}
+
+/// The Markdown document of an upload with the other files of its archive, keyed by their path.
+struct MarkdownBundle {
+    name: String,
+    markdown: String,
+    dir: String,
+    files: HashMap<String, Vec<u8>>,
+}
+
+/// Image of the bundle referenced by the document, uploaded once the document is valid.
+/// `attrs` stand in for the attributes of the uploaded file meanwhile.
+struct PlannedAsset {
+    id: Uuid,
+    name: String,
+    path: String,
+    attrs: FileAttributes,
+}
+
+struct ImportedAsset {
+    name: String,
+    blob_id: Uuid,
+    attrs: FileAttributes,
+}
+
+fn is_markdown(path: &str) -> bool {
+    let path = path.to_lowercase();
+
+    path.ends_with(".md") || path.ends_with(".markdown")
+}
+
+fn file_stem(path: &str) -> String {
+    let name = path.rsplit('/').next().unwrap_or(path);
+
+    name.rsplit_once('.')
+        .map(|(stem, _)| stem)
+        .filter(|stem| !stem.is_empty())
+        .unwrap_or(name)
+        .to_string()
+}
+
+/// Errors are returned as the message of an `InvalidBody` response for the `file` field.
+fn read_bundle(file_name: &str, bytes: Vec<u8>) -> Result<MarkdownBundle, String> {
+    if !bytes.starts_with(ZIP_SIGNATURE) {
+        let markdown =
+            String::from_utf8(bytes).map_err(|_| "Markdown must be UTF-8 encoded".to_string())?;
+
+        return Ok(MarkdownBundle {
+            name: file_stem(file_name),
+            markdown,
+            dir: String::new(),
+            files: HashMap::new(),
+        });
+    }
+
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| format!("Invalid zip archive: {e}"))?;
+
+    let mut documents = Vec::new();
+    let mut files = HashMap::new();
+    let mut total_size = 0;
+
+    for index in 0..archive.len() {
+        let mut entry = archive
+            .by_index(index)
+            .map_err(|e| format!("Invalid zip archive: {e}"))?;
+
+        // Entries escaping the archive root are skipped
+        let Some(path) = entry.enclosed_name() else {
+            continue;
+        };
+        if entry.is_dir() {
+            continue;
+        }
+
+        total_size += entry.size();
+        if total_size > MAX_BUNDLE_SIZE {
+            return Err("The archive is too large".to_string());
+        }
+
+        let path = path.to_string_lossy().replace('\\', "/");
+        let mut content = Vec::with_capacity(entry.size() as usize);
+        entry
+            .read_to_end(&mut content)
+            .map_err(|e| format!("Invalid zip archive: {e}"))?;
+
+        if is_markdown(&path) {
+            documents.push((path, content));
+        } else {
+            files.insert(path, content);
+        }
+    }
+
+    let (path, markdown) = match documents.len() {
+        1 => documents.remove(0),
+        _ => {
+            return Err("The archive must contain exactly one Markdown file".to_string());
+        }
+    };
+
+    let markdown =
+        String::from_utf8(markdown).map_err(|_| "Markdown must be UTF-8 encoded".to_string())?;
+
+    Ok(MarkdownBundle {
+        name: file_stem(&path),
+        markdown,
+        dir: path
+            .rsplit_once('/')
+            .map(|(dir, _)| dir.to_string())
+            .unwrap_or_default(),
+        files,
+    })
+}
+
+/// Resolves a relative image source against the directory of the document.
+/// Absolute URLs, root paths and paths leaving the archive return `None`.
+fn resolve_path(dir: &str, src: &str) -> Option<String> {
+    if src.contains("://") || src.starts_with('/') || src.starts_with("data:") {
+        return None;
+    }
+
+    let src = src.split(['?', '#']).next().unwrap_or_default();
+    let src = percent_encoding::percent_decode_str(src)
+        .decode_utf8()
+        .ok()?;
+
+    let mut segments = dir.split('/').filter(|s| !s.is_empty()).collect::<Vec<_>>();
+
+    for segment in src.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+
+    Some(segments.join("/"))
+}
+
+async fn upload_blob(
+    storage_service_url: &str,
+    name: &str,
+    bytes: Vec<u8>,
+) -> Result<StorageBlobResponse, ErrorResponse> {
+    let form = reqwest::multipart::Form::new().part(
+        "file",
+        reqwest::multipart::Part::bytes(bytes).file_name(name.to_string()),
+    );
+
+    let resp = reqwest::Client::new()
+        .post(format!("{}/internal/upload", storage_service_url))
+        .multipart(form)
+        .send()
+        .await
+        .map_err(|e| {
+            ErrorResponse::internal_server_error(Some(format!(
+                "Failed to upload to storage: {}",
+                e
+            )))
+        })?;
+
+    if resp.status() != reqwest::StatusCode::CREATED {
+        return Err(resp
+            .json::<ErrorResponse>()
+            .await
+            .unwrap_or_else(|e| ErrorResponse::internal_server_error(Some(e.to_string()))));
+    }
+
+    resp.json().await.map_err(|e| {
+        ErrorResponse::internal_server_error(Some(format!(
+            "Failed to parse storage response: {}",
+            e
+        )))
+    })
+}
+
+pub struct PageImportService;
+
+impl PageImportService {
+    // COMMANDS
+
+    /// Creates a text page from a Markdown file, or from a zip holding one Markdown file and the
+    /// images it references by relative path. Those images are uploaded to the storage service
+    /// and become assets of the page.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn import_markdown(
+        pool: &sqlx::PgPool,
+        storage_service_url: &str,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        file_name: String,
+        bytes: Vec<u8>,
+        title: Option<String>,
+        parent_page_id: Option<Uuid>,
+    ) -> Result<Page, ErrorResponse> {
+        let bundle = tokio::task::spawn_blocking(move || read_bundle(&file_name, bytes))
+            .await
+            .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?
+            .map_err(|message| invalid_file(&message))?;
+
+        let mut planned: HashMap<String, PlannedAsset> = HashMap::new();
+
+        for src in TipTapContent::markdown_image_sources(&bundle.markdown) {
+            if planned.contains_key(&src) {
+                continue;
+            }
+
+            let Some(path) = resolve_path(&bundle.dir, &src) else {
+                continue;
+            };
+            let Some(bytes) = bundle.files.get(&path) else {
+                continue;
+            };
+
+            let id = Uuid::new_v4();
+            let name = path.rsplit('/').next().unwrap_or(&path).to_string();
+            let attrs = AssetsService::file_attributes(
+                id,
+                "application/octet-stream",
+                bytes.len() as i64,
+                &name,
+                storage_service_url,
+            );
+
+            planned.insert(
+                src,
+                PlannedAsset {
+                    id,
+                    name,
+                    path,
+                    attrs,
+                },
+            );
+        }
+
+        let mut content = TipTapContent::from_markdown(&bundle.markdown, |src| {
+            planned.get(src).map(|asset| asset.attrs.clone())
+        });
+
+        // Checked before uploading, so rejected documents leave no blobs behind
+        let mut errors = HashMap::new();
+        validation::validate_tiptap_content("content", Some(&content), &mut errors);
+        if !errors.is_empty() {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidBody,
+                Some(errors),
+                None,
+            ));
+        }
+
+        let mut assets = Vec::with_capacity(planned.len());
+
+        for asset in planned.into_values() {
+            let Some(bytes) = bundle.files.get(&asset.path) else {
+                continue;
+            };
+
+            let blob = upload_blob(storage_service_url, &asset.name, bytes.clone()).await?;
+
+            assets.push(ImportedAsset {
+                attrs: AssetsService::file_attributes(
+                    asset.id,
+                    &blob.mime_type,
+                    blob.size,
+                    &asset.name,
+                    storage_service_url,
+                ),
+                name: asset.name,
+                blob_id: blob.id,
+            });
+        }
+
+        // The stored type and size of the images are only known once uploaded
+        let uploaded = assets
+            .iter()
+            .map(|asset| (asset.attrs.id, &asset.attrs))
+            .collect::<HashMap<_, _>>();
+        content.update_file_nodes(|attrs| {
+            if let Some(uploaded) = uploaded.get(&attrs.id) {
+                *attrs = (*uploaded).clone();
+            }
+        });
+
+        let title = title
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .unwrap_or(bundle.name);
+
+        let mut tx = pool.begin().await?;
+
+        let page = PageService::create(
+            &mut tx,
+            CreatePageDto {
+                title,
+                parent_page_id,
+                r#type: PageType::Text,
+                content: Some(content),
+                workspace_id,
+                owner_id: user_id,
+            },
+        )
+        .await?;
+
+        for asset in assets {
+            AssetsRepository::create(
+                &mut *tx,
+                CreateAssetDto {
+                    id: asset.attrs.id,
+                    name: asset.name,
+                    blob_id: asset.blob_id,
+                    entity_id: page.id,
+                    entity_type: EntityType::PageText,
+                },
+            )
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(page)
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    assets::model::{Asset, EntityType},
+    page::model::{PageAccess, Role},
+    page_share_links::model::PageShareLink,
+};
+use uuid::Uuid;
+
+use crate::{
+    controllers::{
+        page::dto::DetailedPageResponse, page_share_links::dto::CreatePageShareLinkRequest,
+    },
+    repos::{
+        assets::AssetsRepository,
+        page_share_links::{CreatePageShareLinkDto, PageShareLinkRepository},
+        pages::PageRepository,
+    },
+    services::pages::PageService,
+    shared::tokens::{generate_token, hash_token},
+};
+
+/// Wrong passwords allowed before a link is locked.
+const MAX_PASSWORD_ATTEMPTS: i32 = 5;
+
+fn link_not_found() -> ErrorResponse {
+    ErrorResponse::not_found(
+        codes::NotFoundErrorCode::NotFound,
+        None,
+        Some("Share link not found".to_string()),
+    )
+}
+
+fn invalid_password() -> ErrorResponse {
+    ErrorResponse::unauthorized(codes::UnauthorizedErrorCode::InvalidCredentials, None)
+}
+
+pub struct PageShareLinkService;
+
+impl PageShareLinkService {
+    // QUERIES
+
+    pub async fn get_active<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+    ) -> Result<Vec<PageShareLink>, ErrorResponse> {
+        PageShareLinkRepository::get_active_by_page_id(executor, page_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Resolves a token to its link and checks the optional password. The access of the link creator
+    /// is re-checked as well, so a link stops working once its creator loses access to the page.
+    async fn resolve(
+        pool: &sqlx::PgPool,
+        token: &str,
+        password: Option<String>,
+    ) -> Result<(PageShareLink, PageAccess), ErrorResponse> {
+        let link = PageShareLinkRepository::get_active_by_token_hash(pool, &hash_token(token))
+            .await
+            .map_err(|_| link_not_found())?;
+
+        if let Some(password_hash) = link.password_hash.clone() {
+            let password = password.ok_or_else(|| {
+                ErrorResponse::unauthorized(
+                    codes::UnauthorizedErrorCode::InvalidCredentials,
+                    Some("Share link requires a password".to_string()),
+                )
+            })?;
+
+            // A locked link answers like a wrong password, so guesses can't tell them apart
+            if !PageShareLinkRepository::start_password_attempt(
+                pool,
+                link.id,
+                MAX_PASSWORD_ATTEMPTS,
+            )
+            .await?
+            {
+                return Err(invalid_password());
+            }
+
+            let is_valid =
+                tokio::task::spawn_blocking(move || bcrypt::verify(password, &password_hash))
+                    .await
+                    .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?
+                    .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?;
+
+            if !is_valid {
+                return Err(invalid_password());
+            }
+
+            PageShareLinkRepository::reset_password_attempts(pool, link.id).await?;
+        }
+
+        let creator_access =
+            PageRepository::get_one_page_access(pool, link.created_by, link.page_id)
+                .await
+                .map_err(|_| link_not_found())?;
+
+        Ok((link, creator_access))
+    }
+
+    /// Read-only view of a shared page, rendered as a guest of the link creator with user emails
+    /// and ancestors removed.
+    pub async fn get_shared_page(
+        pool: &sqlx::PgPool,
+        token: &str,
+        password: Option<String>,
+        lang: Option<String>,
+    ) -> Result<DetailedPageResponse, ErrorResponse> {
+        let (link, creator_access) = Self::resolve(pool, token, password).await?;
+
+        let mut page = PageService::get_detailed_page(
+            pool,
+            link.page_id,
+            PageAccess {
+                role: Role::Guest,
+                ..creator_access
+            },
+            lang,
+            None,
+            false,
+        )
+        .await?;
+
+        // The groups above the shared page are not shared
+        page.base_mut().ancestors.clear();
+
+        if let DetailedPageResponse::Board(board) = &mut page {
+            for assignee in board.assignees.iter_mut() {
+                assignee.email = None;
+            }
+        }
+
+        PageShareLinkRepository::touch(pool, link.id).await?;
+
+        Ok(page)
+    }
+
+    /// Only assets embedded in the shared page itself and avatars are served through a link.
+    pub async fn validate_asset_access(
+        pool: &sqlx::PgPool,
+        token: &str,
+        password: Option<String>,
+        asset_id: Uuid,
+    ) -> Result<Asset, ErrorResponse> {
+        let (link, _) = Self::resolve(pool, token, password).await?;
+
+        let asset = AssetsRepository::get_one_by_id(pool, asset_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let is_valid = match asset.entity_type {
+            EntityType::PageText => asset.entity_id == link.page_id,
+            EntityType::UserAvatar => true,
+            EntityType::TaskDescription | EntityType::UserDraft => false,
+        };
+
+        if !is_valid {
+            return Err(ErrorResponse::forbidden(
+                codes::ForbiddenErrorCode::AccessDenied,
+                None,
+                None,
+            ));
+        }
+
+        Ok(asset)
+    }
+
+    // COMMANDS
+
+    /// Returns the link together with its token, the token cannot be retrieved again later.
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        user_id: Uuid,
+        dto: CreatePageShareLinkRequest,
+    ) -> Result<(PageShareLink, String), ErrorResponse> {
+        if let Some(expires_at) = dto.expires_at
+            && expires_at <= Utc::now()
+        {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidBody,
+                Some(HashMap::from([(
+                    "expiresAt".to_string(),
+                    "Expiration date must be in the future".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let password_hash = match dto.password {
+            Some(password) if password.is_empty() => {
+                return Err(ErrorResponse::bad_request(
+                    codes::BadRequestErrorCode::InvalidBody,
+                    Some(HashMap::from([(
+                        "password".to_string(),
+                        "Password must not be empty".to_string(),
+                    )])),
+                    None,
+                ));
+            }
+            Some(password) => Some(
+                tokio::task::spawn_blocking(move || bcrypt::hash(password, bcrypt::DEFAULT_COST))
+                    .await
+                    .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?
+                    .map_err(|e| ErrorResponse::internal_server_error(Some(e.to_string())))?,
+            ),
+            None => None,
+        };
+
+        let token = generate_token();
+
+        let link = PageShareLinkRepository::create(
+            pool,
+            CreatePageShareLinkDto {
+                page_id,
+                token_hash: hash_token(&token),
+                password_hash,
+                expires_at: dto.expires_at,
+                created_by: user_id,
+            },
+        )
+        .await?;
+
+        Ok((link, token))
+    }
+
+    pub async fn revoke<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        link_id: Uuid,
+    ) -> Result<PageShareLink, ErrorResponse> {
+        PageShareLinkRepository::revoke(executor, link_id, page_id)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => link_not_found(),
+                e => ErrorResponse::from(e),
+            })
+    }
+}
@@ -0,0 +1,106 @@
+//! Templates available in every workspace, they cannot be changed nor deleted.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sql::{
+    page::model::PageType,
+    page_templates::model::{PageTemplate, TemplateBoardStatus},
+    shared::tiptap_content::TipTapContent,
+};
+use sqlx::types::Json;
+use uuid::Uuid;
+
+struct BuiltInTemplate {
+    id: Uuid,
+    name: &'static str,
+    r#type: PageType,
+    title_pattern: &'static str,
+    /// Text templates only
+    markdown: Option<&'static str>,
+    /// English titles of the statuses of board templates, the first one is the initial status
+    statuses: &'static [&'static str],
+}
+
+const BUILT_IN_TEMPLATES: [BuiltInTemplate; 3] = [
+    BuiltInTemplate {
+        id: Uuid::from_u128(1),
+        name: "Meeting notes",
+        r#type: PageType::Text,
+        title_pattern: "Meeting notes {{date}}",
+        markdown: Some(
+            "**Date:** {{date}}\n\n**Facilitator:** {{user}}\n\n\
+             ## Attendees\n\n- {{user}}\n\n\
+             ## Agenda\n\n1. Topic\n\n\
+             ## Notes\n\nNotes\n\n\
+             ## Action items\n\n- [ ] Action item\n",
+        ),
+        statuses: &[],
+    },
+    BuiltInTemplate {
+        id: Uuid::from_u128(2),
+        name: "Project brief",
+        r#type: PageType::Text,
+        title_pattern: "Project brief",
+        markdown: Some(
+            "**Owner:** {{user}}\n\n**Last updated:** {{date}}\n\n\
+             ## Goal\n\nWhat the project achieves and why it matters.\n\n\
+             ## Scope\n\n- In scope\n- Out of scope\n\n\
+             ## Milestones\n\n| Milestone | Date |\n| --- | --- |\n| Kickoff | {{date}} |\n\n\
+             ## Risks\n\n- Risk\n",
+        ),
+        statuses: &[],
+    },
+    BuiltInTemplate {
+        id: Uuid::from_u128(3),
+        name: "Sprint board",
+        r#type: PageType::Board,
+        title_pattern: "Sprint {{date}}",
+        markdown: None,
+        statuses: &["Backlog", "To Do", "In Progress", "In Review", "Done"],
+    },
+];
+
+impl BuiltInTemplate {
+    fn to_template(&self) -> PageTemplate {
+        let statuses = self
+            .statuses
+            .iter()
+            .enumerate()
+            .map(|(index, title)| TemplateBoardStatus {
+                initial: index == 0,
+                localizations: HashMap::from([("en".to_string(), title.to_string())]),
+            })
+            .collect();
+
+        PageTemplate {
+            id: self.id,
+            workspace_id: None,
+            created_by: None,
+            name: self.name.to_string(),
+            r#type: self.r#type.clone(),
+            title_pattern: self.title_pattern.to_string(),
+            content: Json(
+                self.markdown
+                    .map(|markdown| TipTapContent::from_markdown(markdown, |_| None)),
+            ),
+            statuses: Json(statuses),
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+            updated_at: DateTime::<Utc>::UNIX_EPOCH,
+        }
+    }
+}
+
+pub(super) fn all() -> Vec<PageTemplate> {
+    BUILT_IN_TEMPLATES
+        .iter()
+        .map(BuiltInTemplate::to_template)
+        .collect()
+}
+
+pub(super) fn get(id: Uuid) -> Option<PageTemplate> {
+    BUILT_IN_TEMPLATES
+        .iter()
+        .find(|template| template.id == id)
+        .map(BuiltInTemplate::to_template)
+}
@@ -0,0 +1,4 @@
+mod built_in;
+mod service;
+
+pub use service::PageTemplateService;
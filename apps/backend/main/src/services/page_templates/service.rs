@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    page::model::{Page, PageType},
+    page_templates::model::PageTemplate,
+    shared::tiptap_content::{TipTapContent, Visit},
+    workspace::model::{Role, WorkspaceAccess},
+};
+use sqlx::{PgConnection, types::Json};
+use uuid::Uuid;
+
+use crate::{
+    controllers::{
+        page::dto::CreatePageRequest,
+        page_templates::dto::{
+            CreatePageTemplateRequest, UpdatePageTemplateRequest, validate_template_type,
+        },
+    },
+    repos::{
+        page_templates::{CreatePageTemplateDto, PageTemplateRepository, UpdatePageTemplateDto},
+        pages::CreatePageDto,
+        users::UserRepository,
+    },
+    services::pages::PageService,
+};
+
+use super::built_in;
+
+fn insufficient_permissions() -> ErrorResponse {
+    ErrorResponse::forbidden(
+        codes::ForbiddenErrorCode::InsufficientPermissions,
+        Some(HashMap::from([(
+            "message".to_string(),
+            "Insufficient permissions".to_string(),
+        )])),
+        None,
+    )
+}
+
+fn template_not_found() -> ErrorResponse {
+    ErrorResponse::not_found(
+        codes::NotFoundErrorCode::NotFound,
+        None,
+        Some("Page template not found".to_string()),
+    )
+}
+
+/// Creators can always manage their templates, workspace admins can manage every template.
+/// Built-in templates cannot be managed.
+fn can_manage(template: &PageTemplate, workspace_access: &WorkspaceAccess) -> bool {
+    template.workspace_id.is_some()
+        && (template.created_by == Some(workspace_access.user_id)
+            || workspace_access.role >= Role::Admin)
+}
+
+/// Values of the placeholders of a template.
+struct Placeholders {
+    date: String,
+    user: String,
+}
+
+impl Placeholders {
+    fn expand(&self, text: &str) -> String {
+        text.replace("{{date}}", &self.date)
+            .replace("{{user}}", &self.user)
+    }
+
+    fn expand_content(&self, content: &mut TipTapContent) {
+        content.visit_mut(|node| {
+            if let Some(serde_json::Value::String(text)) = node.get_mut("text") {
+                *text = self.expand(text);
+            }
+
+            Visit::Continue
+        });
+    }
+}
+
+pub struct PageTemplateService;
+
+impl PageTemplateService {
+    // QUERIES
+
+    /// Built-in templates first, then the templates of the workspace.
+    pub async fn get_all<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        workspace_id: Uuid,
+    ) -> Result<Vec<PageTemplate>, ErrorResponse> {
+        let templates = PageTemplateRepository::get_all_by_workspace_id(executor, workspace_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(built_in::all().into_iter().chain(templates).collect())
+    }
+
+    /// Templates of other workspaces are reported as not found.
+    pub async fn get_one<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        workspace_id: Uuid,
+        template_id: Uuid,
+    ) -> Result<PageTemplate, ErrorResponse> {
+        if let Some(template) = built_in::get(template_id) {
+            return Ok(template);
+        }
+
+        let template = PageTemplateRepository::get_one_by_id(executor, template_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        if template.workspace_id != Some(workspace_id) {
+            return Err(template_not_found());
+        }
+
+        Ok(template)
+    }
+
+    // COMMANDS
+
+    pub async fn create(
+        pool: &sqlx::PgPool,
+        workspace_access: &WorkspaceAccess,
+        dto: CreatePageTemplateRequest,
+    ) -> Result<PageTemplate, ErrorResponse> {
+        if workspace_access.role < Role::Member {
+            return Err(insufficient_permissions());
+        }
+
+        let statuses = match (&dto.r#type, dto.statuses) {
+            (PageType::Board, None) => PageService::default_board_statuses(),
+            (_, statuses) => statuses.unwrap_or_default(),
+        };
+
+        PageTemplateRepository::create(
+            pool,
+            CreatePageTemplateDto {
+                workspace_id: workspace_access.workspace_id,
+                created_by: workspace_access.user_id,
+                name: dto.name,
+                r#type: dto.r#type,
+                title_pattern: dto.title_pattern,
+                content: Json(dto.content),
+                statuses: Json(statuses),
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    pub async fn update(
+        pool: &sqlx::PgPool,
+        workspace_access: &WorkspaceAccess,
+        template_id: Uuid,
+        dto: UpdatePageTemplateRequest,
+    ) -> Result<PageTemplate, ErrorResponse> {
+        let template = Self::get_one(pool, workspace_access.workspace_id, template_id).await?;
+
+        if !can_manage(&template, workspace_access) {
+            return Err(insufficient_permissions());
+        }
+
+        let mut errors = HashMap::new();
+        validate_template_type(
+            &template.r#type,
+            dto.content.as_ref().and_then(|c| c.as_ref()),
+            dto.statuses.as_deref(),
+            &mut errors,
+        );
+
+        if !errors.is_empty() {
+            return Err(ErrorResponse::unprocessable_entity(
+                codes::UnprocessableEntityErrorCode::ValidationErrors,
+                Some(errors),
+                None,
+            ));
+        }
+
+        PageTemplateRepository::update(
+            pool,
+            template.id,
+            UpdatePageTemplateDto {
+                name: dto.name,
+                title_pattern: dto.title_pattern,
+                content: dto.content.map(Json),
+                statuses: dto.statuses.map(Json),
+            },
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    pub async fn delete(
+        pool: &sqlx::PgPool,
+        workspace_access: &WorkspaceAccess,
+        template_id: Uuid,
+    ) -> Result<PageTemplate, ErrorResponse> {
+        let template = Self::get_one(pool, workspace_access.workspace_id, template_id).await?;
+
+        if !can_manage(&template, workspace_access) {
+            return Err(insufficient_permissions());
+        }
+
+        PageTemplateRepository::delete(pool, template.id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Creates a page from a template. The title and content of the request win over the
+    /// template ones, `{{date}}` and `{{user}}` are expanded in the template ones.
+    pub async fn create_page(
+        executor: &mut PgConnection,
+        workspace_id: Uuid,
+        owner_id: Uuid,
+        template_id: Uuid,
+        dto: CreatePageRequest,
+    ) -> Result<Page, ErrorResponse> {
+        let template = Self::get_one(&mut *executor, workspace_id, template_id).await?;
+
+        if template.r#type != dto.r#type {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "type".to_string(),
+                    format!("The template creates {} pages", template.r#type),
+                )])),
+                None,
+            ));
+        }
+
+        let owner = UserRepository::get_one_by_id(&mut *executor, owner_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let placeholders = Placeholders {
+            date: Utc::now().format("%Y-%m-%d").to_string(),
+            user: owner.username,
+        };
+
+        let title = match dto.title.trim().is_empty() {
+            true => placeholders.expand(&template.title_pattern),
+            false => dto.title,
+        };
+
+        let content = dto.content.or_else(|| {
+            template.content.0.map(|mut content| {
+                placeholders.expand_content(&mut content);
+                content
+            })
+        });
+
+        PageService::create_with_statuses(
+            executor,
+            CreatePageDto {
+                title,
+                r#type: dto.r#type,
+                parent_page_id: dto.parent_page_id,
+                content,
+                workspace_id,
+                owner_id,
+            },
+            template.statuses.0,
+        )
+        .await
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashSet;
+
+use chrono::{Duration, Utc};
+use error_handlers::handlers::ErrorResponse;
+use similar::{ChangeTag, TextDiff};
+use sql::{
+    page_versions::model::{PageVersion, PageVersionSummary},
+    shared::tiptap_content::TipTapContent,
+};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    controllers::page_versions::dto::{PageVersionDiffLine, PageVersionDiffOp},
+    repos::{
+        assets::AssetsRepository, page_versions::PageVersionRepository, pages::PageRepository,
+    },
+    services::{
+        links::LinkService, mentions::MentionService, page_collaboration::PageCollaborationService,
+    },
+};
+
+/// Saves of one editor closer together than this are coalesced into a single version.
+const SESSION_GAP: Duration = Duration::minutes(10);
+
+pub struct PageVersionService;
+
+impl PageVersionService {
+    // QUERIES
+
+    pub async fn get_list(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<(Vec<PageVersionSummary>, i64), ErrorResponse> {
+        let limit = limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT);
+        let offset = offset.unwrap_or(sql::shared::constants::DEFAULT_OFFSET);
+
+        PageVersionRepository::get_list(pool, page_id, limit, offset)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn get_one<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+        page_id: Uuid,
+        version_id: Uuid,
+    ) -> Result<PageVersion, ErrorResponse> {
+        PageVersionRepository::get_one_by_id(executor, page_id, version_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Line diff of the plain text of a version against another version,
+    /// or against the current page content when `compare_to` is `None`.
+    pub async fn diff(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        version_id: Uuid,
+        compare_to: Option<Uuid>,
+    ) -> Result<Vec<PageVersionDiffLine>, ErrorResponse> {
+        let version = Self::get_one(pool, page_id, version_id).await?;
+
+        let target = match compare_to {
+            Some(compare_to) => Self::get_one(pool, page_id, compare_to).await?.content.0,
+            None => {
+                PageRepository::get_text_page_content(pool, page_id)
+                    .await
+                    .map_err(ErrorResponse::from)?
+                    .content
+                    .0
+            }
+        };
+
+        let old_text = version
+            .content
+            .0
+            .map(|c| c.to_plain_text())
+            .unwrap_or_default();
+        let new_text = target.map(|c| c.to_plain_text()).unwrap_or_default();
+
+        let diff = TextDiff::from_lines(&old_text, &new_text);
+
+        Ok(diff
+            .iter_all_changes()
+            .map(|change| PageVersionDiffLine {
+                op: match change.tag() {
+                    ChangeTag::Equal => PageVersionDiffOp::Equal,
+                    ChangeTag::Insert => PageVersionDiffOp::Insert,
+                    ChangeTag::Delete => PageVersionDiffOp::Delete,
+                },
+                text: change.value().trim_end_matches('\n').to_string(),
+            })
+            .collect())
+    }
+
+    // COMMANDS
+
+    /// Records `content` as saved by `author_id`, must run before the page content is replaced.
+    /// The first recorded save also keeps the content it replaces as a baseline version.
+    pub async fn record(
+        executor: &mut PgConnection,
+        page_id: Uuid,
+        author_id: Uuid,
+        content: Option<&TipTapContent>,
+        coalesce: bool,
+    ) -> Result<PageVersion, ErrorResponse> {
+        let latest = PageVersionRepository::get_latest_by_page_id(&mut *executor, page_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        match latest {
+            Some(latest)
+                if coalesce
+                    && latest.created_by == Some(author_id)
+                    && Utc::now() - latest.updated_at < SESSION_GAP =>
+            {
+                return PageVersionRepository::update_content(&mut *executor, latest.id, content)
+                    .await
+                    .map_err(ErrorResponse::from);
+            }
+            Some(_) => {}
+            None => {
+                let current = PageRepository::get_text_page_content(&mut *executor, page_id)
+                    .await
+                    .map_err(ErrorResponse::from)?;
+
+                if let Some(baseline) = current.content.0 {
+                    PageVersionRepository::create(&mut *executor, page_id, Some(&baseline), None)
+                        .await
+                        .map_err(ErrorResponse::from)?;
+                }
+            }
+        }
+
+        PageVersionRepository::create(&mut *executor, page_id, content, Some(author_id))
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Makes the content of a version the current page content, as a new version of its own.
+    /// File nodes whose asset no longer exists are dropped, so the restored page never
+    /// references missing files.
+    pub async fn restore(
+        executor: &mut PgConnection,
+        page_id: Uuid,
+        version_id: Uuid,
+        author_id: Uuid,
+    ) -> Result<PageVersion, ErrorResponse> {
+        let version = Self::get_one(&mut *executor, page_id, version_id).await?;
+
+        let mut content = version.content.0;
+
+        if let Some(content) = &mut content {
+            let existing_assets =
+                AssetsRepository::get_existing_ids(&mut *executor, &content.file_asset_ids())
+                    .await
+                    .map_err(ErrorResponse::from)?
+                    .into_iter()
+                    .collect::<HashSet<_>>();
+
+            content.retain_file_nodes(|attrs| {
+                attrs.id.is_some_and(|id| existing_assets.contains(&id))
+            });
+        }
+
+        PageCollaborationService::replace_content(&mut *executor, page_id, content.as_ref())
+            .await?;
+
+        let restored =
+            Self::record(&mut *executor, page_id, author_id, content.as_ref(), false).await?;
+
+        MentionService::sync(&mut *executor, page_id, None, author_id, content.as_ref()).await?;
+        LinkService::sync(&mut *executor, page_id, None, content.as_ref()).await?;
+
+        PageRepository::update_content(&mut *executor, page_id, content)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(restored)
+    }
+}
@@ -1,27 +1,39 @@
 use std::collections::{HashMap, HashSet};
 
-use error_handlers::handlers::ErrorResponse;
-use sql::page::model::{Page, PageAccess, PageType};
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    checklist_items::model::ChecklistProgress,
+    page::model::{Page, PageAccess, PageType, Role},
+    page_templates::model::TemplateBoardStatus,
+    shared::types::ShiftAction,
+};
 use sqlx::PgConnection;
 use uuid::Uuid;
 
 use crate::{
     controllers::{
         board_statuses::dto::BoardStatusResponse,
+        board_views::dto::BoardViewResponse,
         page::dto::{
             DetailedPageResponse, DetailedPageResponseBase, DetailedPageResponseBoard,
-            DetailedPageResponseGroup, DetailedPageResponseText, PageAccessResponse, PageSummary,
-            TaskSummary, UpdatePageRequest,
+            DetailedPageResponseGroup, DetailedPageResponseText, MovePageRequest,
+            PageAccessResponse, PageResponse, PageSummary, TaskSummary, UpdatePageRequest,
         },
     },
     repos::{
         board_statuses::{BoardStatusRepository, CreateBoardStatusDto},
+        checklist_items::ChecklistItemRepository,
+        page_comments::PageCommentRepository,
         pages::{
             CreatePageAccessDto, CreatePageDto, PageRepository, UpdatePageAccessDto, UpdatePageDto,
         },
         tasks::TaskRepository,
         users::UserRepository,
     },
+    services::{
+        board_views::BoardViewService, links::LinkService, mentions::MentionService,
+        page_collaboration::PageCollaborationService, page_versions::PageVersionService,
+    },
     shared::extractors::x_user_language::DEFAULT_LANGUAGE,
 };
 
@@ -48,15 +60,38 @@ impl PageService {
             .map_err(ErrorResponse::from)
     }
 
+    pub async fn get_trash<'a>(
+        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres> + Copy,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<(Vec<Page>, i64), ErrorResponse> {
+        PageRepository::get_trash(
+            executor,
+            workspace_id,
+            user_id,
+            limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT),
+            offset.unwrap_or(sql::shared::constants::DEFAULT_OFFSET),
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
     pub async fn get_detailed_page<'a>(
         executor: impl sqlx::Executor<'a, Database = sqlx::Postgres> + Copy,
         page_id: Uuid,
         page_access: PageAccess,
         lang: Option<String>,
+        view_id: Option<Uuid>,
+        include_descendants: bool,
     ) -> Result<DetailedPageResponse, ErrorResponse> {
         let lang = lang.unwrap_or(DEFAULT_LANGUAGE.to_string());
         let page = PageRepository::get_one_by_id(executor, page_id).await?;
 
+        let user_id = page_access.user_id;
+        let ancestors = PageRepository::get_ancestors(executor, page_id, user_id).await?;
+        let comment_count = PageCommentRepository::count_open_threads(executor, page_id).await?;
         let base = DetailedPageResponseBase {
             created_at: page.created_at,
             updated_at: page.updated_at,
@@ -64,6 +99,8 @@ impl PageService {
             id: page.id,
             title: page.title,
             user_role: page_access.role,
+            ancestors: ancestors.iter().map(PageSummary::from).collect(),
+            comment_count,
         };
 
         match page.r#type {
@@ -76,20 +113,56 @@ impl PageService {
                 }))
             }
             PageType::Board => {
+                let view = match view_id {
+                    Some(view_id) => Some(
+                        BoardViewService::get_visible_view(executor, page_id, view_id, user_id)
+                            .await?,
+                    ),
+                    None => None,
+                };
+
                 let (statuses, tasks) = tokio::join!(
                     BoardStatusRepository::get_board_statuses_by_page_id(executor, page_id),
-                    TaskRepository::get_all_tasks_by_page_id(executor, page_id),
+                    async {
+                        match &view {
+                            Some(view) => {
+                                TaskRepository::get_filtered_tasks_by_page_id(
+                                    executor,
+                                    page_id,
+                                    user_id,
+                                    &view.filters,
+                                    &view.sort,
+                                )
+                                .await
+                            }
+                            None => {
+                                TaskRepository::get_all_tasks_by_page_id(executor, page_id).await
+                            }
+                        }
+                    },
                 );
 
                 let statuses = statuses?;
+                let tasks = tasks?;
+
+                let task_ids: Vec<Uuid> = tasks.iter().map(|t| t.id).collect();
+                let checklist_progress: HashMap<Uuid, ChecklistProgress> =
+                    ChecklistItemRepository::get_progress_by_task_ids(executor, &task_ids)
+                        .await?
+                        .into_iter()
+                        .map(|p| (p.task_id, p))
+                        .collect();
+
                 let mut assignee_ids = HashSet::new();
-                let tasks = tasks?
+                let tasks = tasks
                     .iter()
                     .map(|t| {
                         if let Some(assignee_id) = t.assignee_id {
                             assignee_ids.insert(assignee_id);
                         }
 
+                        let progress = checklist_progress.get(&t.id);
+
                         TaskSummary {
                             id: t.id,
                             title: t.title.clone(),
@@ -98,6 +171,8 @@ impl PageService {
                             is_draft: t.is_draft,
                             status_id: t.status_id,
                             assignee_id: t.assignee_id,
+                            checklist_done: progress.map(|p| p.done).unwrap_or_default(),
+                            checklist_total: progress.map(|p| p.total).unwrap_or_default(),
                         }
                     })
                     .collect();
@@ -119,14 +194,26 @@ impl PageService {
                         })
                         .collect(),
                     tasks,
+                    view: view.map(|view| Box::new(BoardViewResponse::from(view))),
                 }))
             }
             PageType::Group => {
                 let child_pages = PageRepository::get_child_pages(executor, page_id).await?;
+                let descendants = match include_descendants {
+                    true => Some(
+                        PageRepository::get_descendants(executor, page_id)
+                            .await?
+                            .into_iter()
+                            .map(PageResponse::from)
+                            .collect(),
+                    ),
+                    false => None,
+                };
 
                 Ok(DetailedPageResponse::Group(DetailedPageResponseGroup {
                     base,
                     child_pages: child_pages.iter().map(PageSummary::from).collect(),
+                    descendants,
                 }))
             }
         }
@@ -175,25 +262,69 @@ impl PageService {
         executor: &mut PgConnection,
         dto: CreatePageDto,
     ) -> Result<Page, ErrorResponse> {
+        Self::create_with_statuses(executor, dto, Self::default_board_statuses()).await
+    }
+
+    /// Statuses of a new board page.
+    pub fn default_board_statuses() -> Vec<TemplateBoardStatus> {
+        crate::shared::constants::INIT_BOARD_STATUSES
+            .iter()
+            .map(|status| TemplateBoardStatus {
+                initial: status.initial,
+                localizations: status
+                    .localizations
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect::<HashMap<String, String>>(),
+            })
+            .collect()
+    }
+
+    /// Same as `create`, board pages get `statuses` in order instead of the default ones.
+    /// The first status is the initial one when none is marked.
+    pub async fn create_with_statuses(
+        executor: &mut PgConnection,
+        dto: CreatePageDto,
+        statuses: Vec<TemplateBoardStatus>,
+    ) -> Result<Page, ErrorResponse> {
+        if let Some(parent_page_id) = dto.parent_page_id {
+            Self::validate_parent(
+                &mut *executor,
+                dto.workspace_id,
+                parent_page_id,
+                dto.owner_id,
+            )
+            .await?;
+        }
+
+        let author_id = dto.owner_id;
+        let content = dto.content.clone();
+
+        PageRepository::lock_tree(&mut *executor, dto.workspace_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
         let page = PageRepository::create(&mut *executor, dto)
             .await
             .map_err(ErrorResponse::from)?;
 
+        if page.r#type == PageType::Text {
+            MentionService::sync(&mut *executor, page.id, None, author_id, content.as_ref())
+                .await?;
+            LinkService::sync(&mut *executor, page.id, None, content.as_ref()).await?;
+        }
+
         if page.r#type == PageType::Board {
-            for status in crate::shared::constants::INIT_BOARD_STATUSES {
-                let localizations = status
-                    .localizations
-                    .iter()
-                    .map(|(k, v)| (k.to_string(), v.to_string()))
-                    .collect::<HashMap<String, String>>();
+            let has_initial = statuses.iter().any(|status| status.initial);
 
+            for (index, status) in statuses.into_iter().enumerate() {
                 BoardStatusRepository::create(
                     &mut *executor,
                     CreateBoardStatusDto {
                         page_id: page.id,
-                        position: status.position,
-                        initial: Some(status.initial),
-                        localizations: sqlx::types::Json(localizations),
+                        position: index as i32 + 1,
+                        initial: Some(status.initial || (!has_initial && index == 0)),
+                        localizations: sqlx::types::Json(status.localizations),
                     },
                 )
                 .await
@@ -207,6 +338,7 @@ impl PageService {
     pub async fn update(
         executor: &mut PgConnection,
         id: Uuid,
+        author_id: Uuid,
         dto: UpdatePageRequest,
     ) -> Result<Page, ErrorResponse> {
         let page = if dto.title.is_some() {
@@ -220,6 +352,16 @@ impl PageService {
         };
 
         if let Some(content) = dto.content {
+            if page.r#type == PageType::Text {
+                PageCollaborationService::replace_content(&mut *executor, id, content.as_ref())
+                    .await?;
+                PageVersionService::record(&mut *executor, id, author_id, content.as_ref(), true)
+                    .await?;
+            }
+
+            MentionService::sync(&mut *executor, id, None, author_id, content.as_ref()).await?;
+            LinkService::sync(&mut *executor, id, None, content.as_ref()).await?;
+
             PageRepository::update_content(&mut *executor, id, content)
                 .await
                 .map_err(ErrorResponse::from)?;
@@ -228,11 +370,208 @@ impl PageService {
         Ok(page)
     }
 
-    pub async fn delete<'a>(
-        executor: impl sqlx::Executor<'a, Database = sqlx::Postgres>,
+    /// Moves the page under `parent_page_id` (or to the root) at `position`, shifting siblings on both sides.
+    pub async fn move_to_position(
+        executor: &mut PgConnection,
         id: Uuid,
+        user_id: Uuid,
+        dto: MovePageRequest,
     ) -> Result<Page, ErrorResponse> {
-        PageRepository::delete(executor, id)
+        let workspace_id = PageRepository::get_one_by_id(&mut *executor, id)
+            .await
+            .map_err(ErrorResponse::from)?
+            .workspace_id;
+
+        PageRepository::lock_tree(&mut *executor, workspace_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        // Read again, a concurrent move may have changed it meanwhile
+        let page = PageRepository::get_one_by_id(&mut *executor, id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        if let Some(parent_page_id) = dto.parent_page_id {
+            let is_cycle = PageRepository::is_ancestor_or_self(&mut *executor, id, parent_page_id)
+                .await
+                .map_err(ErrorResponse::from)?;
+
+            if is_cycle {
+                return Err(ErrorResponse::bad_request(
+                    codes::BadRequestErrorCode::InvalidBody,
+                    Some(HashMap::from([(
+                        "parentPageId".to_string(),
+                        "A page cannot be moved into itself or its own child pages".to_string(),
+                    )])),
+                    None,
+                ));
+            }
+
+            Self::validate_parent(&mut *executor, page.workspace_id, parent_page_id, user_id)
+                .await?;
+        }
+
+        let is_same_parent = page.parent_page_id == dto.parent_page_id;
+
+        let last_position = PageRepository::get_last_position(
+            &mut *executor,
+            page.workspace_id,
+            dto.parent_page_id,
+        )
+        .await
+        .map_err(ErrorResponse::from)?
+        .unwrap_or_default();
+
+        // A page joining new siblings may also take the slot after the last one
+        let max_position = if is_same_parent {
+            last_position.max(page.position)
+        } else {
+            last_position + 1
+        };
+        let position = dto.position.unwrap_or(max_position).clamp(1, max_position);
+
+        if is_same_parent {
+            if position == page.position {
+                return Ok(page);
+            }
+
+            let (start, end, action) = if position > page.position {
+                (page.position + 1, position, ShiftAction::Minus)
+            } else {
+                (position, page.position - 1, ShiftAction::Plus)
+            };
+
+            PageRepository::shift_pages_position(
+                &mut *executor,
+                page.workspace_id,
+                page.parent_page_id,
+                Some(start),
+                Some(end),
+                action,
+            )
+            .await
+            .map_err(ErrorResponse::from)?;
+        } else {
+            PageRepository::shift_pages_position(
+                &mut *executor,
+                page.workspace_id,
+                page.parent_page_id,
+                Some(page.position + 1),
+                None,
+                ShiftAction::Minus,
+            )
+            .await
+            .map_err(ErrorResponse::from)?;
+
+            PageRepository::shift_pages_position(
+                &mut *executor,
+                page.workspace_id,
+                dto.parent_page_id,
+                Some(position),
+                None,
+                ShiftAction::Plus,
+            )
+            .await
+            .map_err(ErrorResponse::from)?;
+        }
+
+        PageRepository::move_to(&mut *executor, id, dto.parent_page_id, position)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Moves the page with its child pages to trash, the purge worker removes them after the retention window.
+    /// The siblings after the page move up to close the gap.
+    pub async fn delete(executor: &mut PgConnection, id: Uuid) -> Result<Page, ErrorResponse> {
+        let workspace_id = PageRepository::get_one_by_id(&mut *executor, id)
+            .await
+            .map_err(ErrorResponse::from)?
+            .workspace_id;
+
+        PageRepository::lock_tree(&mut *executor, workspace_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let page = PageRepository::trash(&mut *executor, id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        PageRepository::shift_pages_position(
+            &mut *executor,
+            page.workspace_id,
+            page.parent_page_id,
+            Some(page.position + 1),
+            None,
+            ShiftAction::Minus,
+        )
+        .await
+        .map_err(ErrorResponse::from)?;
+
+        Ok(page)
+    }
+
+    /// Restores a trashed page with the child pages trashed along with it. The page goes to the end
+    /// of its former siblings, or to the workspace root when its parent is still in trash.
+    pub async fn restore(
+        executor: &mut PgConnection,
+        workspace_id: Uuid,
+        id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Page, ErrorResponse> {
+        let page = match PageRepository::get_one_by_id(&mut *executor, id).await {
+            Ok(page) if page.workspace_id == workspace_id && page.deleted_at.is_some() => page,
+            Ok(_) | Err(sqlx::Error::RowNotFound) => {
+                return Err(ErrorResponse::not_found(
+                    codes::NotFoundErrorCode::NotFound,
+                    None,
+                    Some("Page not found in trash".to_string()),
+                ));
+            }
+            Err(e) => return Err(ErrorResponse::from(e)),
+        };
+
+        let page_access = PageRepository::get_trashed_page_access(&mut *executor, user_id, id)
+            .await
+            .map_err(|e| match e {
+                sqlx::Error::RowNotFound => {
+                    ErrorResponse::forbidden(codes::ForbiddenErrorCode::AccessDenied, None, None)
+                }
+                error => ErrorResponse::from(error),
+            })?;
+
+        if page_access.role < Role::Member {
+            return Err(ErrorResponse::forbidden(
+                codes::ForbiddenErrorCode::InsufficientPermissions,
+                Some(HashMap::from([(
+                    "message".to_string(),
+                    "Insufficient permissions".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        PageRepository::lock_tree(&mut *executor, workspace_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let parent_page_id = match page.parent_page_id {
+            Some(parent_page_id) => PageRepository::get_one_by_id(&mut *executor, parent_page_id)
+                .await
+                .map_err(ErrorResponse::from)?
+                .deleted_at
+                .is_none()
+                .then_some(parent_page_id),
+            None => None,
+        };
+
+        let position =
+            PageRepository::get_last_position(&mut *executor, workspace_id, parent_page_id)
+                .await
+                .map_err(ErrorResponse::from)?
+                .unwrap_or_default()
+                + 1;
+
+        PageRepository::restore(&mut *executor, id, parent_page_id, position)
             .await
             .map_err(ErrorResponse::from)
     }
@@ -274,4 +613,62 @@ impl PageService {
                 error => ErrorResponse::internal_server_error(Some(error.to_string())),
             })
     }
+
+    /// Child pages can only live in a group of the same workspace that the user can edit.
+    async fn validate_parent(
+        executor: &mut PgConnection,
+        workspace_id: Uuid,
+        parent_page_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        let invalid_parent = |message: &str| {
+            ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidBody,
+                Some(HashMap::from([(
+                    "parentPageId".to_string(),
+                    message.to_string(),
+                )])),
+                None,
+            )
+        };
+
+        let parent = match PageRepository::get_one_by_id(&mut *executor, parent_page_id).await {
+            Ok(parent) if parent.workspace_id == workspace_id && parent.deleted_at.is_none() => {
+                parent
+            }
+            Ok(_) | Err(sqlx::Error::RowNotFound) => {
+                return Err(invalid_parent("Parent page not found in this workspace"));
+            }
+            Err(e) => return Err(ErrorResponse::from(e)),
+        };
+
+        if parent.r#type != PageType::Group {
+            return Err(invalid_parent("Parent page must be a group"));
+        }
+
+        let parent_access =
+            PageRepository::get_one_page_access(&mut *executor, user_id, parent_page_id)
+                .await
+                .map_err(|e| match e {
+                    sqlx::Error::RowNotFound => ErrorResponse::forbidden(
+                        codes::ForbiddenErrorCode::AccessDenied,
+                        None,
+                        None,
+                    ),
+                    error => ErrorResponse::from(error),
+                })?;
+
+        if parent_access.role < Role::Member {
+            return Err(ErrorResponse::forbidden(
+                codes::ForbiddenErrorCode::InsufficientPermissions,
+                Some(HashMap::from([(
+                    "message".to_string(),
+                    "Insufficient permissions".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::search::model::{SearchHit, SearchHitKind};
+use uuid::Uuid;
+
+use crate::repos::search::SearchRepository;
+
+pub struct SearchService;
+
+impl SearchService {
+    // QUERIES
+
+    /// Every word of `query` has to match, the last letters of a word may be missing.
+    pub async fn search(
+        pool: &sqlx::PgPool,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        query: &str,
+        kinds: Option<Vec<SearchHitKind>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> Result<(Vec<SearchHit>, i64), ErrorResponse> {
+        let Some(ts_query) = Self::to_prefix_ts_query(query) else {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidQueryParams,
+                Some(HashMap::from([(
+                    "query".to_string(),
+                    "Query must contain at least one letter or digit".to_string(),
+                )])),
+                None,
+            ));
+        };
+
+        let kinds = kinds.filter(|kinds| !kinds.is_empty()).unwrap_or_else(|| {
+            vec![
+                SearchHitKind::Page,
+                SearchHitKind::Task,
+                SearchHitKind::Message,
+            ]
+        });
+        let limit = limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT);
+        let offset = offset.unwrap_or(sql::shared::constants::DEFAULT_OFFSET);
+
+        SearchRepository::search(
+            pool,
+            &ts_query,
+            workspace_id,
+            user_id,
+            &kinds,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(ErrorResponse::from)
+    }
+
+    /// Builds a `to_tsquery` input from free text. Only letters and digits are kept,
+    /// so the user can't inject tsquery operators.
+    fn to_prefix_ts_query(query: &str) -> Option<String> {
+        let terms = query
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|term| !term.is_empty())
+            .map(|term| format!("{}:*", term.to_lowercase()))
+            .collect::<Vec<_>>();
+
+        (!terms.is_empty()).then(|| terms.join(" & "))
+    }
+}
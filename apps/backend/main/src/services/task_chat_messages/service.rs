@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use error_handlers::handlers::ErrorResponse;
+use sql::{
+    task_chat_messages::model::{TaskChatMessage, TaskChatMessageCount},
+    user::model::User,
+};
+use uuid::Uuid;
+
+use crate::repos::{
+    task_chat_messages::{TaskChatMessageFilterBy, TaskChatMessageRepository},
+    users::UserRepository,
+};
+
+pub struct TaskChatMessageService;
+
+impl TaskChatMessageService {
+    // QUERIES
+
+    pub async fn get_all_with_pagination(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        filter: TaskChatMessageFilterBy,
+    ) -> Result<(Vec<TaskChatMessage>, i64), ErrorResponse> {
+        let limit = limit.unwrap_or(sql::shared::constants::DEFAULT_LIMIT);
+        let offset = offset.unwrap_or(sql::shared::constants::DEFAULT_OFFSET);
+
+        TaskChatMessageRepository::get_list(pool, task_id, limit, offset, &filter)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn get_pinned(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+    ) -> Result<Vec<TaskChatMessage>, ErrorResponse> {
+        TaskChatMessageRepository::get_pinned_by_task_id(pool, task_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn get_count(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+    ) -> Result<TaskChatMessageCount, ErrorResponse> {
+        let counts = TaskChatMessageRepository::get_counts_by_task_ids(pool, &[task_id])
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(counts.into_iter().next().unwrap_or(TaskChatMessageCount {
+            task_id,
+            total: 0,
+            pinned: 0,
+        }))
+    }
+
+    pub async fn get_counts_by_page_id(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+    ) -> Result<Vec<TaskChatMessageCount>, ErrorResponse> {
+        TaskChatMessageRepository::get_counts_by_page_id(pool, page_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    /// Authors of the given messages, keyed by user id.
+    pub async fn get_authors(
+        pool: &sqlx::PgPool,
+        messages: &[TaskChatMessage],
+    ) -> Result<HashMap<Uuid, User>, ErrorResponse> {
+        let mut user_ids = messages.iter().map(|m| m.user_id).collect::<Vec<_>>();
+        user_ids.sort_unstable();
+        user_ids.dedup();
+
+        let users = UserRepository::get_users_by_ids(pool, &user_ids)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        Ok(users.into_iter().map(|u| (u.id, u)).collect())
+    }
+}
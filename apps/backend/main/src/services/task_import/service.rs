@@ -0,0 +1,596 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, NaiveDate, Utc};
+use error_handlers::{
+    codes::{self, FieldErrorCode},
+    handlers::ErrorResponse,
+};
+use sql::{page::model::PageType, shared::tiptap_content::TipTapContent};
+use uuid::Uuid;
+
+use crate::{
+    controllers::task::dto::{
+        TaskImportMapping, TaskImportResponse, TaskImportRowPreview, TaskResponse,
+    },
+    repos::{
+        board_statuses::BoardStatusRepository,
+        pages::PageRepository,
+        tasks::{CreateTaskDto, TaskRepository},
+        users::UserRepository,
+    },
+};
+
+const MAX_IMPORT_ROWS: usize = 1000;
+const MAX_TITLE_LENGTH: usize = 255;
+
+/// Column indices resolved from the mapping against the CSV header.
+struct ColumnIndices {
+    title: usize,
+    status: Option<usize>,
+    assignee_email: Option<usize>,
+    due_date: Option<usize>,
+    description: Option<usize>,
+}
+
+impl ColumnIndices {
+    fn resolve(
+        headers: &csv::StringRecord,
+        mapping: &TaskImportMapping,
+    ) -> Result<Self, HashMap<String, String>> {
+        let mut missing = HashMap::new();
+
+        let mut find = |field: &str, column: Option<&String>| -> Option<usize> {
+            let column = column?;
+            let index = headers
+                .iter()
+                .position(|h| h.trim().eq_ignore_ascii_case(column.trim()));
+
+            if index.is_none() {
+                missing.insert(
+                    format!("mapping.{field}"),
+                    format!("Column '{column}' not found"),
+                );
+            }
+
+            index
+        };
+
+        let title = find("title", Some(&mapping.title));
+        let status = find("status", mapping.status.as_ref());
+        let assignee_email = find("assigneeEmail", mapping.assignee_email.as_ref());
+        let due_date = find("dueDate", mapping.due_date.as_ref());
+        let description = find("description", mapping.description.as_ref());
+
+        match title {
+            Some(title) if missing.is_empty() => Ok(Self {
+                title,
+                status,
+                assignee_email,
+                due_date,
+                description,
+            }),
+            _ => Err(missing),
+        }
+    }
+}
+
+struct RawRow {
+    line: usize,
+    title: String,
+    status: Option<String>,
+    assignee_email: Option<String>,
+    due_date: Option<String>,
+    description: Option<String>,
+}
+
+struct ParsedRow {
+    preview: TaskImportRowPreview,
+    description: Option<TipTapContent>,
+}
+
+/// Accepts RFC 3339 timestamps and plain `YYYY-MM-DD` dates (midnight UTC).
+fn parse_due_date(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|d| d.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+                .map(|d| d.and_utc())
+        })
+}
+
+/// Errors are returned as field details for an `InvalidBody` response.
+fn read_rows(
+    bytes: &[u8],
+    mapping: &TaskImportMapping,
+) -> Result<Vec<RawRow>, HashMap<String, String>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(bytes);
+
+    let headers = reader
+        .headers()
+        .cloned()
+        .map_err(|e| HashMap::from([("file".to_string(), e.to_string())]))?;
+
+    let columns = ColumnIndices::resolve(&headers, mapping)?;
+
+    let cell = |record: &csv::StringRecord, index: Option<usize>| {
+        index
+            .and_then(|i| record.get(i))
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    };
+
+    let mut rows = Vec::new();
+
+    for record in reader.records() {
+        let record = record.map_err(|e| HashMap::from([("file".to_string(), e.to_string())]))?;
+
+        if record.iter().all(|v| v.is_empty()) {
+            continue;
+        }
+
+        if rows.len() == MAX_IMPORT_ROWS {
+            return Err(HashMap::from([(
+                "file".to_string(),
+                format!("At most {MAX_IMPORT_ROWS} rows can be imported at once"),
+            )]));
+        }
+
+        rows.push(RawRow {
+            line: record
+                .position()
+                .map(|p| p.line() as usize)
+                .unwrap_or_default(),
+            title: cell(&record, Some(columns.title)).unwrap_or_default(),
+            status: cell(&record, columns.status),
+            assignee_email: cell(&record, columns.assignee_email),
+            due_date: cell(&record, columns.due_date),
+            description: cell(&record, columns.description),
+        });
+    }
+
+    Ok(rows)
+}
+
+/// Validates a row against the statuses of the board and the emails of the workspace members.
+fn parse_row(
+    raw: RawRow,
+    status_ids: &HashMap<String, Uuid>,
+    default_status_id: Option<Uuid>,
+    user_ids: &HashMap<String, Uuid>,
+) -> ParsedRow {
+    let mut errors = HashMap::new();
+
+    if raw.title.is_empty() {
+        errors.insert(
+            "title".to_string(),
+            FieldErrorCode::FieldRequired.to_string(),
+        );
+    } else if raw.title.chars().count() > MAX_TITLE_LENGTH {
+        errors.insert(
+            "title".to_string(),
+            FieldErrorCode::FieldTooLong.to_string(),
+        );
+    }
+
+    let status_id = match &raw.status {
+        Some(status) => status_ids.get(&status.to_lowercase()).copied(),
+        None => default_status_id,
+    };
+    if status_id.is_none() {
+        errors.insert(
+            "status".to_string(),
+            FieldErrorCode::InvalidEnumValue.to_string(),
+        );
+    }
+
+    let assignee_id = raw
+        .assignee_email
+        .as_ref()
+        .and_then(|e| user_ids.get(&e.to_lowercase()).copied());
+    if raw.assignee_email.is_some() && assignee_id.is_none() {
+        errors.insert(
+            "assigneeEmail".to_string(),
+            FieldErrorCode::InvalidEmail.to_string(),
+        );
+    }
+
+    let due_date = raw.due_date.as_deref().and_then(parse_due_date);
+    if raw.due_date.is_some() && due_date.is_none() {
+        errors.insert(
+            "dueDate".to_string(),
+            FieldErrorCode::InvalidDate.to_string(),
+        );
+    }
+
+    ParsedRow {
+        preview: TaskImportRowPreview {
+            row: raw.line,
+            title: raw.title,
+            status_id,
+            assignee_id,
+            due_date,
+            has_description: raw.description.is_some(),
+            errors,
+        },
+        description: raw
+            .description
+            .as_deref()
+            .map(TipTapContent::from_plain_text),
+    }
+}
+
+pub struct TaskImportService;
+
+impl TaskImportService {
+    /// Validates every row first; nothing is written unless all rows are valid and `dry_run` is off.
+    pub async fn import_csv(
+        pool: &sqlx::PgPool,
+        page_id: Uuid,
+        reporter_id: Uuid,
+        bytes: &[u8],
+        mapping: TaskImportMapping,
+        dry_run: bool,
+    ) -> Result<TaskImportResponse, ErrorResponse> {
+        let page = PageRepository::get_one_by_id(pool, page_id).await?;
+
+        if page.r#type != PageType::Board {
+            return Err(ErrorResponse::bad_request(
+                codes::BadRequestErrorCode::InvalidParams,
+                Some(HashMap::from([(
+                    "pageId".to_string(),
+                    "Tasks can only be imported into board pages".to_string(),
+                )])),
+                None,
+            ));
+        }
+
+        let raw_rows = read_rows(bytes, &mapping).map_err(|details| {
+            ErrorResponse::bad_request(codes::BadRequestErrorCode::InvalidBody, Some(details), None)
+        })?;
+
+        let statuses = BoardStatusRepository::get_board_statuses_by_page_id(pool, page_id).await?;
+        let default_status_id = statuses
+            .iter()
+            .find(|s| s.initial)
+            .or(statuses.first())
+            .map(|s| s.id);
+
+        // Any localization of a status title may be used in the file
+        let status_ids: HashMap<String, Uuid> = statuses
+            .iter()
+            .flat_map(|s| {
+                s.localizations
+                    .values()
+                    .map(|title| (title.trim().to_lowercase(), s.id))
+            })
+            .collect();
+
+        let emails: Vec<String> = raw_rows
+            .iter()
+            .filter_map(|r| r.assignee_email.as_ref())
+            .map(|e| e.to_lowercase())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        // Only members of the workspace can be assigned, other emails are reported as invalid
+        let user_ids: HashMap<String, Uuid> =
+            UserRepository::get_workspace_members_by_emails(pool, page.workspace_id, &emails)
+                .await?
+                .into_iter()
+                .filter_map(|u| u.email.map(|e| (e.to_lowercase(), u.id)))
+                .collect();
+
+        let rows: Vec<ParsedRow> = raw_rows
+            .into_iter()
+            .map(|raw| parse_row(raw, &status_ids, default_status_id, &user_ids))
+            .collect();
+
+        if dry_run {
+            return Ok(TaskImportResponse {
+                dry_run,
+                rows: rows.into_iter().map(|r| r.preview).collect(),
+                created: vec![],
+            });
+        }
+
+        let row_errors: HashMap<String, String> = rows
+            .iter()
+            .flat_map(|r| {
+                r.preview.errors.iter().map(|(field, code)| {
+                    (format!("rows[{}].{}", r.preview.row, field), code.clone())
+                })
+            })
+            .collect();
+
+        if !row_errors.is_empty() {
+            return Err(ErrorResponse::unprocessable_entity(
+                codes::UnprocessableEntityErrorCode::ValidationErrors,
+                Some(row_errors),
+                None,
+            ));
+        }
+
+        let mut tx = pool.begin().await?;
+        let mut positions: HashMap<Uuid, i32> = HashMap::new();
+        let mut created = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let Some(status_id) = row.preview.status_id else {
+                continue;
+            };
+
+            let position = match positions.get(&status_id) {
+                Some(position) => *position,
+                None => TaskRepository::get_last_position(&mut *tx, status_id)
+                    .await?
+                    .unwrap_or_default(),
+            } + 1;
+            positions.insert(status_id, position);
+
+            let task = TaskRepository::create(
+                &mut *tx,
+                CreateTaskDto {
+                    title: row.preview.title,
+                    status_id,
+                    position,
+                    description: row.description,
+                    due_date: row.preview.due_date,
+                    assignee_id: row.preview.assignee_id,
+                    reporter_id,
+                    is_draft: false,
+                    page_id,
+                },
+            )
+            .await?;
+
+            created.push(TaskResponse::from(task));
+        }
+
+        tx.commit().await?;
+
+        Ok(TaskImportResponse {
+            dry_run,
+            rows: vec![],
+            created,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mapping() -> TaskImportMapping {
+        TaskImportMapping {
+            title: "Title".to_string(),
+            status: Some("Status".to_string()),
+            assignee_email: Some("Assignee".to_string()),
+            due_date: Some("Due".to_string()),
+            description: None,
+        }
+    }
+
+    fn raw(title: &str) -> RawRow {
+        RawRow {
+            line: 2,
+            title: title.to_string(),
+            status: None,
+            assignee_email: None,
+            due_date: None,
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_read_rows() {
+        let csv = "title , STATUS,Assignee,Due,Extra\n\
+            \x20First , Done ,a@example.com,2026-01-31,x\n\
+            ,,,,\n\
+            \"Second, quoted\",,,\n\
+            Short\n";
+
+        let rows = read_rows(csv.as_bytes(), &mapping()).unwrap();
+
+        assert_eq!(rows.len(), 3);
+
+        assert_eq!(rows[0].line, 2);
+        assert_eq!(rows[0].title, "First");
+        assert_eq!(rows[0].status.as_deref(), Some("Done"));
+        assert_eq!(rows[0].assignee_email.as_deref(), Some("a@example.com"));
+        assert_eq!(rows[0].due_date.as_deref(), Some("2026-01-31"));
+        assert_eq!(rows[0].description, None);
+
+        // Blank rows are skipped but still count as lines
+        assert_eq!(rows[1].line, 4);
+        assert_eq!(rows[1].title, "Second, quoted");
+        assert_eq!(rows[1].status, None);
+
+        // Short rows miss the trailing columns
+        assert_eq!(rows[2].line, 5);
+        assert_eq!(rows[2].title, "Short");
+        assert_eq!(rows[2].due_date, None);
+    }
+
+    #[test]
+    fn test_read_rows_missing_columns() {
+        let mapping = TaskImportMapping {
+            title: "Name".to_string(),
+            description: Some("Notes".to_string()),
+            ..mapping()
+        };
+
+        let errors = read_rows(b"Title,Status,Assignee,Due\n", &mapping)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            errors,
+            HashMap::from([
+                (
+                    "mapping.title".to_string(),
+                    "Column 'Name' not found".to_string()
+                ),
+                (
+                    "mapping.description".to_string(),
+                    "Column 'Notes' not found".to_string()
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_read_rows_limit() {
+        let mut csv = String::from("Title,Status,Assignee,Due\n");
+        for index in 0..MAX_IMPORT_ROWS {
+            csv.push_str(&format!("Task {index}\n"));
+        }
+
+        assert_eq!(
+            read_rows(csv.as_bytes(), &mapping()).unwrap().len(),
+            MAX_IMPORT_ROWS
+        );
+
+        csv.push_str("One too many\n");
+        let errors = read_rows(csv.as_bytes(), &mapping()).err().unwrap();
+
+        assert!(errors.contains_key("file"));
+    }
+
+    #[test]
+    fn test_read_rows_invalid_file() {
+        let errors = read_rows(&[0xff, 0xfe, b'\n'], &mapping()).err().unwrap();
+
+        assert!(errors.contains_key("file"));
+    }
+
+    #[test]
+    fn test_parse_due_date() {
+        assert_eq!(
+            parse_due_date("2026-01-31"),
+            Some("2026-01-31T00:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            parse_due_date("2026-01-31T10:00:00+02:00"),
+            Some("2026-01-31T08:00:00Z".parse().unwrap())
+        );
+        assert_eq!(parse_due_date("31/01/2026"), None);
+        assert_eq!(parse_due_date("2026-02-30"), None);
+    }
+
+    #[test]
+    fn test_parse_row() {
+        let todo = Uuid::new_v4();
+        let done = Uuid::new_v4();
+        let member = Uuid::new_v4();
+        let status_ids = HashMap::from([("todo".to_string(), todo), ("done".to_string(), done)]);
+        let user_ids = HashMap::from([("member@example.com".to_string(), member)]);
+
+        let valid = parse_row(
+            RawRow {
+                status: Some("DONE".to_string()),
+                assignee_email: Some("Member@Example.com".to_string()),
+                due_date: Some("2026-01-31".to_string()),
+                description: Some("First\n\nSecond".to_string()),
+                ..raw("Task")
+            },
+            &status_ids,
+            Some(todo),
+            &user_ids,
+        );
+
+        assert!(valid.preview.errors.is_empty());
+        assert_eq!(valid.preview.row, 2);
+        assert_eq!(valid.preview.status_id, Some(done));
+        assert_eq!(valid.preview.assignee_id, Some(member));
+        assert!(valid.preview.has_description);
+        assert!(valid.description.is_some());
+
+        let defaulted = parse_row(raw("Task"), &status_ids, Some(todo), &user_ids);
+
+        assert!(defaulted.preview.errors.is_empty());
+        assert_eq!(defaulted.preview.status_id, Some(todo));
+        assert_eq!(defaulted.preview.assignee_id, None);
+
+        let invalid = parse_row(
+            RawRow {
+                status: Some("Blocked".to_string()),
+                assignee_email: Some("outsider@example.com".to_string()),
+                due_date: Some("tomorrow".to_string()),
+                ..raw("")
+            },
+            &status_ids,
+            Some(todo),
+            &user_ids,
+        );
+
+        assert_eq!(
+            invalid.preview.errors,
+            HashMap::from([
+                (
+                    "title".to_string(),
+                    FieldErrorCode::FieldRequired.to_string()
+                ),
+                (
+                    "status".to_string(),
+                    FieldErrorCode::InvalidEnumValue.to_string()
+                ),
+                (
+                    "assigneeEmail".to_string(),
+                    FieldErrorCode::InvalidEmail.to_string()
+                ),
+                (
+                    "dueDate".to_string(),
+                    FieldErrorCode::InvalidDate.to_string()
+                ),
+            ])
+        );
+        assert_eq!(invalid.preview.status_id, None);
+        assert_eq!(invalid.preview.assignee_id, None);
+    }
+
+    #[test]
+    fn test_parse_row_limits() {
+        let too_long = parse_row(
+            raw(&"a".repeat(MAX_TITLE_LENGTH + 1)),
+            &HashMap::new(),
+            Some(Uuid::new_v4()),
+            &HashMap::new(),
+        );
+
+        assert_eq!(
+            too_long.preview.errors,
+            HashMap::from([(
+                "title".to_string(),
+                FieldErrorCode::FieldTooLong.to_string()
+            )])
+        );
+
+        // Characters are counted, not bytes
+        let multibyte = parse_row(
+            raw(&"é".repeat(MAX_TITLE_LENGTH)),
+            &HashMap::new(),
+            Some(Uuid::new_v4()),
+            &HashMap::new(),
+        );
+
+        assert!(multibyte.preview.errors.is_empty());
+
+        // A board without statuses has no default one
+        let no_status = parse_row(raw("Task"), &HashMap::new(), None, &HashMap::new());
+
+        assert_eq!(
+            no_status.preview.errors,
+            HashMap::from([(
+                "status".to_string(),
+                FieldErrorCode::InvalidEnumValue.to_string()
+            )])
+        );
+    }
+}
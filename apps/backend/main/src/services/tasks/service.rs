@@ -15,9 +15,11 @@ use crate::{
     },
     repos::{
         board_statuses::BoardStatusRepository,
+        task_watchers::TaskWatcherRepository,
         tasks::{CreateTaskDto, TaskRepository, UpdateTaskDto},
         users::UserRepository,
     },
+    services::{links::LinkService, mentions::MentionService},
 };
 
 pub struct TaskService;
@@ -26,6 +28,7 @@ impl TaskService {
     pub async fn update(
         pool: &sqlx::PgPool,
         id: Uuid,
+        author_id: Uuid,
         dto: UpdateTaskDto,
     ) -> Result<Task, ErrorResponse> {
         if dto.is_empty() {
@@ -99,6 +102,35 @@ impl TaskService {
             }
         }
 
+        if let Some(description) = &dto.description {
+            let synced_mentions = MentionService::sync(
+                &mut *tx,
+                current_task.page_id,
+                Some(id),
+                author_id,
+                description.as_ref(),
+            )
+            .await;
+
+            if let Err(e) = synced_mentions {
+                tx.rollback().await?;
+                return Err(e);
+            }
+
+            let synced_links = LinkService::sync(
+                &mut *tx,
+                current_task.page_id,
+                Some(id),
+                description.as_ref(),
+            )
+            .await;
+
+            if let Err(e) = synced_links {
+                tx.rollback().await?;
+                return Err(e);
+            }
+        }
+
         let updated_task = TaskRepository::update(&mut *tx, id, dto)
             .await
             .map_err(ErrorResponse::from);
@@ -130,17 +162,21 @@ impl TaskService {
         reporter_id: Uuid,
         dto: crate::controllers::task::dto::CreateTaskRequest,
     ) -> Result<Task, ErrorResponse> {
-        let last_position = TaskRepository::get_last_position(pool, dto.status_id)
+        let mut tx = pool.begin().await?;
+
+        let last_position = TaskRepository::get_last_position(&mut *tx, dto.status_id)
             .await
             .map_err(ErrorResponse::from)?
             .unwrap_or_default();
 
-        TaskRepository::create(
-            pool,
+        let description = dto.description;
+
+        let task = TaskRepository::create(
+            &mut *tx,
             CreateTaskDto {
                 title: dto.title,
                 status_id: dto.status_id,
-                description: dto.description,
+                description: description.clone(),
                 due_date: dto.due_date,
                 assignee_id: dto.assignee_id,
                 reporter_id,
@@ -150,7 +186,21 @@ impl TaskService {
             },
         )
         .await
-        .map_err(ErrorResponse::from)
+        .map_err(ErrorResponse::from)?;
+
+        MentionService::sync(
+            &mut *tx,
+            page_id,
+            Some(task.id),
+            reporter_id,
+            description.as_ref(),
+        )
+        .await?;
+        LinkService::sync(&mut *tx, page_id, Some(task.id), description.as_ref()).await?;
+
+        tx.commit().await?;
+
+        Ok(task)
     }
 
     pub async fn create_draft(
@@ -189,6 +239,44 @@ impl TaskService {
         .map_err(ErrorResponse::from)
     }
 
+    pub async fn watch(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        TaskWatcherRepository::create(pool, task_id, user_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn unwatch(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<(), ErrorResponse> {
+        TaskWatcherRepository::delete(pool, task_id, user_id)
+            .await
+            .map_err(ErrorResponse::from)
+    }
+
+    pub async fn get_watchers(
+        pool: &sqlx::PgPool,
+        task_id: Uuid,
+    ) -> Result<Vec<User>, ErrorResponse> {
+        let watchers = TaskWatcherRepository::get_by_task_id(pool, task_id)
+            .await
+            .map_err(ErrorResponse::from)?;
+
+        let user_ids: Vec<Uuid> = watchers.iter().map(|w| w.user_id).collect();
+        let users = UserRepository::get_users_by_ids(pool, &user_ids).await?;
+        let users_map: HashMap<Uuid, User> = users.into_iter().map(|u| (u.id, u)).collect();
+
+        Ok(watchers
+            .into_iter()
+            .filter_map(|w| users_map.get(&w.user_id).cloned())
+            .collect())
+    }
+
     pub async fn get_task_with_details(
         pool: &sqlx::PgPool,
         task_id: Uuid,
@@ -29,3 +29,14 @@ where
 
     Ok(Some(result))
 }
+
+/// Distinguishes an explicit `null` (`Some(None)`) from a missing field (`None`), use with `#[serde(default)]`.
+pub fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    T: serde::Deserialize<'de>,
+{
+    use serde::Deserialize;
+
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -9,6 +9,8 @@ use error_handlers::{codes, handlers::ErrorResponse};
 use reqwest::header;
 use serde::de::DeserializeOwned;
 
+use crate::shared::validation::Validate;
+
 pub struct ValidatedJson<T>(pub T);
 
 fn json_content_type(headers: &HeaderMap) -> bool {
@@ -30,7 +32,7 @@ fn json_content_type(headers: &HeaderMap) -> bool {
 
 impl<T, S> FromRequest<S> for ValidatedJson<T>
 where
-    T: DeserializeOwned,
+    T: DeserializeOwned + Validate,
     S: Send + Sync,
 {
     type Rejection = ErrorResponse;
@@ -87,6 +89,14 @@ where
             )
         })?;
 
+        value.validate().map_err(|errors| {
+            ErrorResponse::unprocessable_entity(
+                codes::UnprocessableEntityErrorCode::ValidationErrors,
+                Some(errors),
+                None,
+            )
+        })?;
+
         Ok(ValidatedJson(value))
     }
 }
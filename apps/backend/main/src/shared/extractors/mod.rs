@@ -1,4 +1,5 @@
 pub mod json;
 pub mod path;
 pub mod query;
+pub mod x_share_password;
 pub mod x_user_language;
@@ -0,0 +1,26 @@
+use axum::extract::FromRequestParts;
+
+const HEADER_NAME: &str = "x-share-password";
+
+/// Password of a protected share link, sent as a header so it does not end up in access logs.
+pub struct XSharePassword(pub Option<String>);
+
+impl<S> FromRequestParts<S> for XSharePassword
+where
+    S: Send + Sync,
+{
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(
+        parts: &mut axum::http::request::Parts,
+        _: &S,
+    ) -> Result<Self, Self::Rejection> {
+        let password = parts
+            .headers
+            .get(HEADER_NAME)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+
+        Ok(Self(password))
+    }
+}
@@ -2,3 +2,5 @@ pub mod constants;
 pub mod deserialization;
 pub mod extractors;
 pub mod generate_initials_avatar;
+pub mod tokens;
+pub mod validation;
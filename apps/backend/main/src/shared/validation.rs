@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use error_handlers::codes::FieldErrorCode;
+use sql::shared::tiptap_content::{SchemaViolation, TipTapContent};
+
+/// Checks run by `ValidatedJson` once the body is deserialized.
+/// Errors map field paths to a `FieldErrorCode`.
+pub trait Validate {
+    fn validate(&self) -> Result<(), HashMap<String, String>> {
+        Ok(())
+    }
+}
+
+fn field_error_code(violation: SchemaViolation) -> FieldErrorCode {
+    match violation {
+        SchemaViolation::MissingField => FieldErrorCode::MissingField,
+        SchemaViolation::InvalidType => FieldErrorCode::InvalidType,
+        SchemaViolation::UnknownType => FieldErrorCode::InvalidEnumValue,
+        SchemaViolation::NotAllowed => FieldErrorCode::FieldNotAllowed,
+        SchemaViolation::OutOfRange | SchemaViolation::TooDeep => FieldErrorCode::FieldOutOfRange,
+        SchemaViolation::Empty => FieldErrorCode::FieldTooShort,
+        SchemaViolation::TooLarge => FieldErrorCode::FieldTooLong,
+        SchemaViolation::DisallowedUrl => FieldErrorCode::InvalidFormat,
+    }
+}
+
+/// Adds the schema errors of a TipTap document to `errors`, under the `field` it was sent in.
+pub fn validate_tiptap_content(
+    field: &str,
+    content: Option<&TipTapContent>,
+    errors: &mut HashMap<String, String>,
+) {
+    let Some(Err(schema_errors)) = content.map(|c| c.validate()) else {
+        return;
+    };
+
+    for error in schema_errors {
+        let path = if error.path.is_empty() {
+            field.to_string()
+        } else {
+            format!("{field}.{}", error.path)
+        };
+
+        errors.insert(path, field_error_code(error.violation).to_string());
+    }
+}
+
+/// Wraps the collected field errors in a result.
+pub fn into_result(errors: HashMap<String, String>) -> Result<(), HashMap<String, String>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
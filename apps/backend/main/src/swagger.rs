@@ -6,13 +6,72 @@ use utils::swagger::SecurityAddon;
         crate::controllers::board_statuses::controller::create_board_status::create_board_status,
         crate::controllers::board_statuses::controller::get_board_statuses::get_board_statuses,
 
+        crate::controllers::board_views::controller::get_board_views::get_board_views,
+        crate::controllers::board_views::controller::create_board_view::create_board_view,
+        crate::controllers::board_views::controller::update_board_view::update_board_view,
+        crate::controllers::board_views::controller::delete_board_view::delete_board_view,
+
+        crate::controllers::calendar_feeds::controller::get_calendar_feeds::get_calendar_feeds,
+        crate::controllers::calendar_feeds::controller::create_calendar_feed::create_calendar_feed,
+        crate::controllers::calendar_feeds::controller::revoke_calendar_feed::revoke_calendar_feed,
+        crate::controllers::calendar_feeds::controller::get_calendar_feed_ics::get_calendar_feed_ics,
+
+        crate::controllers::checklist_items::controller::get_checklist_items::get_checklist_items,
+        crate::controllers::checklist_items::controller::create_checklist_item::create_checklist_item,
+        crate::controllers::checklist_items::controller::update_checklist_item::update_checklist_item,
+        crate::controllers::checklist_items::controller::move_checklist_item::move_checklist_item,
+        crate::controllers::checklist_items::controller::delete_checklist_item::delete_checklist_item,
+
+        crate::controllers::task_chat_messages::controller::get_task_chat_messages::get_task_chat_messages,
+        crate::controllers::task_chat_messages::controller::get_pinned_task_chat_messages::get_pinned_task_chat_messages,
+        crate::controllers::task_chat_messages::controller::get_task_chat_message_count::get_task_chat_message_count,
+        crate::controllers::task_chat_messages::controller::get_page_task_chat_message_counts::get_page_task_chat_message_counts,
+
+        crate::controllers::mentions::controller::get_my_mentions::get_my_mentions,
+
+        crate::controllers::links::controller::get_page_backlinks::get_page_backlinks,
+        crate::controllers::links::controller::get_task_backlinks::get_task_backlinks,
+
+        crate::controllers::search::controller::search_workspace::search_workspace,
+
+        crate::controllers::page_comments::controller::get_page_comments::get_page_comments,
+        crate::controllers::page_comments::controller::create_page_comment::create_page_comment,
+        crate::controllers::page_comments::controller::reply_to_page_comment::reply_to_page_comment,
+        crate::controllers::page_comments::controller::update_page_comment::update_page_comment,
+        crate::controllers::page_comments::controller::delete_page_comment::delete_page_comment,
+        crate::controllers::page_comments::controller::resolve_page_comment::resolve_page_comment,
+        crate::controllers::page_comments::controller::unresolve_page_comment::unresolve_page_comment,
+
+        crate::controllers::page_share_links::controller::get_page_share_links::get_page_share_links,
+        crate::controllers::page_share_links::controller::create_page_share_link::create_page_share_link,
+        crate::controllers::page_share_links::controller::revoke_page_share_link::revoke_page_share_link,
+        crate::controllers::page_share_links::controller::get_shared_page::get_shared_page,
+
+        crate::controllers::page_templates::controller::get_page_templates::get_page_templates,
+        crate::controllers::page_templates::controller::get_page_template::get_page_template,
+        crate::controllers::page_templates::controller::create_page_template::create_page_template,
+        crate::controllers::page_templates::controller::update_page_template::update_page_template,
+        crate::controllers::page_templates::controller::delete_page_template::delete_page_template,
+
+        crate::controllers::page_versions::controller::get_page_versions::get_page_versions,
+        crate::controllers::page_versions::controller::get_page_version::get_page_version,
+        crate::controllers::page_versions::controller::get_page_version_diff::get_page_version_diff,
+        crate::controllers::page_versions::controller::restore_page_version::restore_page_version,
+
         crate::controllers::page::controller::get_page_list::get_page_list,
         crate::controllers::page::controller::get_page::get_page,
         crate::controllers::page::controller::create_page::create_page,
+        crate::controllers::page::controller::import_page::import_page,
+        crate::controllers::page_collaboration::controller::collaborate_page::collaborate_page,
         crate::controllers::page::controller::update_page::update_page,
+        crate::controllers::page::controller::move_page::move_page,
+        crate::controllers::page::controller::duplicate_page::duplicate_page,
         crate::controllers::page::controller::delete_page::delete_page,
+        crate::controllers::page::controller::get_page_trash::get_page_trash,
+        crate::controllers::page::controller::restore_page::restore_page,
 
         crate::controllers::page::controller::get_page_details::get_page_details,
+        crate::controllers::page::controller::export_page::export_page,
 
         crate::controllers::page::controller::get_page_access_list::get_page_access_list,
         crate::controllers::page::controller::create_page_access::create_page_access,
@@ -27,8 +86,12 @@ use utils::swagger::SecurityAddon;
         crate::controllers::task::controller::get_tasks_in_page::get_tasks_in_page,
         crate::controllers::task::controller::create_task::create_task,
         crate::controllers::task::controller::create_draft_task::create_draft_task,
+        crate::controllers::task::controller::import_tasks::import_tasks,
         crate::controllers::task::controller::update_task::update_task,
         crate::controllers::task::controller::delete_task::delete_task,
+        crate::controllers::task::controller::get_task_watchers::get_task_watchers,
+        crate::controllers::task::controller::watch_task::watch_task,
+        crate::controllers::task::controller::unwatch_task::unwatch_task,
 
         crate::controllers::user::controller::get_list::get_list,
         crate::controllers::user::controller::get_by_id::get_by_id,
@@ -74,6 +137,7 @@ pub struct ApiDoc;
     paths(
         crate::controllers::assets::controller::create_asset::create_asset,
         crate::controllers::assets::controller::validate_access::validate_access,
+        crate::controllers::page_share_links::controller::get_shared_asset_blob_id::get_shared_asset_blob_id,
     )
 )]
 pub struct InternalApiDoc;
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use axum::extract::FromRef;
 pub use utils::auth_middleware::InternalAuthState;
 
+use crate::services::page_collaboration::CollaborationRooms;
+
 #[derive(Clone)]
 pub struct AppState {
     pub postgres: sqlx::postgres::PgPool,
@@ -11,6 +13,7 @@ pub struct AppState {
     pub authentik_api_url: Arc<String>,
     pub authentik_api_token: Arc<String>,
     pub storage_service_url: Arc<String>,
+    pub collaboration_rooms: CollaborationRooms,
 }
 
 impl FromRef<AppState> for InternalAuthState {
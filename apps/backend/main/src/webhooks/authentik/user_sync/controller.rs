@@ -12,7 +12,7 @@ use crate::{
         assets::{AssetsRepository, CreateAssetDto},
         users::{CreateUserDto, UpdateUserDto, UserRepository},
         workspaces::CreateWorkspaceDto,
-    }, services::workspaces::WorkspaceService, shared::{extractors::json::ValidatedJson, generate_initials_avatar::fetch_png_avatar}
+    }, services::workspaces::WorkspaceService, shared::{extractors::json::ValidatedJson, generate_initials_avatar::fetch_png_avatar, validation::Validate}
 };
 
 #[derive(serde::Deserialize)]
@@ -47,6 +47,8 @@ pub enum UserLifecycleEvents {
     Deleted(UserDeletedData),
 }
 
+impl Validate for UserLifecycleEvents {}
+
 #[utoipa::path(
     post,
     path = "/webhooks/authentik/user_sync",
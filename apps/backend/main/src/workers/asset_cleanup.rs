@@ -2,7 +2,7 @@ use std::sync::Arc;
 use tokio_cron_scheduler::{Job, JobScheduler};
 use tracing::{error, info};
 
-use crate::types::app_state::AppState;
+use crate::{services::assets::AssetsService, types::app_state::AppState};
 
 pub async fn init_asset_cleanup_worker(state: Arc<AppState>, cron_expression: &str) -> Result<(), Box<dyn std::error::Error>> {
     let sched = JobScheduler::new().await?;
@@ -15,6 +15,12 @@ pub async fn init_asset_cleanup_worker(state: Arc<AppState>, cron_expression: &s
                 error!("Error in UserDraft cleanup worker: {}", e);
             }
             info!("UserDraft cleanup worker finished.");
+
+            info!("Running unreferenced assets cleanup worker...");
+            if let Err(e) = run_garbage_collection(&state_clone).await {
+                error!("Error in unreferenced assets cleanup worker: {}", e);
+            }
+            info!("Unreferenced assets cleanup worker finished.");
         })
     })?;
 
@@ -40,3 +46,15 @@ async fn run_cleanup(state: &AppState) -> Result<(), Box<dyn std::error::Error>>
 
     Ok(())
 }
+
+async fn run_garbage_collection(state: &AppState) -> Result<(), Box<dyn std::error::Error>> {
+    let deleted = AssetsService::collect_garbage(&state.postgres)
+        .await
+        .map_err(|e| format!("{:?}", e))?;
+
+    if deleted > 0 {
+        info!("Deleted {} unreferenced assets.", deleted);
+    }
+
+    Ok(())
+}
@@ -1 +1,3 @@
 pub mod asset_cleanup;
+pub mod page_trash;
+pub mod task_reminders;
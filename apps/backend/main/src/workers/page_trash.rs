@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{error, info};
+
+use crate::types::app_state::AppState;
+
+pub async fn init_page_trash_purge_worker(
+    state: Arc<AppState>,
+    cron_expression: &str,
+    retention_days: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sched = JobScheduler::new().await?;
+
+    let job = Job::new_async(cron_expression, move |_uuid, mut _l| {
+        let state_clone = state.clone();
+        Box::pin(async move {
+            info!("Running page trash purge worker...");
+            if let Err(e) = run_purge(&state_clone, retention_days).await {
+                error!("Error in page trash purge worker: {}", e);
+            }
+            info!("Page trash purge worker finished.");
+        })
+    })?;
+
+    sched.add(job).await?;
+    sched.start().await?;
+
+    Ok(())
+}
+
+async fn run_purge(
+    state: &AppState,
+    retention_days: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = state.postgres.acquire().await?;
+
+    // Child pages trashed along with a parent share its `deleted_at`, so whole subtrees expire together.
+    // Tasks, statuses and contents go with the pages through ON DELETE CASCADE.
+    let result = sqlx::query(
+        "DELETE FROM pages WHERE deleted_at IS NOT NULL AND deleted_at < NOW() - make_interval(days => $1)",
+    )
+    .bind(retention_days)
+    .execute(&mut *conn)
+    .await?;
+
+    if result.rows_affected() > 0 {
+        info!("Purged {} pages from trash.", result.rows_affected());
+    }
+
+    Ok(())
+}
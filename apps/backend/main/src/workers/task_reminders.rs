@@ -0,0 +1,102 @@
+use sql::task::model::TaskReminder;
+use std::sync::Arc;
+use tokio_cron_scheduler::{Job, JobScheduler};
+use tracing::{error, info};
+
+use crate::types::app_state::AppState;
+
+/// Channel the reminder events are published on, one JSON payload per recipient.
+pub const TASK_REMINDERS_CHANNEL: &str = "task_reminders";
+
+pub async fn init_task_reminder_worker(
+    state: Arc<AppState>,
+    cron_expression: &str,
+    lead_hours: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let sched = JobScheduler::new().await?;
+
+    let job = Job::new_async(cron_expression, move |_uuid, mut _l| {
+        let state_clone = state.clone();
+        Box::pin(async move {
+            info!("Running task due-date reminder worker...");
+            if let Err(e) = run_reminders(&state_clone, lead_hours).await {
+                error!("Error in task due-date reminder worker: {}", e);
+            }
+            info!("Task due-date reminder worker finished.");
+        })
+    })?;
+
+    sched.add(job).await?;
+    sched.start().await?;
+
+    Ok(())
+}
+
+async fn run_reminders(
+    state: &AppState,
+    lead_hours: i32,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = state.postgres.acquire().await?;
+
+    // Recording and publishing happen in one statement: the unique key on task_reminders
+    // lets only one replica claim a reminder, and NOTIFY is delivered only if the insert commits.
+    let reminders = sqlx::query_as::<_, TaskReminder>(
+        r#"
+        WITH due_tasks AS (
+            SELECT t.id, t.assignee_id, t.due_date,
+                CASE WHEN t.due_date < NOW() THEN 'overdue' ELSE 'due_soon' END AS kind
+            FROM tasks t
+            JOIN pages p ON p.id = t.page_id AND p.deleted_at IS NULL
+            WHERE t.deleted_at IS NULL
+                AND t.is_draft = false
+                AND t.due_date IS NOT NULL
+                AND t.due_date < NOW() + make_interval(hours => $1)
+        ),
+        recipients AS (
+            SELECT id AS task_id, assignee_id AS user_id, kind, due_date
+            FROM due_tasks
+            WHERE assignee_id IS NOT NULL
+            UNION
+            SELECT d.id, w.user_id, d.kind, d.due_date
+            FROM due_tasks d
+            JOIN task_watchers w ON w.task_id = d.id
+        ),
+        sent AS (
+            INSERT INTO task_reminders (task_id, user_id, kind, due_date)
+            SELECT task_id, user_id, kind, due_date FROM recipients
+            ON CONFLICT (task_id, user_id, kind, due_date) DO NOTHING
+            RETURNING *
+        )
+        SELECT sent.*, pg_notify(
+            $2,
+            json_build_object(
+                'taskId', sent.task_id,
+                'userId', sent.user_id,
+                'kind', sent.kind,
+                'dueDate', sent.due_date
+            )::text
+        )::text AS notified
+        FROM sent
+        "#,
+    )
+    .bind(lead_hours)
+    .bind(TASK_REMINDERS_CHANNEL)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for reminder in &reminders {
+        info!(
+            task_id = %reminder.task_id,
+            user_id = %reminder.user_id,
+            kind = ?reminder.kind,
+            due_date = %reminder.due_date,
+            "Task due-date reminder emitted."
+        );
+    }
+
+    if !reminders.is_empty() {
+        info!("Emitted {} task due-date reminders.", reminders.len());
+    }
+
+    Ok(())
+}
@@ -17,7 +17,9 @@ pub fn openapi_json() -> String {
     serde_json::to_string_pretty(&swagger::ApiDoc::openapi()).unwrap()
 }
 
-pub async fn build() -> axum::Router {
+/// Returns the public router and the router for `/internal/*` routes, which is served on a
+/// separate port reachable only by the other services.
+pub async fn build() -> (axum::Router, axum::Router) {
     let cors_origins: Vec<http::HeaderValue> = std::env::var("CORS_ORIGINS")
         .unwrap_or_else(|_| "http://localhost:1346,http://localhost:80".to_string())
         .split(',')
@@ -121,15 +123,20 @@ pub async fn build() -> axum::Router {
         .await
         .expect("Failed to init blob cleanup worker");
 
-    axum::Router::new()
+    let internal = entities::internal::router::init()
+        .with_state(app_state.clone())
+        .layer(tower_http::trace::TraceLayer::new_for_http());
+
+    let public = axum::Router::new()
         .merge(entities::actions::router::init(app_state.clone()))
         .merge(entities::files::router::init(app_state.clone()))
-        .merge(entities::internal::router::init())
         .merge(
             utoipa_swagger_ui::SwaggerUi::new("/api")
                 .url("/api/openapi.json", swagger::ApiDoc::openapi()),
         )
         .with_state(app_state)
         .layer(tower_http::trace::TraceLayer::new_for_http())
-        .layer(cors)
+        .layer(cors);
+
+    (public, internal)
 }
@@ -44,6 +44,16 @@ pub async fn get_file(
 ) -> Result<(StatusCode, HeaderMap, Body), ErrorResponse> {
     let response = validate_access(&state.main_service_url, user_id, asset_id).await?;
 
+    serve_blob(&state, response, query.filename, request.headers()).await
+}
+
+/// Streams the blob behind an access check of the main service, honouring `Range` requests.
+pub(crate) async fn serve_blob(
+    state: &AppState,
+    response: ValidateAccessResponse,
+    filename: Option<String>,
+    request_headers: &HeaderMap,
+) -> Result<(StatusCode, HeaderMap, Body), ErrorResponse> {
     let blob = BlobsRepository::get_one_by_id(&state.postgres, response.blob_id)
         .await
         .map_err(ErrorResponse::from)?;
@@ -70,14 +80,14 @@ pub async fn get_file(
         HeaderValue::from_str(&blob.mime_type).unwrap(),
     );
 
-    if let Some(range_header) = request.headers().get(header::RANGE)
+    if let Some(range_header) = request_headers.get(header::RANGE)
         && let Ok(range_str) = range_header.to_str()
         && let Some(range) = parse_range_header(range_str, file_size)
     {
         return serve_partial_content(blob_path, range, file_size, headers).await;
     }
 
-    let file_name = query.filename.unwrap_or(response.name);
+    let file_name = filename.unwrap_or(response.name);
 
     headers.insert(
         header::CONTENT_LENGTH,
@@ -99,7 +109,7 @@ pub async fn get_file(
 }
 
 #[derive(Deserialize)]
-struct ValidateAccessResponse {
+pub(crate) struct ValidateAccessResponse {
     blob_id: Uuid,
     name: String,
 }
@@ -109,9 +119,19 @@ async fn validate_access(
     user_id: Uuid,
     asset_id: Uuid,
 ) -> Result<ValidateAccessResponse, ErrorResponse> {
-    let resp = reqwest::Client::new()
-        .get(format!("{}/assets/{}/blob-id", main_service_url, asset_id))
-        .header("x-user-id", user_id.to_string())
+    fetch_access(
+        reqwest::Client::new()
+            .get(format!("{}/assets/{}/blob-id", main_service_url, asset_id))
+            .header("x-user-id", user_id.to_string()),
+    )
+    .await
+}
+
+/// Sends an access check to the main service, its errors are passed through as is.
+pub(crate) async fn fetch_access(
+    request: reqwest::RequestBuilder,
+) -> Result<ValidateAccessResponse, ErrorResponse> {
+    let resp = request
         .send()
         .await
         .map_err(|_| ErrorResponse::internal_server_error(None))?;
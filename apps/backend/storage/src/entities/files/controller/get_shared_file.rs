@@ -0,0 +1,55 @@
+use axum::{
+    body::Body,
+    extract::{Path as PathExtract, Query, Request, State},
+    http::{HeaderMap, StatusCode},
+};
+use error_handlers::handlers::ErrorResponse;
+use uuid::Uuid;
+
+use crate::{
+    entities::files::controller::get_file::{QueryParams, fetch_access, serve_blob},
+    types::app_state::AppState,
+};
+
+const SHARE_PASSWORD_HEADER: &str = "x-share-password";
+
+/// Unauthenticated, serves files embedded in a page shared through a public link.
+#[utoipa::path(
+    get,
+    path = "/shared/{token}/files/{asset_id}",
+    responses(
+        (status = 200, description = "File found", body = String),
+        (status = 206, description = "Partial content", body = String),
+        (status = 401, description = "Missing or wrong share password", body = String),
+        (status = 403, description = "File is not part of the shared page", body = String),
+        (status = 404, description = "File or share link not found", body = String),
+    ),
+    params(
+        ("token" = String, Path, description = "Share link token"),
+        ("asset_id" = Uuid, Path, description = "Asset ID"),
+        ("filename" = Option<String>, Query, description = "File name"),
+        ("x-share-password" = Option<String>, Header, description = "Password of a protected link"),
+    ),
+    security(()),
+    tag = "Files",
+    operation_id = "get_shared_file",
+)]
+pub async fn get_shared_file(
+    State(state): State<AppState>,
+    PathExtract((token, asset_id)): PathExtract<(String, Uuid)>,
+    Query(query): Query<QueryParams>,
+    request: Request,
+) -> Result<(StatusCode, HeaderMap, Body), ErrorResponse> {
+    let mut access_request = reqwest::Client::new().get(format!(
+        "{}/shared/{}/assets/{}/blob-id",
+        state.main_service_url, token, asset_id
+    ));
+
+    if let Some(password) = request.headers().get(SHARE_PASSWORD_HEADER) {
+        access_request = access_request.header(SHARE_PASSWORD_HEADER, password);
+    }
+
+    let response = fetch_access(access_request).await?;
+
+    serve_blob(&state, response, query.filename, request.headers()).await
+}
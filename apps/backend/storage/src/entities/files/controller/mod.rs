@@ -1 +1,2 @@
 pub mod get_file;
+pub mod get_shared_file;
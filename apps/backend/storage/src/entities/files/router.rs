@@ -3,7 +3,7 @@ use axum::{Router, routing::get};
 use crate::{entities::files::controller, types::app_state::AppState};
 
 pub fn init(state: AppState) -> Router<AppState> {
-    Router::new()
+    let authorized = Router::new()
         .route(
             "/files/{asset_id}",
             get(controller::get_file::get_file),
@@ -11,5 +11,13 @@ pub fn init(state: AppState) -> Router<AppState> {
         .layer(axum::middleware::from_fn_with_state(
             state,
             utils::auth_middleware::auth_guard,
-        ))
+        ));
+
+    // The share token authenticates the request, see `get_shared_file`
+    let public = Router::new().route(
+        "/shared/{token}/files/{asset_id}",
+        get(controller::get_shared_file::get_shared_file),
+    );
+
+    Router::new().merge(authorized).merge(public)
 }
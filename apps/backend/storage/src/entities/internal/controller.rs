@@ -1,11 +1,23 @@
-use axum::{Json, extract::Multipart, extract::State, http::StatusCode};
-use error_handlers::handlers::ErrorResponse;
-use sql::{blobs::model::Blob, shared::traits::PostgresqlRepositoryCreate};
+use axum::{
+    Json,
+    body::Body,
+    extract::{Multipart, Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+};
+use error_handlers::{codes, handlers::ErrorResponse};
+use sql::{
+    blobs::model::Blob,
+    shared::traits::{PostgresqlRepositoryCreate, PostgresqlRepositoryGetOneById},
+};
 use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
 
 use crate::{
     db::blobs::{BlobsRepository, CreateBlobDto},
-    entities::actions::{service::ActionsService, shared::build_path_to_assets_file},
+    entities::{
+        actions::{service::ActionsService, shared::build_path_to_assets_file},
+        internal::dto::{BLOB_DOWNLOAD_AUDIENCE, BlobDownloadToken},
+    },
     types::app_state::AppState,
 };
 
@@ -101,3 +113,76 @@ pub async fn upload(
 
     Ok((StatusCode::CREATED, Json(blob)))
 }
+
+#[utoipa::path(
+    get,
+    path = "/internal/blobs/{blob_id}",
+    params(
+        ("blob_id" = Uuid, Path, description = "Blob ID"),
+    ),
+    responses(
+        (status = 200, description = "Blob content", body = String),
+        (status = 401, description = "Missing or invalid download token", body = ErrorResponse),
+        (status = 404, description = "Blob not found", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse),
+    ),
+    tags = ["Internal"],
+)]
+pub async fn download(
+    State(state): State<AppState>,
+    Path(blob_id): Path<Uuid>,
+    request_headers: HeaderMap,
+) -> Result<(StatusCode, HeaderMap, Body), ErrorResponse> {
+    let token = request_headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or_else(|| {
+            ErrorResponse::unauthorized(codes::UnauthorizedErrorCode::Unauthorized, None)
+        })?;
+
+    let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::HS256);
+    validation.set_audience(&[BLOB_DOWNLOAD_AUDIENCE]);
+
+    let token = jsonwebtoken::decode::<BlobDownloadToken>(
+        token,
+        &jsonwebtoken::DecodingKey::from_secret(state.jwt_secret.as_bytes()),
+        &validation,
+    )
+    .map_err(|e| {
+        ErrorResponse::unauthorized(
+            codes::UnauthorizedErrorCode::InvalidToken,
+            Some(e.to_string()),
+        )
+    })?;
+
+    if token.claims.sub != blob_id {
+        return Err(ErrorResponse::unauthorized(
+            codes::UnauthorizedErrorCode::InvalidToken,
+            Some("Blob id does not match".to_string()),
+        ));
+    }
+
+    let blob = BlobsRepository::get_one_by_id(&state.postgres, blob_id)
+        .await
+        .map_err(ErrorResponse::from)?;
+
+    let file = tokio::fs::File::open(&blob.path)
+        .await
+        .map_err(|_| ErrorResponse::not_found(codes::NotFoundErrorCode::NotFound, None, None))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_str(&blob.mime_type)
+            .unwrap_or(HeaderValue::from_static("application/octet-stream")),
+    );
+    headers.insert(
+        header::CONTENT_LENGTH,
+        HeaderValue::from_str(&blob.size.to_string()).unwrap(),
+    );
+
+    let body = Body::from_stream(tokio_util::io::ReaderStream::new(file));
+
+    Ok((StatusCode::OK, headers, body))
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+/// Audience of blob download tokens, so that other tokens signed with the shared secret are
+/// not accepted as one.
+pub const BLOB_DOWNLOAD_AUDIENCE: &str = "storage:blob-download";
+
+/// Signed by the main service with the shared JWT secret, `sub` is the blob being downloaded.
+#[derive(Deserialize)]
+pub struct BlobDownloadToken {
+    pub sub: Uuid,
+    #[allow(dead_code)]
+    pub aud: String,
+    #[allow(dead_code)]
+    pub exp: usize,
+}
@@ -1,4 +1,8 @@
-use axum::{Router, extract::DefaultBodyLimit, routing::post};
+use axum::{
+    Router,
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+};
 
 use crate::types::app_state::AppState;
 
@@ -9,4 +13,5 @@ pub fn init() -> Router<AppState> {
             post(super::controller::upload)
                 .layer(DefaultBodyLimit::max(20 * 1024 * 1024)),
         )
+        .route("/internal/blobs/{blob_id}", get(super::controller::download))
 }
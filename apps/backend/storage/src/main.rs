@@ -18,15 +18,23 @@ async fn main() {
     let port = std::env::var("SELF_PORT").unwrap_or("3000".to_string());
     let addr = format!("0.0.0.0:{}", port);
 
-    let app = storage_app::build().await;
+    let internal_port = std::env::var("INTERNAL_PORT").unwrap_or("3001".to_string());
+    let internal_addr = format!("0.0.0.0:{}", internal_port);
+
+    let (app, internal_app) = storage_app::build().await;
 
     println!("Listening on {}", addr);
     tracing::info!("Listening on {}", addr);
+    tracing::info!("Internal routes listening on {}", internal_addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
+    let internal_listener = tokio::net::TcpListener::bind(internal_addr).await.unwrap();
+
+    let (public, internal) = tokio::join!(
+        axum::serve(listener, app).with_graceful_shutdown(shutdown_signal()),
+        axum::serve(internal_listener, internal_app).with_graceful_shutdown(shutdown_signal()),
+    );
 
-    axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
-        .await
-        .expect("Failed to start server");
+    public.expect("Failed to start server");
+    internal.expect("Failed to start internal server");
 }
@@ -4,6 +4,7 @@ use utils::swagger::SecurityAddon;
 #[openapi(
     paths(
         crate::entities::files::controller::get_file::get_file,
+        crate::entities::files::controller::get_shared_file::get_shared_file,
 
         crate::entities::actions::controller::upload_init::upload_init,
         crate::entities::actions::controller::upload_verify::upload_verify,
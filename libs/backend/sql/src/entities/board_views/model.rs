@@ -0,0 +1,91 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, types::Json};
+use uuid::Uuid;
+
+use crate::shared::types::SortOrder;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DueDateFilter {
+    Overdue,
+    Today,
+    ThisWeek,
+    NoDueDate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardViewFilters {
+    /// Resolved against the requesting user, so a shared "My tasks" view works for everyone.
+    #[serde(default)]
+    pub assigned_to_me: bool,
+    pub assignee_ids: Option<Vec<Uuid>>,
+    pub reporter_ids: Option<Vec<Uuid>>,
+    pub status_ids: Option<Vec<Uuid>>,
+    pub due_date: Option<DueDateFilter>,
+    pub search: Option<String>,
+    #[serde(default)]
+    pub hide_drafts: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum BoardViewSortBy {
+    #[default]
+    Position,
+    DueDate,
+    Title,
+    CreatedAt,
+    UpdatedAt,
+}
+
+impl std::fmt::Display for BoardViewSortBy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BoardViewSortBy::Position => write!(f, "position"),
+            BoardViewSortBy::DueDate => write!(f, "due_date"),
+            BoardViewSortBy::Title => write!(f, "title"),
+            BoardViewSortBy::CreatedAt => write!(f, "created_at"),
+            BoardViewSortBy::UpdatedAt => write!(f, "updated_at"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardViewSort {
+    #[serde(default)]
+    pub by: BoardViewSortBy,
+    #[serde(default)]
+    pub order: SortOrder,
+}
+
+/// Grouping of the board columns, only stored with the view.
+/// Tasks are returned filtered and sorted but ungrouped, grouping happens on the client.
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Default, PartialEq, utoipa::ToSchema, sqlx::Type,
+)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum BoardViewGroupBy {
+    #[default]
+    Status,
+    Assignee,
+    None,
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct BoardView {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub owner_id: Uuid,
+    pub name: String,
+    pub is_shared: bool,
+    pub filters: Json<BoardViewFilters>,
+    pub sort: Json<BoardViewSort>,
+    pub group_by: BoardViewGroupBy,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, utoipa::ToSchema, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum CalendarFeedScope {
+    /// Tasks assigned to or watched by the feed owner
+    User,
+    Workspace,
+    Board,
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct CalendarFeed {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub token_hash: String,
+    pub scope: CalendarFeedScope,
+    pub workspace_id: Option<Uuid>,
+    pub page_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+/// Task with a due date as published in a calendar feed.
+#[derive(Debug, FromRow, Clone)]
+pub struct CalendarTask {
+    pub id: Uuid,
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub page_title: String,
+    pub status_title: String,
+}
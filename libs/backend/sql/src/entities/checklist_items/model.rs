@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Clone)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub task_id: Uuid,
+    pub text: String,
+    pub is_done: bool,
+    pub assignee_id: Option<Uuid>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct ChecklistProgress {
+    pub task_id: Uuid,
+    pub done: i64,
+    pub total: i64,
+}
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Page or task description that links to a target, with the titles to display it.
+#[derive(Debug, FromRow, Clone)]
+pub struct Backlink {
+    pub page_id: Uuid,
+    pub page_title: String,
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
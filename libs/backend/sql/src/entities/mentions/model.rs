@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::shared::tiptap_content::MentionKind;
+
+#[derive(Debug, FromRow, Clone)]
+pub struct Mention {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub task_id: Option<Uuid>,
+    pub target_kind: MentionKind,
+    pub target_id: Uuid,
+    pub mentioned_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Mention joined with the titles of the page and task it was made in.
+#[derive(Debug, FromRow, Clone)]
+pub struct MentionSource {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub page_title: String,
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    pub mentioned_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
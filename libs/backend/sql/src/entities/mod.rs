@@ -1,7 +1,19 @@
 pub mod assets;
 pub mod blobs;
 pub mod board_statuses;
+pub mod board_views;
+pub mod calendar_feeds;
+pub mod checklist_items;
+pub mod links;
+pub mod mentions;
 pub mod page;
+pub mod page_comments;
+pub mod page_share_links;
+pub mod page_templates;
+pub mod page_versions;
+pub mod search;
 pub mod task;
+pub mod task_chat_messages;
+pub mod text_page_updates;
 pub mod user;
 pub mod workspace;
@@ -76,6 +76,8 @@ pub struct Page {
     pub owner_id: Uuid,
     pub workspace_id: Uuid,
     pub parent_page_id: Option<Uuid>,
+    /// Order among pages sharing the same parent, starting at 1.
+    pub position: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
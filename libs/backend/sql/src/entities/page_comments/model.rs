@@ -0,0 +1,18 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, FromRow, Clone)]
+pub struct PageComment {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub author_id: Uuid,
+    /// `None` for the first comment of a thread.
+    pub parent_comment_id: Option<Uuid>,
+    pub text: String,
+    pub quote: Option<String>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolved_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
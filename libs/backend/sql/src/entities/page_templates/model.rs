@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, types::Json};
+use uuid::Uuid;
+
+use crate::{page::model::PageType, shared::tiptap_content::TipTapContent};
+
+#[derive(Debug, Serialize, Deserialize, Clone, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateBoardStatus {
+    /// Status of new tasks, the first status is used when none is marked
+    #[serde(default)]
+    pub initial: bool,
+    pub localizations: HashMap<String, String>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct PageTemplate {
+    pub id: Uuid,
+    /// `None` for built-in templates, which are available in every workspace.
+    pub workspace_id: Option<Uuid>,
+    pub created_by: Option<Uuid>,
+    pub name: String,
+    pub r#type: PageType,
+    pub title_pattern: String,
+    pub content: Json<Option<TipTapContent>>,
+    pub statuses: Json<Vec<TemplateBoardStatus>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::shared::tiptap_content::TipTapContent;
+
+#[derive(Debug, FromRow, Clone)]
+pub struct PageVersion {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub content: sqlx::types::Json<Option<TipTapContent>>,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Version without its content, for listings.
+#[derive(Debug, FromRow, Clone)]
+pub struct PageVersionSummary {
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, utoipa::ToSchema, sqlx::Type)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum SearchHitKind {
+    Page,
+    Task,
+    Message,
+}
+
+impl SearchHitKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchHitKind::Page => "page",
+            SearchHitKind::Task => "task",
+            SearchHitKind::Message => "message",
+        }
+    }
+}
+
+impl std::str::FromStr for SearchHitKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "page" => Ok(SearchHitKind::Page),
+            "task" => Ok(SearchHitKind::Task),
+            "message" => Ok(SearchHitKind::Message),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct SearchHit {
+    pub kind: SearchHitKind,
+    /// Id of the page, task or chat message, depending on `kind`.
+    pub id: Uuid,
+    pub page_id: Uuid,
+    pub page_title: String,
+    pub task_id: Option<Uuid>,
+    pub task_title: Option<String>,
+    /// HTML-escaped fragments with the matches wrapped in `<mark>`.
+    pub headline: String,
+    pub rank: f32,
+}
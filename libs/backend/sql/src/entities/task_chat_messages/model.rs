@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Written by the socket service, timestamps are stored without a time zone.
+#[derive(Debug, FromRow, Clone)]
+pub struct TaskChatMessage {
+    pub id: Uuid,
+    pub text: String,
+    pub user_id: Uuid,
+    pub task_id: Uuid,
+    pub pinned_by: Option<Uuid>,
+    pub reply_to: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, FromRow, Clone)]
+pub struct TaskChatMessageCount {
+    pub task_id: Uuid,
+    pub total: i64,
+    pub pinned: i64,
+}
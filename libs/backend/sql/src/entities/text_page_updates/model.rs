@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Binary Yjs update (v1 encoding) of a collaboratively edited text page.
+#[derive(Debug, FromRow, Clone)]
+pub struct TextPageUpdate {
+    pub id: i64,
+    pub page_id: Uuid,
+    pub data: Vec<u8>,
+    pub created_at: DateTime<Utc>,
+}
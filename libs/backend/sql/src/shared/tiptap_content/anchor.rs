@@ -0,0 +1,208 @@
+//! Comment anchors: `comment` marks over a range of ProseMirror positions.
+
+use serde_json::{Value, json};
+
+use super::validate::is_leaf;
+
+fn node_type(node: &Value) -> &str {
+    node.get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+}
+
+fn text(node: &Value) -> &str {
+    node.get("text")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+}
+
+/// Size of the node in ProseMirror positions: text counts its UTF-16 code units, leaves count
+/// one, other nodes count their content plus their opening and closing tokens.
+fn node_size(node: &Value) -> usize {
+    match node_type(node) {
+        "text" => text(node).encode_utf16().count(),
+        node_type if is_leaf(node_type) => 1,
+        _ => 2 + content(node).iter().map(node_size).sum::<usize>(),
+    }
+}
+
+fn content(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+/// Byte index of the UTF-16 `offset` in `text`, offsets inside a character round up.
+fn byte_index(text: &str, offset: usize) -> usize {
+    let mut units = 0;
+
+    for (index, char) in text.char_indices() {
+        if units >= offset {
+            return index;
+        }
+        units += char.len_utf16();
+    }
+
+    text.len()
+}
+
+pub(super) struct Anchor {
+    from: usize,
+    to: usize,
+    mark: Value,
+    /// Anchored text of each block
+    pub quote: Vec<String>,
+}
+
+impl Anchor {
+    pub fn new(comment_id: uuid::Uuid, from: usize, to: usize) -> Self {
+        Self {
+            from,
+            to,
+            mark: json!({ "type": "comment", "attrs": { "commentId": comment_id } }),
+            quote: Vec::new(),
+        }
+    }
+
+    /// Marks the text of `nodes` inside the range, `start` being the position of the first
+    /// node. Text nodes crossing a bound of the range are split.
+    pub fn apply(&mut self, nodes: &mut Vec<Value>, start: usize) {
+        let mut position = start;
+        let mut quote = String::new();
+        let mut marked = Vec::with_capacity(nodes.len());
+
+        for mut node in nodes.drain(..) {
+            let size = node_size(&node);
+            let end = position + size;
+
+            if end <= self.from || position >= self.to {
+                marked.push(node);
+            } else if node_type(&node) == "text" {
+                let text = text(&node).to_string();
+                let from = byte_index(&text, self.from.saturating_sub(position));
+                let to = byte_index(&text, self.to - position);
+
+                for (range, in_range) in
+                    [(0..from, false), (from..to, true), (to..text.len(), false)]
+                {
+                    if range.is_empty() {
+                        continue;
+                    }
+
+                    let mut part = node.clone();
+                    part["text"] = Value::String(text[range.clone()].to_string());
+                    if in_range {
+                        quote.push_str(&text[range]);
+                        self.add_mark(&mut part);
+                    }
+                    marked.push(part);
+                }
+            } else if !is_leaf(node_type(&node)) && node_type(&node) != "codeBlock" {
+                if let Some(children) = node.get_mut("content").and_then(|c| c.as_array_mut()) {
+                    self.apply(children, position + 1);
+                }
+                marked.push(node);
+            } else {
+                marked.push(node);
+            }
+
+            position = end;
+        }
+
+        *nodes = marked;
+
+        if !quote.is_empty() {
+            self.quote.push(quote);
+        }
+    }
+
+    fn add_mark(&self, node: &mut Value) {
+        let Some(object) = node.as_object_mut() else {
+            return;
+        };
+
+        let marks = object
+            .entry("marks")
+            .or_insert_with(|| Value::Array(Vec::new()));
+
+        if let Some(marks) = marks.as_array_mut()
+            && !marks.contains(&self.mark)
+        {
+            marks.push(self.mark.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paragraph(content: Value) -> Value {
+        json!({ "type": "paragraph", "content": content })
+    }
+
+    #[test]
+    fn test_node_size() {
+        let nodes = json!([
+            paragraph(json!([{ "type": "text", "text": "a😀" }, { "type": "hardBreak" }])),
+            { "type": "horizontalRule" },
+            { "type": "paragraph" },
+        ]);
+
+        let sizes = nodes
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(node_size)
+            .collect::<Vec<_>>();
+
+        assert_eq!(sizes, vec![6, 1, 2]);
+    }
+
+    #[test]
+    fn test_apply_splits_text() {
+        let id = uuid::Uuid::nil();
+        let mut nodes = vec![
+            paragraph(json!([
+                { "type": "text", "text": "hello ", "marks": [{ "type": "bold" }] },
+                { "type": "text", "text": "world" },
+            ])),
+            json!({ "type": "codeBlock", "content": [{ "type": "text", "text": "code" }] }),
+            paragraph(json!([{ "type": "text", "text": "again" }])),
+        ];
+
+        // From inside "hello" to inside "again", across the code block
+        let mut anchor = Anchor::new(id, 3, 22);
+        anchor.apply(&mut nodes, 0);
+
+        let comment = json!({ "type": "comment", "attrs": { "commentId": id } });
+        assert_eq!(
+            nodes,
+            vec![
+                paragraph(json!([
+                    { "type": "text", "text": "he", "marks": [{ "type": "bold" }] },
+                    { "type": "text", "text": "llo ", "marks": [{ "type": "bold" }, comment] },
+                    { "type": "text", "text": "world", "marks": [comment] },
+                ])),
+                json!({ "type": "codeBlock", "content": [{ "type": "text", "text": "code" }] }),
+                paragraph(json!([
+                    { "type": "text", "text": "ag", "marks": [comment] },
+                    { "type": "text", "text": "ain" },
+                ])),
+            ]
+        );
+        assert_eq!(anchor.quote, vec!["llo world", "ag"]);
+    }
+
+    #[test]
+    fn test_apply_outside_of_text() {
+        let mut nodes = vec![paragraph(json!([{ "type": "hardBreak" }]))];
+
+        let mut anchor = Anchor::new(uuid::Uuid::nil(), 0, 3);
+        anchor.apply(&mut nodes, 0);
+
+        assert!(anchor.quote.is_empty());
+        assert_eq!(nodes, vec![paragraph(json!([{ "type": "hardBreak" }]))]);
+    }
+}
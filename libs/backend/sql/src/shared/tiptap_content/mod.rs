@@ -0,0 +1,634 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+mod anchor;
+mod parse;
+mod render;
+mod validate;
+mod visit;
+
+pub use render::escape_html;
+pub use validate::{SchemaError, SchemaViolation};
+pub use visit::Visit;
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FileAttributes {
+    pub id: Option<Uuid>,
+
+    pub src: Option<String>,
+    pub href: Option<String>,
+
+    pub alt: Option<String>,
+    pub title: Option<String>,
+
+    pub height: Option<String>,
+    pub width: Option<String>,
+
+    pub size: Option<u64>,
+
+    pub r#type: Option<String>,
+}
+
+#[derive(
+    Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash, Default, ToSchema, sqlx::Type,
+)]
+#[serde(rename_all = "camelCase")]
+#[sqlx(type_name = "text", rename_all = "snake_case")]
+pub enum MentionKind {
+    #[default]
+    User,
+    Task,
+    Page,
+}
+
+impl MentionKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MentionKind::User => "user",
+            MentionKind::Task => "task",
+            MentionKind::Page => "page",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionAttributes {
+    pub id: Uuid,
+    /// Mentions created before kinds existed only referenced users.
+    #[serde(default)]
+    pub kind: MentionKind,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase", tag = "type")]
+pub enum Node {
+    File { attrs: FileAttributes },
+    Mention { attrs: MentionAttributes },
+}
+
+impl Node {
+    /// Parses a file or mention node, other nodes return `None`.
+    fn from_value(node: &serde_json::Value) -> Option<Self> {
+        match node.get("type").and_then(|t| t.as_str()) {
+            Some("file" | "mention") => serde_json::from_value(node.clone()).ok(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(untagged, rename_all = "camelCase")]
+pub enum NodeWrapper {
+    Typed(Node),
+
+    Generic(serde_json::Value),
+}
+
+impl NodeWrapper {
+    fn from_value(node: serde_json::Value) -> Self {
+        match Node::from_value(&node) {
+            Some(node) => NodeWrapper::Typed(node),
+            None => NodeWrapper::Generic(node),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub enum DocType {
+    #[serde(rename = "doc")]
+    Doc,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TipTapContent {
+    pub r#type: DocType,
+    pub content: Option<Vec<NodeWrapper>>,
+}
+
+impl TipTapContent {
+    /// Builds a document with one paragraph per line of `text`.
+    pub fn from_plain_text(text: &str) -> Self {
+        let paragraphs = text
+            .lines()
+            .map(|line| {
+                let line = line.trim_end();
+
+                if line.is_empty() {
+                    NodeWrapper::Generic(serde_json::json!({ "type": "paragraph" }))
+                } else {
+                    NodeWrapper::Generic(serde_json::json!({
+                        "type": "paragraph",
+                        "content": [{ "type": "text", "text": line }],
+                    }))
+                }
+            })
+            .collect();
+
+        Self {
+            r#type: DocType::Doc,
+            content: Some(paragraphs),
+        }
+    }
+
+    /// Parses CommonMark with GFM tables, task lists and strikethrough.
+    /// `resolve_file` turns an image source into the attributes of a file node,
+    /// images it does not resolve are kept as links.
+    pub fn from_markdown<F>(markdown: &str, mut resolve_file: F) -> Self
+    where
+        F: FnMut(&str) -> Option<FileAttributes>,
+    {
+        let mut content = parse::parse(markdown, &mut resolve_file)
+            .into_iter()
+            .map(NodeWrapper::from_value)
+            .collect::<Vec<_>>();
+
+        // A document needs at least one block
+        if content.is_empty() {
+            content.push(NodeWrapper::Generic(
+                serde_json::json!({ "type": "paragraph" }),
+            ));
+        }
+
+        Self {
+            r#type: DocType::Doc,
+            content: Some(content),
+        }
+    }
+
+    /// Sources of the images of a Markdown document, in order of appearance.
+    pub fn markdown_image_sources(markdown: &str) -> Vec<String> {
+        parse::image_sources(markdown)
+    }
+
+    /// Checks the document against the schema of the editor: known node and mark types,
+    /// allowed children, attribute types, nesting depth, size and link URL schemes.
+    pub fn validate(&self) -> Result<(), Vec<SchemaError>> {
+        let document = serde_json::to_value(self).map_err(|_| {
+            vec![SchemaError {
+                path: String::new(),
+                violation: SchemaViolation::InvalidType,
+            }]
+        })?;
+        let size = document.to_string().len();
+
+        let errors = validate::validate(&document, size);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Text of the document with block nodes separated by new lines, formatting and files are dropped.
+    /// Mentions are kept inline as `@label`.
+    pub fn to_plain_text(&self) -> String {
+        fn collect(node: &serde_json::Value, lines: &mut Vec<String>) {
+            let node_type = node
+                .get("type")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default();
+
+            let inline = match node_type {
+                "text" => node
+                    .get("text")
+                    .and_then(|t| t.as_str())
+                    .map(str::to_string),
+                "mention" => node
+                    .pointer("/attrs/label")
+                    .and_then(|l| l.as_str())
+                    .map(|label| format!("@{label}")),
+                "hardBreak" => {
+                    lines.push(String::new());
+                    return;
+                }
+                _ => None,
+            };
+
+            if let Some(inline) = inline {
+                match lines.last_mut() {
+                    Some(line) => line.push_str(&inline),
+                    None => lines.push(inline),
+                }
+                return;
+            }
+
+            // Only blocks start a new line, other inline nodes have no text
+            if validate::is_block(node_type) {
+                lines.push(String::new());
+            }
+
+            if let Some(children) = node.get("content").and_then(|c| c.as_array()) {
+                for child in children {
+                    collect(child, lines);
+                }
+            }
+        }
+
+        let mut lines = Vec::new();
+
+        for node_wrapper in self.content.iter().flatten() {
+            if let NodeWrapper::Generic(node) = node_wrapper {
+                collect(node, &mut lines);
+            }
+        }
+
+        lines
+            .iter()
+            .map(|l| l.trim_end())
+            .filter(|l| !l.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn nodes(&self) -> Vec<serde_json::Value> {
+        self.content
+            .iter()
+            .flatten()
+            .filter_map(|node_wrapper| serde_json::to_value(node_wrapper).ok())
+            .collect()
+    }
+
+    /// Renders the document as GitHub flavored Markdown.
+    /// `file_url` gives the link of a file node, files without one are rendered as their name.
+    pub fn to_markdown<F>(&self, file_url: F) -> String
+    where
+        F: Fn(&FileAttributes) -> Option<String>,
+    {
+        render::MarkdownRenderer {
+            file_url: &file_url,
+        }
+        .blocks(&self.nodes())
+    }
+
+    /// Renders the document as an HTML fragment, see `to_markdown` for `file_url`.
+    pub fn to_html<F>(&self, file_url: F) -> String
+    where
+        F: Fn(&FileAttributes) -> Option<String>,
+    {
+        render::HtmlRenderer {
+            file_url: &file_url,
+        }
+        .blocks(&self.nodes())
+    }
+
+    /// Calls `visit` on every node of the document, depth first.
+    pub fn visit<F>(&self, mut visit: F)
+    where
+        F: FnMut(&serde_json::Value) -> Visit,
+    {
+        for node in self.nodes() {
+            if !visit::walk(&node, &mut visit) {
+                return;
+            }
+        }
+    }
+
+    /// Calls `visit` on every node of the document, depth first. Nodes may be changed in place.
+    pub fn visit_mut<F>(&mut self, mut visit: F)
+    where
+        F: FnMut(&mut serde_json::Value) -> Visit,
+    {
+        let Some(content) = &mut self.content else {
+            return;
+        };
+
+        for node_wrapper in content.iter_mut() {
+            let mut node = match node_wrapper {
+                NodeWrapper::Generic(node) => std::mem::take(node),
+                NodeWrapper::Typed(node) => match serde_json::to_value(&*node) {
+                    Ok(node) => node,
+                    Err(_) => continue,
+                },
+            };
+
+            let proceed = visit::walk_mut(&mut node, &mut visit);
+            *node_wrapper = NodeWrapper::from_value(node);
+
+            if !proceed {
+                return;
+            }
+        }
+    }
+
+    /// First node, depth first, for which `predicate` returns true.
+    pub fn find_node<F>(&self, mut predicate: F) -> Option<serde_json::Value>
+    where
+        F: FnMut(&serde_json::Value) -> bool,
+    {
+        let mut found = None;
+
+        self.visit(|node| {
+            if predicate(node) {
+                found = Some(node.clone());
+                Visit::Stop
+            } else {
+                Visit::Continue
+            }
+        });
+
+        found
+    }
+
+    /// Values `f` returns for the nodes of the document, depth first.
+    pub fn collect_nodes<T, F>(&self, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&serde_json::Value) -> Option<T>,
+    {
+        let mut collected = Vec::new();
+
+        self.visit(|node| {
+            collected.extend(f(node));
+            Visit::Continue
+        });
+
+        collected
+    }
+
+    /// Removes the nodes, at any depth, for which `keep` returns false.
+    pub fn retain_nodes<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&serde_json::Value) -> bool,
+    {
+        let Some(content) = &mut self.content else {
+            return;
+        };
+
+        let mut nodes = content
+            .drain(..)
+            .filter_map(|node_wrapper| serde_json::to_value(node_wrapper).ok())
+            .filter(|node| keep(node))
+            .collect::<Vec<_>>();
+
+        for node in nodes.iter_mut() {
+            visit::retain(node, &mut keep);
+        }
+
+        content.extend(nodes.into_iter().map(NodeWrapper::from_value));
+    }
+
+    /// Distinct mentions of the document, in order of first appearance.
+    pub fn mentions(&self) -> Vec<MentionAttributes> {
+        let mut mentions = self.collect_nodes(|node| match Node::from_value(node) {
+            Some(Node::Mention { attrs }) => Some(attrs),
+            _ => None,
+        });
+
+        let mut seen = std::collections::HashSet::new();
+        mentions.retain(|m| seen.insert((m.kind, m.id)));
+
+        mentions
+    }
+
+    /// Distinct pages and tasks the document refers to, through page/task mentions
+    /// or links to their routes (`/pages/{id}`, `/tasks/{id}` or a `taskId` query parameter).
+    pub fn references(&self) -> Vec<(MentionKind, Uuid)> {
+        let links = self.collect_nodes(|node| {
+            let marks = node.get("marks").and_then(|m| m.as_array())?;
+
+            let references = marks
+                .iter()
+                .filter(|mark| mark.get("type").and_then(|t| t.as_str()) == Some("link"))
+                .filter_map(|mark| mark.pointer("/attrs/href").and_then(|h| h.as_str()))
+                .filter_map(parse_internal_href)
+                .collect::<Vec<_>>();
+
+            Some(references)
+        });
+
+        let mut references = self
+            .mentions()
+            .into_iter()
+            .filter(|m| m.kind != MentionKind::User)
+            .map(|m| (m.kind, m.id))
+            .chain(links.into_iter().flatten())
+            .collect::<Vec<_>>();
+
+        let mut seen = std::collections::HashSet::new();
+        references.retain(|r| seen.insert(*r));
+
+        references
+    }
+
+    /// Distinct comment threads anchored in the document through `comment` marks,
+    /// in order of first appearance.
+    pub fn comment_ids(&self) -> Vec<Uuid> {
+        let mut ids = self
+            .collect_nodes(|node| {
+                let marks = node.get("marks").and_then(|m| m.as_array())?;
+
+                let ids = marks
+                    .iter()
+                    .filter(|mark| mark.get("type").and_then(|t| t.as_str()) == Some("comment"))
+                    .filter_map(|mark| mark.pointer("/attrs/commentId").and_then(|id| id.as_str()))
+                    .filter_map(|id| Uuid::parse_str(id).ok())
+                    .collect::<Vec<_>>();
+
+                Some(ids)
+            })
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let mut seen = std::collections::HashSet::new();
+        ids.retain(|id| seen.insert(*id));
+
+        ids
+    }
+
+    /// Anchors the thread `comment_id` on the text between the ProseMirror positions `from`
+    /// and `to` with `comment` marks, code blocks are left out. Returns the anchored text,
+    /// one line per block, or `None` without changing the document when the range holds no text.
+    pub fn add_comment_mark(&mut self, comment_id: Uuid, from: usize, to: usize) -> Option<String> {
+        let mut nodes = self.nodes();
+
+        let mut anchor = anchor::Anchor::new(comment_id, from, to);
+        anchor.apply(&mut nodes, 0);
+
+        if anchor.quote.is_empty() {
+            return None;
+        }
+
+        self.content = Some(nodes.into_iter().map(NodeWrapper::from_value).collect());
+
+        Some(anchor.quote.join("\n"))
+    }
+
+    /// Asset ids of the file nodes of the document.
+    pub fn file_asset_ids(&self) -> Vec<Uuid> {
+        self.collect_nodes(|node| match Node::from_value(node) {
+            Some(Node::File { attrs }) => attrs.id,
+            _ => None,
+        })
+    }
+
+    /// Removes the file nodes for which `keep` returns false, other nodes are left untouched.
+    pub fn retain_file_nodes<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&FileAttributes) -> bool,
+    {
+        self.retain_nodes(|node| match Node::from_value(node) {
+            Some(Node::File { attrs }) => keep(&attrs),
+            _ => true,
+        });
+    }
+
+    /// Applies `update` to the attributes of every file node.
+    pub fn update_file_nodes<F>(&mut self, mut update: F)
+    where
+        F: FnMut(&mut FileAttributes),
+    {
+        self.visit_mut(|node| {
+            if let Some(Node::File { mut attrs }) = Node::from_value(node) {
+                update(&mut attrs);
+
+                if let Ok(attrs) = serde_json::to_value(attrs) {
+                    node["attrs"] = attrs;
+                }
+            }
+
+            Visit::Continue
+        });
+    }
+
+    /// Finds a file node by its id and applies the hydration function to it.
+    /// Returns true if the node was found and updated.
+    pub fn hydrate_file_node<F>(&mut self, asset_id: Uuid, hydrate_fn: F) -> bool
+    where
+        F: FnOnce(&mut FileAttributes),
+    {
+        let mut hydrate_fn = Some(hydrate_fn);
+
+        self.visit_mut(|node| {
+            let Some(Node::File { mut attrs }) = Node::from_value(node) else {
+                return Visit::Continue;
+            };
+            if attrs.id != Some(asset_id) {
+                return Visit::Continue;
+            }
+
+            if let Some(hydrate_fn) = hydrate_fn.take() {
+                hydrate_fn(&mut attrs);
+            }
+            if let Ok(attrs) = serde_json::to_value(attrs) {
+                node["attrs"] = attrs;
+            }
+
+            Visit::Stop
+        });
+
+        hydrate_fn.is_none()
+    }
+}
+
+/// Resolves an app link to the page or task it opens, other links return `None`.
+fn parse_internal_href(href: &str) -> Option<(MentionKind, Uuid)> {
+    let (path, query) = href.split_once('?').unwrap_or((href, ""));
+
+    // Board pages open tasks in a drawer via `?taskId=`
+    for pair in query.split(['&', '#']) {
+        if let Some(id) = pair.strip_prefix("taskId=")
+            && let Ok(id) = Uuid::parse_str(id)
+        {
+            return Some((MentionKind::Task, id));
+        }
+    }
+
+    let segments = path.split(['/', '#']).collect::<Vec<_>>();
+
+    segments.windows(2).find_map(|pair| {
+        let kind = match pair[0] {
+            "pages" => MentionKind::Page,
+            "tasks" => MentionKind::Task,
+            _ => return None,
+        };
+
+        Uuid::parse_str(pair[1]).ok().map(|id| (kind, id))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ID: &str = "0b6f3c1e-8d5a-4a4e-9d6b-2f1c3e5a7b9d";
+
+    fn id() -> Uuid {
+        Uuid::parse_str(ID).unwrap()
+    }
+
+    #[test]
+    fn test_to_plain_text() {
+        let document: TipTapContent = serde_json::from_value(serde_json::json!({
+            "type": "doc",
+            "content": [
+                { "type": "heading", "attrs": { "level": 1 }, "content": [{ "type": "text", "text": "Title" }] },
+                { "type": "paragraph", "content": [
+                    { "type": "text", "text": "Ask " },
+                    { "type": "mention", "attrs": { "id": ID, "label": "Ann" } },
+                    { "type": "text", "text": " about " },
+                    { "type": "mention", "attrs": { "id": ID, "kind": "page" } },
+                    { "type": "text", "text": "it", "marks": [{ "type": "bold" }] },
+                    { "type": "hardBreak" },
+                    { "type": "text", "text": "next line" },
+                ] },
+                { "type": "paragraph" },
+                { "type": "file", "attrs": { "id": ID, "title": "a.png" } },
+                { "type": "bulletList", "content": [
+                    { "type": "listItem", "content": [
+                        { "type": "paragraph", "content": [{ "type": "text", "text": "item" }] },
+                    ] },
+                ] },
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            document.to_plain_text(),
+            "Title\nAsk @Ann about it\nnext line\nitem"
+        );
+    }
+
+    #[test]
+    fn test_parse_internal_href() {
+        for (href, expected) in [
+            (format!("/pages/{ID}"), Some((MentionKind::Page, id()))),
+            (
+                format!("https://app.example.com/workspaces/x/pages/{ID}"),
+                Some((MentionKind::Page, id())),
+            ),
+            (
+                format!("/pages/{ID}#heading"),
+                Some((MentionKind::Page, id())),
+            ),
+            (format!("/tasks/{ID}"), Some((MentionKind::Task, id()))),
+            (format!("/tasks/{ID}/"), Some((MentionKind::Task, id()))),
+            // The task drawer wins over the board page it is opened on
+            (
+                format!("/pages/{}?view=1&taskId={ID}", Uuid::nil()),
+                Some((MentionKind::Task, id())),
+            ),
+            (
+                format!("?taskId={ID}#comments"),
+                Some((MentionKind::Task, id())),
+            ),
+            (
+                format!("/pages/{ID}?taskId=invalid"),
+                Some((MentionKind::Page, id())),
+            ),
+            ("/pages/invalid".to_string(), None),
+            (format!("/users/{ID}"), None),
+            (format!("/pages?id={ID}"), None),
+            ("https://example.com".to_string(), None),
+            ("".to_string(), None),
+        ] {
+            assert_eq!(parse_internal_href(&href), expected, "{href}");
+        }
+    }
+}
@@ -0,0 +1,616 @@
+//! Markdown (CommonMark with GFM tables, task lists and strikethrough) to TipTap parser.
+
+use pulldown_cmark::{CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
+use serde_json::{Map, Value, json};
+
+use super::FileAttributes;
+
+fn options() -> Options {
+    Options::ENABLE_TABLES | Options::ENABLE_TASKLISTS | Options::ENABLE_STRIKETHROUGH
+}
+
+pub(super) fn image_sources(markdown: &str) -> Vec<String> {
+    Parser::new_ext(markdown, options())
+        .filter_map(|event| match event {
+            Event::Start(Tag::Image { dest_url, .. }) => Some(dest_url.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+pub(super) fn parse(
+    markdown: &str,
+    resolve_file: &mut dyn FnMut(&str) -> Option<FileAttributes>,
+) -> Vec<Value> {
+    let mut builder = Builder {
+        stack: vec![Frame::new("doc", Map::new(), false)],
+        marks: Vec::new(),
+        image: None,
+        in_table_head: false,
+        resolve_file,
+    };
+
+    for event in Parser::new_ext(markdown, options()) {
+        builder.event(event);
+    }
+
+    while builder.stack.len() > 1 {
+        builder.close();
+    }
+
+    builder
+        .stack
+        .pop()
+        .map(|doc| doc.children)
+        .unwrap_or_default()
+}
+
+struct Frame {
+    node_type: &'static str,
+    attrs: Map<String, Value>,
+    children: Vec<Value>,
+    /// Paragraph opened for inline content of a tight list item, table cell, etc.
+    implicit: bool,
+}
+
+impl Frame {
+    fn new(node_type: &'static str, attrs: Map<String, Value>, implicit: bool) -> Self {
+        Self {
+            node_type,
+            attrs,
+            children: Vec::new(),
+            implicit,
+        }
+    }
+
+    /// Builds the node, fixing up the content TipTap requires.
+    /// Returns `None` for empty paragraphs, which are left over from splitting around files.
+    fn into_node(mut self) -> Option<Value> {
+        match self.node_type {
+            "paragraph" if self.children.is_empty() => return None,
+            "codeBlock" => {
+                let code = self
+                    .children
+                    .iter()
+                    .filter_map(|child| child.get("text").and_then(|t| t.as_str()))
+                    .collect::<String>();
+                let code = code.trim_end_matches('\n');
+
+                self.children = if code.is_empty() {
+                    vec![]
+                } else {
+                    vec![json!({ "type": "text", "text": code })]
+                };
+            }
+            "bulletList" | "orderedList"
+                if self.children.iter().any(|c| c["type"] == "taskItem") =>
+            {
+                // A list with some task items becomes a task list, the other items are unchecked tasks
+                self.node_type = "taskList";
+                self.attrs.clear();
+
+                for child in self.children.iter_mut() {
+                    if child["type"] == "listItem" {
+                        child["type"] = json!("taskItem");
+                        child["attrs"] = json!({ "checked": false });
+                    }
+                }
+            }
+            "listItem" | "taskItem"
+                if self
+                    .children
+                    .first()
+                    .is_none_or(|c| c["type"] != "paragraph") =>
+            {
+                self.children.insert(0, json!({ "type": "paragraph" }));
+            }
+            "blockquote" | "tableCell" | "tableHeader" if self.children.is_empty() => {
+                self.children.push(json!({ "type": "paragraph" }));
+            }
+            _ => {}
+        }
+
+        let mut node = Map::new();
+        node.insert("type".to_string(), json!(self.node_type));
+        if !self.attrs.is_empty() {
+            node.insert("attrs".to_string(), Value::Object(self.attrs));
+        }
+        if !self.children.is_empty() {
+            node.insert("content".to_string(), Value::Array(self.children));
+        }
+
+        Some(Value::Object(node))
+    }
+}
+
+struct Image {
+    src: String,
+    alt: String,
+}
+
+struct Builder<'a> {
+    stack: Vec<Frame>,
+    marks: Vec<Value>,
+    /// Text inside an image is its alt text
+    image: Option<Image>,
+    in_table_head: bool,
+    resolve_file: &'a mut dyn FnMut(&str) -> Option<FileAttributes>,
+}
+
+impl Builder<'_> {
+    fn top(&mut self) -> &mut Frame {
+        self.stack
+            .last_mut()
+            .expect("the document frame is never closed")
+    }
+
+    fn event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => self.text(&text),
+            Event::Code(code) => self.code(&code),
+            // Raw HTML is not interpreted, it is kept as text, one line of an HTML block per line
+            Event::Html(html) => {
+                if self.top().implicit && !self.top().children.is_empty() {
+                    self.push_inline(json!({ "type": "hardBreak" }));
+                }
+                self.text(html.trim_end_matches('\n'));
+            }
+            Event::InlineHtml(html) => self.text(&html),
+            Event::SoftBreak => self.text(" "),
+            Event::HardBreak => match &mut self.image {
+                Some(image) => image.alt.push(' '),
+                None => self.push_inline(json!({ "type": "hardBreak" })),
+            },
+            Event::Rule => {
+                self.open("horizontalRule", Map::new());
+                self.close();
+            }
+            Event::TaskListMarker(checked) => {
+                if let Some(item) = self
+                    .stack
+                    .iter_mut()
+                    .rev()
+                    .find(|frame| frame.node_type == "listItem")
+                {
+                    item.node_type = "taskItem";
+                    item.attrs.insert("checked".to_string(), json!(checked));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag) {
+        match tag {
+            Tag::HtmlBlock => self.close_implicit(),
+            Tag::Paragraph => self.open("paragraph", Map::new()),
+            Tag::Heading { level, .. } => {
+                self.open("heading", attrs(json!({ "level": level as u8 })))
+            }
+            Tag::BlockQuote(_) => self.open("blockquote", Map::new()),
+            Tag::CodeBlock(kind) => {
+                let language = match kind {
+                    CodeBlockKind::Fenced(info) => info
+                        .split_whitespace()
+                        .next()
+                        .map(|language| language.to_string()),
+                    CodeBlockKind::Indented => None,
+                };
+
+                self.open("codeBlock", attrs(json!({ "language": language })));
+            }
+            Tag::List(Some(start)) => self.open("orderedList", attrs(json!({ "start": start }))),
+            Tag::List(None) => self.open("bulletList", Map::new()),
+            Tag::Item => self.open("listItem", Map::new()),
+            Tag::Table(_) => self.open("table", Map::new()),
+            Tag::TableHead => {
+                self.in_table_head = true;
+                self.open("tableRow", Map::new());
+            }
+            Tag::TableRow => self.open("tableRow", Map::new()),
+            Tag::TableCell => {
+                let cell = if self.in_table_head {
+                    "tableHeader"
+                } else {
+                    "tableCell"
+                };
+
+                self.open(cell, Map::new());
+            }
+            Tag::Emphasis => self.marks.push(json!({ "type": "italic" })),
+            Tag::Strong => self.marks.push(json!({ "type": "bold" })),
+            Tag::Strikethrough => self.marks.push(json!({ "type": "strike" })),
+            Tag::Link { dest_url, .. } => self.marks.push(json!({
+                "type": "link",
+                "attrs": { "href": dest_url.to_string() },
+            })),
+            Tag::Image { dest_url, .. } => {
+                self.image = Some(Image {
+                    src: dest_url.to_string(),
+                    alt: String::new(),
+                })
+            }
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph
+            | TagEnd::Heading(_)
+            | TagEnd::BlockQuote(_)
+            | TagEnd::CodeBlock
+            | TagEnd::List(_)
+            | TagEnd::Item
+            | TagEnd::Table
+            | TagEnd::TableRow
+            | TagEnd::TableCell => {
+                self.close_implicit();
+                self.close();
+            }
+            TagEnd::HtmlBlock => self.close_implicit(),
+            TagEnd::TableHead => {
+                self.close_implicit();
+                self.close();
+                self.in_table_head = false;
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough | TagEnd::Link => {
+                self.marks.pop();
+            }
+            TagEnd::Image => {
+                if let Some(image) = self.image.take() {
+                    self.image(image);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn open(&mut self, node_type: &'static str, attrs: Map<String, Value>) {
+        self.close_implicit();
+        self.stack.push(Frame::new(node_type, attrs, false));
+    }
+
+    fn close_implicit(&mut self) {
+        if self.top().implicit {
+            self.close();
+        }
+    }
+
+    fn close(&mut self) {
+        if self.stack.len() == 1 {
+            return;
+        }
+
+        let frame = self.stack.pop().expect("checked above");
+        if let Some(node) = frame.into_node() {
+            self.top().children.push(node);
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(image) = &mut self.image {
+            image.alt.push_str(text);
+            return;
+        }
+        if self.top().node_type == "codeBlock" {
+            self.top()
+                .children
+                .push(json!({ "type": "text", "text": text }));
+            return;
+        }
+
+        let marks = self.marks.clone();
+        self.push_inline(text_node(text, marks));
+    }
+
+    /// The code mark excludes every other mark in TipTap, only a surrounding link is kept.
+    fn code(&mut self, code: &str) {
+        if code.is_empty() {
+            return;
+        }
+        if let Some(image) = &mut self.image {
+            image.alt.push_str(code);
+            return;
+        }
+
+        let mut marks = vec![json!({ "type": "code" })];
+        marks.extend(self.marks.iter().filter(|m| m["type"] == "link").cloned());
+
+        self.push_inline(text_node(code, marks));
+    }
+
+    fn push_inline(&mut self, node: Value) {
+        if !matches!(self.top().node_type, "paragraph" | "heading") {
+            self.stack.push(Frame::new("paragraph", Map::new(), true));
+        }
+
+        let children = &mut self.top().children;
+
+        // Adjacent text with the same marks is merged, soft breaks would otherwise split every line
+        if let Some(last) = children.last_mut()
+            && last["type"] == "text"
+            && node["type"] == "text"
+            && last.get("marks") == node.get("marks")
+            && let (Some(previous), Some(text)) = (last["text"].as_str(), node["text"].as_str())
+        {
+            last["text"] = json!(format!("{previous}{text}"));
+            return;
+        }
+
+        children.push(node);
+    }
+
+    /// Resolved images become file nodes, which are blocks, so the surrounding paragraph is split.
+    /// Unresolved ones are kept as links.
+    fn image(&mut self, image: Image) {
+        let file = match self.top().node_type {
+            "heading" => None,
+            _ => (self.resolve_file)(&image.src),
+        };
+
+        let Some(mut attrs) = file else {
+            let label = if image.alt.trim().is_empty() {
+                image.src.clone()
+            } else {
+                image.alt
+            };
+            let mut marks = self.marks.clone();
+            marks.retain(|m| m["type"] != "link");
+            marks.push(json!({ "type": "link", "attrs": { "href": image.src } }));
+
+            self.push_inline(text_node(&label, marks));
+            return;
+        };
+
+        if !image.alt.trim().is_empty() {
+            attrs.alt = Some(image.alt);
+        }
+        let node = json!({ "type": "file", "attrs": attrs });
+
+        if self.top().node_type == "paragraph" {
+            let implicit = self.top().implicit;
+            self.close();
+            self.top().children.push(node);
+            self.stack
+                .push(Frame::new("paragraph", Map::new(), implicit));
+        } else {
+            self.top().children.push(node);
+        }
+    }
+}
+
+fn attrs(value: Value) -> Map<String, Value> {
+    match value {
+        Value::Object(map) => map,
+        _ => Map::new(),
+    }
+}
+
+fn text_node(text: &str, marks: Vec<Value>) -> Value {
+    if marks.is_empty() {
+        json!({ "type": "text", "text": text })
+    } else {
+        json!({ "type": "text", "text": text, "marks": marks })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::super::{NodeWrapper, TipTapContent};
+    use super::*;
+
+    fn parse_markdown(markdown: &str) -> Value {
+        Value::Array(parse(markdown, &mut |_| None))
+    }
+
+    fn text(text: &str) -> Value {
+        json!({ "type": "text", "text": text })
+    }
+
+    fn paragraph(content: Value) -> Value {
+        json!({ "type": "paragraph", "content": content })
+    }
+
+    #[test]
+    fn test_blocks() {
+        let markdown = "# Title\n\nfirst\nline\n\n> quote\n\n```rust extra\nlet a = 1;\n```\n\n    indented\n\n---";
+
+        assert_eq!(
+            parse_markdown(markdown),
+            json!([
+                { "type": "heading", "attrs": { "level": 1 }, "content": [text("Title")] },
+                paragraph(json!([text("first line")])),
+                { "type": "blockquote", "content": [paragraph(json!([text("quote")]))] },
+                { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [text("let a = 1;")] },
+                { "type": "codeBlock", "attrs": { "language": null }, "content": [text("indented")] },
+                { "type": "horizontalRule" },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_marks() {
+        let markdown = "**bold** *italic* ~~strike~~ [`code`](https://example.com) a  \nb";
+
+        assert_eq!(
+            parse_markdown(markdown),
+            json!([paragraph(json!([
+                { "type": "text", "text": "bold", "marks": [{ "type": "bold" }] },
+                text(" "),
+                { "type": "text", "text": "italic", "marks": [{ "type": "italic" }] },
+                text(" "),
+                { "type": "text", "text": "strike", "marks": [{ "type": "strike" }] },
+                text(" "),
+                { "type": "text", "text": "code", "marks": [
+                    { "type": "code" },
+                    { "type": "link", "attrs": { "href": "https://example.com" } },
+                ] },
+                text(" a"),
+                { "type": "hardBreak" },
+                text("b"),
+            ]))])
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let markdown = "- one\n  - nested\n- [x] done\n\n3. three\n4. four";
+
+        assert_eq!(
+            parse_markdown(markdown),
+            json!([
+                { "type": "taskList", "content": [
+                    { "type": "taskItem", "attrs": { "checked": false }, "content": [
+                        paragraph(json!([text("one")])),
+                        { "type": "bulletList", "content": [
+                            { "type": "listItem", "content": [paragraph(json!([text("nested")]))] },
+                        ] },
+                    ] },
+                    { "type": "taskItem", "attrs": { "checked": true }, "content": [paragraph(json!([text("done")]))] },
+                ] },
+                { "type": "orderedList", "attrs": { "start": 3 }, "content": [
+                    { "type": "listItem", "content": [paragraph(json!([text("three")]))] },
+                    { "type": "listItem", "content": [paragraph(json!([text("four")]))] },
+                ] },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let markdown = "| a | b |\n| --- | --- |\n| 1 |  |";
+
+        assert_eq!(
+            parse_markdown(markdown),
+            json!([{ "type": "table", "content": [
+                { "type": "tableRow", "content": [
+                    { "type": "tableHeader", "content": [paragraph(json!([text("a")]))] },
+                    { "type": "tableHeader", "content": [paragraph(json!([text("b")]))] },
+                ] },
+                { "type": "tableRow", "content": [
+                    { "type": "tableCell", "content": [paragraph(json!([text("1")]))] },
+                    { "type": "tableCell", "content": [{ "type": "paragraph" }] },
+                ] },
+            ] }])
+        );
+    }
+
+    #[test]
+    fn test_images() {
+        let id = Uuid::nil();
+        let mut resolve_file = |src: &str| {
+            (src == "a.png").then(|| FileAttributes {
+                id: Some(id),
+                src: None,
+                href: None,
+                alt: None,
+                title: Some("a.png".to_string()),
+                height: None,
+                width: None,
+                size: None,
+                r#type: Some("image/png".to_string()),
+            })
+        };
+
+        let nodes = parse(
+            "before ![Alt](a.png) after ![](missing.png)",
+            &mut resolve_file,
+        );
+
+        assert_eq!(
+            Value::Array(nodes),
+            json!([
+                paragraph(json!([text("before ")])),
+                { "type": "file", "attrs": {
+                    "id": id, "src": null, "href": null, "alt": "Alt", "title": "a.png",
+                    "height": null, "width": null, "size": null, "type": "image/png",
+                } },
+                paragraph(json!([
+                    text(" after "),
+                    { "type": "text", "text": "missing.png", "marks": [{ "type": "link", "attrs": { "href": "missing.png" } }] },
+                ])),
+            ])
+        );
+        assert_eq!(
+            image_sources("![a](a.png) [link](b.png) ![b](c.png)"),
+            vec!["a.png", "c.png"]
+        );
+    }
+
+    #[test]
+    fn test_html_is_kept_as_text() {
+        assert_eq!(
+            parse_markdown("<script>alert(1)</script>\n\n<div>\nline\n</div>\n\na <b>b</b>"),
+            json!([
+                paragraph(json!([text("<script>alert(1)</script>")])),
+                paragraph(json!([
+                    text("<div>"),
+                    { "type": "hardBreak" },
+                    text("line"),
+                    { "type": "hardBreak" },
+                    text("</div>"),
+                ])),
+                paragraph(json!([text("a <b>b</b>")])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_markdown_round_trip() {
+        let nodes = json!([
+            { "type": "heading", "attrs": { "level": 2 }, "content": [text("Title # with *stars*")] },
+            paragraph(json!([
+                { "type": "text", "text": "bold", "marks": [{ "type": "bold" }] },
+                text(" _under_ [brackets] a\\b <tag> | pipe "),
+                { "type": "text", "text": "a `tick`", "marks": [{ "type": "code" }] },
+                { "type": "hardBreak" },
+                { "type": "text", "text": "link", "marks": [{ "type": "link", "attrs": { "href": "/pages/a b" } }] },
+            ])),
+            paragraph(json!([text("1. not a list")])),
+            paragraph(json!([text("- not a list either")])),
+            { "type": "blockquote", "content": [paragraph(json!([text("quote")]))] },
+            { "type": "codeBlock", "attrs": { "language": "md" }, "content": [text("```\ncode\n```")] },
+            { "type": "taskList", "content": [
+                { "type": "taskItem", "attrs": { "checked": true }, "content": [
+                    paragraph(json!([text("done")])),
+                    { "type": "orderedList", "attrs": { "start": 1 }, "content": [
+                        { "type": "listItem", "content": [paragraph(json!([text("step")]))] },
+                    ] },
+                ] },
+            ] },
+            { "type": "table", "content": [
+                { "type": "tableRow", "content": [
+                    { "type": "tableHeader", "content": [paragraph(json!([text("a|b")]))] },
+                ] },
+                { "type": "tableRow", "content": [
+                    { "type": "tableCell", "content": [paragraph(json!([text("c")]))] },
+                ] },
+            ] },
+            { "type": "horizontalRule" },
+        ]);
+
+        let document = TipTapContent {
+            r#type: super::super::DocType::Doc,
+            content: Some(
+                nodes
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .cloned()
+                    .map(NodeWrapper::Generic)
+                    .collect(),
+            ),
+        };
+        let markdown = document.to_markdown(|_| None);
+
+        assert_eq!(parse_markdown(&markdown), nodes, "{markdown}");
+    }
+}
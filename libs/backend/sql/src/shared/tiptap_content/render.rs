@@ -0,0 +1,844 @@
+//! Markdown (GFM) and HTML renderers of TipTap documents.
+
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::{FileAttributes, MentionAttributes, MentionKind, validate::MAX_CELL_SPAN};
+
+pub(super) type FileUrl<'a> = &'a dyn Fn(&FileAttributes) -> Option<String>;
+
+fn node_type(node: &Value) -> &str {
+    node.get("type")
+        .and_then(|t| t.as_str())
+        .unwrap_or_default()
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(|c| c.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|a| a.get(name))
+}
+
+fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name).and_then(|v| v.as_str())
+}
+
+fn has_mark(node: &Value, mark: &str) -> bool {
+    mark_attrs(node, mark).is_some()
+}
+
+fn mark_attrs<'a>(node: &'a Value, mark: &str) -> Option<&'a Value> {
+    node.get("marks")
+        .and_then(|m| m.as_array())
+        .into_iter()
+        .flatten()
+        .find(|m| node_type(m) == mark)
+        .map(|m| m.get("attrs").unwrap_or(&Value::Null))
+}
+
+fn file_attributes(node: &Value) -> Option<FileAttributes> {
+    serde_json::from_value(node.get("attrs")?.clone()).ok()
+}
+
+fn mention_attributes(node: &Value) -> Option<MentionAttributes> {
+    serde_json::from_value(node.get("attrs")?.clone()).ok()
+}
+
+fn is_image(attrs: &FileAttributes) -> bool {
+    attrs
+        .r#type
+        .as_deref()
+        .is_some_and(|t| t.starts_with("image/"))
+}
+
+fn file_name(attrs: &FileAttributes) -> &str {
+    attrs
+        .title
+        .as_deref()
+        .or(attrs.alt.as_deref())
+        .unwrap_or("file")
+}
+
+/// Same routes as the ones recognised by `parse_internal_href`, so exported links keep working as references.
+fn mention_href(kind: MentionKind, id: Uuid) -> Option<String> {
+    match kind {
+        MentionKind::User => None,
+        MentionKind::Page => Some(format!("/pages/{id}")),
+        MentionKind::Task => Some(format!("/tasks/{id}")),
+    }
+}
+
+fn mention_label(attrs: &MentionAttributes) -> String {
+    attrs.label.clone().unwrap_or_else(|| attrs.id.to_string())
+}
+
+/// Text of the node without any formatting, used for code blocks.
+fn raw_text(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(|node| match node_type(node) {
+            "text" => node
+                .get("text")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default()
+                .to_string(),
+            "hardBreak" => "\n".to_string(),
+            _ => raw_text(children(node)),
+        })
+        .collect()
+}
+
+/// Moves the surrounding whitespace out of a formatted span, `** bold**` is not emphasis in CommonMark.
+fn wrap_trimmed(text: &str, open: &str, close: &str) -> String {
+    let core = text.trim();
+
+    if core.is_empty() {
+        return text.to_string();
+    }
+
+    let start = text.len() - text.trim_start().len();
+    let end = start + core.len();
+
+    format!("{}{open}{core}{close}{}", &text[..start], &text[end..])
+}
+
+// MARKDOWN
+
+pub(super) struct MarkdownRenderer<'a> {
+    pub file_url: FileUrl<'a>,
+}
+
+impl MarkdownRenderer<'_> {
+    pub fn blocks(&self, nodes: &[Value]) -> String {
+        nodes
+            .iter()
+            .map(|node| self.block(node))
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn block(&self, node: &Value) -> String {
+        match node_type(node) {
+            "paragraph" => escape_block_start(&self.inline(children(node))),
+            "heading" => {
+                let level = attr(node, "level")
+                    .and_then(|l| l.as_u64())
+                    .unwrap_or(1)
+                    .clamp(1, 6) as usize;
+
+                format!("{} {}", "#".repeat(level), self.inline(children(node)))
+            }
+            "blockquote" => prefix_lines(&self.blocks(children(node)), "> ", "> "),
+            "codeBlock" => {
+                let code = raw_text(children(node));
+                let language = attr_str(node, "language").unwrap_or_default();
+                let fence = "`".repeat(longest_run(&code, '`').max(2) + 1);
+
+                format!("{fence}{language}\n{code}\n{fence}")
+            }
+            "horizontalRule" => "---".to_string(),
+            "bulletList" => self.list(node, |_, _| "- ".to_string()),
+            "orderedList" => {
+                let start = attr(node, "start").and_then(|s| s.as_u64()).unwrap_or(1);
+
+                self.list(node, |index, _| format!("{}. ", start + index as u64))
+            }
+            "taskList" => self.list(node, |_, item| {
+                if attr(item, "checked").and_then(|c| c.as_bool()) == Some(true) {
+                    "- [x] ".to_string()
+                } else {
+                    "- [ ] ".to_string()
+                }
+            }),
+            "table" => self.table(node),
+            "text" | "hardBreak" | "mention" | "image" | "file" => {
+                self.inline(std::slice::from_ref(node))
+            }
+            _ => self.blocks(children(node)),
+        }
+    }
+
+    /// Items are kept tight, a nested list directly follows the text of its item.
+    fn list<F>(&self, node: &Value, marker: F) -> String
+    where
+        F: Fn(usize, &Value) -> String,
+    {
+        children(node)
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let marker = marker(index, item);
+                // Task markers are part of the item content, continuation lines align after `- `
+                let indent = if marker.starts_with("- ") {
+                    2
+                } else {
+                    marker.len()
+                };
+
+                let mut body = String::new();
+                for child in children(item) {
+                    let block = self.block(child);
+
+                    if block.is_empty() {
+                        continue;
+                    }
+                    if !body.is_empty() {
+                        body.push_str(if node_type(child).ends_with("List") {
+                            "\n"
+                        } else {
+                            "\n\n"
+                        });
+                    }
+                    body.push_str(&block);
+                }
+
+                prefix_lines(&body, &marker, &" ".repeat(indent))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// GFM tables need a header row, the first row is used as such.
+    fn table(&self, node: &Value) -> String {
+        let rows = children(node)
+            .iter()
+            .map(|row| {
+                children(row)
+                    .iter()
+                    .flat_map(|cell| {
+                        let text = children(cell)
+                            .iter()
+                            .map(|block| self.block(block))
+                            .filter(|block| !block.is_empty())
+                            .collect::<Vec<_>>()
+                            .join("<br>")
+                            .replace("\\\n", "<br>")
+                            .replace('\n', "<br>");
+                        // Markdown has no spans, merged cells are followed by empty ones
+                        let colspan = attr(cell, "colspan")
+                            .and_then(|c| c.as_u64())
+                            .unwrap_or(1)
+                            .min(MAX_CELL_SPAN);
+
+                        std::iter::once(text).chain(std::iter::repeat_n(
+                            String::new(),
+                            colspan.saturating_sub(1) as usize,
+                        ))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+
+        if columns == 0 {
+            return String::new();
+        }
+
+        let line = |cells: &[String]| {
+            let mut line = String::from("|");
+            for index in 0..columns {
+                line.push(' ');
+                line.push_str(cells.get(index).map(String::as_str).unwrap_or_default());
+                line.push_str(" |");
+            }
+            line
+        };
+
+        let mut lines = vec![line(&rows[0]), line(&vec!["---".to_string(); columns])];
+        lines.extend(rows[1..].iter().map(|row| line(row)));
+
+        lines.join("\n")
+    }
+
+    fn inline(&self, nodes: &[Value]) -> String {
+        nodes
+            .iter()
+            .map(|node| match node_type(node) {
+                "text" => Self::text(node),
+                "hardBreak" => "\\\n".to_string(),
+                "mention" => mention_attributes(node)
+                    .map(|attrs| {
+                        let label = escape_markdown(&mention_label(&attrs));
+
+                        match mention_href(attrs.kind, attrs.id) {
+                            Some(href) => format!("[{label}]({href})"),
+                            None => format!("@{label}"),
+                        }
+                    })
+                    .unwrap_or_default(),
+                "image" => format!(
+                    "![{}]({})",
+                    escape_markdown(attr_str(node, "alt").unwrap_or_default()),
+                    markdown_url(attr_str(node, "src").unwrap_or_default())
+                ),
+                "file" => file_attributes(node)
+                    .map(|attrs| self.file(&attrs))
+                    .unwrap_or_default(),
+                _ => self.inline(children(node)),
+            })
+            .collect()
+    }
+
+    fn text(node: &Value) -> String {
+        let text = node
+            .get("text")
+            .and_then(|t| t.as_str())
+            .unwrap_or_default();
+
+        let mut text = if has_mark(node, "code") {
+            let fence = "`".repeat(longest_run(text, '`') + 1);
+            let padding = if text.starts_with('`') || text.ends_with('`') {
+                " "
+            } else {
+                ""
+            };
+
+            format!("{fence}{padding}{text}{padding}{fence}")
+        } else {
+            escape_markdown(text)
+        };
+
+        if has_mark(node, "bold") {
+            text = wrap_trimmed(&text, "**", "**");
+        }
+        if has_mark(node, "italic") {
+            text = wrap_trimmed(&text, "*", "*");
+        }
+        if has_mark(node, "strike") {
+            text = wrap_trimmed(&text, "~~", "~~");
+        }
+        if let Some(href) = mark_attrs(node, "link")
+            .and_then(|a| a.get("href"))
+            .and_then(|h| h.as_str())
+        {
+            text = format!("[{text}]({})", markdown_url(href));
+        }
+
+        text
+    }
+
+    fn file(&self, attrs: &FileAttributes) -> String {
+        let name = escape_markdown(file_name(attrs));
+
+        match (self.file_url)(attrs) {
+            Some(url) if is_image(attrs) => format!("![{name}]({})", markdown_url(&url)),
+            Some(url) => format!("[{name}]({})", markdown_url(&url)),
+            None => name,
+        }
+    }
+}
+
+fn longest_run(text: &str, c: char) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+
+    for ch in text.chars() {
+        if ch == c {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+
+    longest
+}
+
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '~' | '|'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+
+    escaped
+}
+
+/// Keeps a paragraph from being read as a heading, list item or thematic break.
+fn escape_block_start(text: &str) -> String {
+    let digits = text.chars().take_while(|c| c.is_ascii_digit()).count();
+
+    if text.starts_with(['#', '-', '+', '=']) {
+        format!("\\{text}")
+    } else if digits > 0 && text[digits..].starts_with(['.', ')']) {
+        format!("{}\\{}", &text[..digits], &text[digits..])
+    } else {
+        text.to_string()
+    }
+}
+
+fn markdown_url(url: &str) -> String {
+    if url.contains([' ', '(', ')', '<', '>']) {
+        format!("<{}>", url.replace('<', "%3C").replace('>', "%3E"))
+    } else {
+        url.to_string()
+    }
+}
+
+/// Prefixes the first line with `first` and the following non-empty ones with `rest`.
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let prefix = if index == 0 { first } else { rest };
+
+            if line.is_empty() {
+                prefix.trim_end().to_string()
+            } else {
+                format!("{prefix}{line}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// HTML
+
+pub(super) struct HtmlRenderer<'a> {
+    pub file_url: FileUrl<'a>,
+}
+
+impl HtmlRenderer<'_> {
+    pub fn blocks(&self, nodes: &[Value]) -> String {
+        nodes
+            .iter()
+            .map(|node| self.block(node))
+            .filter(|block| !block.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn block(&self, node: &Value) -> String {
+        match node_type(node) {
+            "paragraph" => format!("<p>{}</p>", self.inline(children(node))),
+            "heading" => {
+                let level = attr(node, "level")
+                    .and_then(|l| l.as_u64())
+                    .unwrap_or(1)
+                    .clamp(1, 6);
+
+                format!("<h{level}>{}</h{level}>", self.inline(children(node)))
+            }
+            "blockquote" => format!(
+                "<blockquote>\n{}\n</blockquote>",
+                self.blocks(children(node))
+            ),
+            "codeBlock" => {
+                let code = escape_html(&raw_text(children(node)));
+
+                match attr_str(node, "language") {
+                    Some(language) => format!(
+                        "<pre><code class=\"language-{}\">{code}</code></pre>",
+                        escape_html(language)
+                    ),
+                    None => format!("<pre><code>{code}</code></pre>"),
+                }
+            }
+            "horizontalRule" => "<hr>".to_string(),
+            "bulletList" => format!("<ul>\n{}\n</ul>", self.list_items(node)),
+            "orderedList" => match attr(node, "start").and_then(|s| s.as_u64()) {
+                Some(start) if start != 1 => {
+                    format!("<ol start=\"{start}\">\n{}\n</ol>", self.list_items(node))
+                }
+                _ => format!("<ol>\n{}\n</ol>", self.list_items(node)),
+            },
+            "taskList" => format!(
+                "<ul data-type=\"taskList\">\n{}\n</ul>",
+                children(node)
+                    .iter()
+                    .map(|item| {
+                        let checked = attr(item, "checked").and_then(|c| c.as_bool())
+                            == Some(true);
+
+                        format!(
+                            "<li data-checked=\"{checked}\"><input type=\"checkbox\" disabled{}> {}</li>",
+                            if checked { " checked" } else { "" },
+                            self.blocks(children(item))
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+            "table" => format!(
+                "<table>\n<tbody>\n{}\n</tbody>\n</table>",
+                children(node)
+                    .iter()
+                    .map(|row| format!(
+                        "<tr>{}</tr>",
+                        children(row)
+                            .iter()
+                            .map(|cell| self.table_cell(cell))
+                            .collect::<String>()
+                    ))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+            "text" | "hardBreak" | "mention" | "image" | "file" => {
+                self.inline(std::slice::from_ref(node))
+            }
+            _ => self.blocks(children(node)),
+        }
+    }
+
+    fn list_items(&self, node: &Value) -> String {
+        children(node)
+            .iter()
+            .map(|item| format!("<li>{}</li>", self.blocks(children(item))))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn table_cell(&self, cell: &Value) -> String {
+        let tag = if node_type(cell) == "tableHeader" {
+            "th"
+        } else {
+            "td"
+        };
+
+        let mut spans = String::new();
+        for name in ["colspan", "rowspan"] {
+            if let Some(span) = attr(cell, name).and_then(|s| s.as_u64())
+                && span > 1
+            {
+                let span = span.min(MAX_CELL_SPAN);
+                spans.push_str(&format!(" {name}=\"{span}\""));
+            }
+        }
+
+        format!("<{tag}{spans}>{}</{tag}>", self.blocks(children(cell)))
+    }
+
+    fn inline(&self, nodes: &[Value]) -> String {
+        nodes
+            .iter()
+            .map(|node| match node_type(node) {
+                "text" => Self::text(node),
+                "hardBreak" => "<br>".to_string(),
+                "mention" => mention_attributes(node)
+                    .map(|attrs| {
+                        let label = escape_html(&mention_label(&attrs));
+
+                        match mention_href(attrs.kind, attrs.id) {
+                            Some(href) => format!(
+                                "<a href=\"{href}\" data-type=\"mention\" data-kind=\"{}\">{label}</a>",
+                                attrs.kind.as_str()
+                            ),
+                            None => format!(
+                                "<span data-type=\"mention\" data-kind=\"{}\" data-id=\"{}\">@{label}</span>",
+                                attrs.kind.as_str(),
+                                attrs.id
+                            ),
+                        }
+                    })
+                    .unwrap_or_default(),
+                "image" => format!(
+                    "<img src=\"{}\" alt=\"{}\">",
+                    escape_html(attr_str(node, "src").unwrap_or_default()),
+                    escape_html(attr_str(node, "alt").unwrap_or_default())
+                ),
+                "file" => file_attributes(node)
+                    .map(|attrs| self.file(&attrs))
+                    .unwrap_or_default(),
+                _ => self.inline(children(node)),
+            })
+            .collect()
+    }
+
+    fn text(node: &Value) -> String {
+        let mut text = escape_html(
+            node.get("text")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default(),
+        );
+
+        for (mark, tag) in [
+            ("code", "code"),
+            ("bold", "strong"),
+            ("italic", "em"),
+            ("underline", "u"),
+            ("strike", "s"),
+        ] {
+            if has_mark(node, mark) {
+                text = format!("<{tag}>{text}</{tag}>");
+            }
+        }
+
+        if let Some(href) = mark_attrs(node, "link")
+            .and_then(|a| a.get("href"))
+            .and_then(|h| h.as_str())
+        {
+            text = format!("<a href=\"{}\">{text}</a>", escape_html(href));
+        }
+
+        text
+    }
+
+    fn file(&self, attrs: &FileAttributes) -> String {
+        let name = escape_html(file_name(attrs));
+
+        match (self.file_url)(attrs) {
+            Some(url) if is_image(attrs) => {
+                format!("<img src=\"{}\" alt=\"{name}\">", escape_html(&url))
+            }
+            Some(url) => format!("<a href=\"{}\">{name}</a>", escape_html(&url)),
+            None => name,
+        }
+    }
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn file_url(attrs: &FileAttributes) -> Option<String> {
+        attrs.src.clone()
+    }
+
+    fn markdown(nodes: Value) -> String {
+        MarkdownRenderer {
+            file_url: &file_url,
+        }
+        .blocks(nodes.as_array().unwrap())
+    }
+
+    fn html(nodes: Value) -> String {
+        HtmlRenderer {
+            file_url: &file_url,
+        }
+        .blocks(nodes.as_array().unwrap())
+    }
+
+    fn text(text: &str, marks: Value) -> Value {
+        json!({ "type": "text", "text": text, "marks": marks })
+    }
+
+    fn paragraph(content: Value) -> Value {
+        json!({ "type": "paragraph", "content": content })
+    }
+
+    #[test]
+    fn test_markdown_blocks() {
+        let nodes = json!([
+            { "type": "heading", "attrs": { "level": 2 }, "content": [{ "type": "text", "text": "Title" }] },
+            paragraph(json!([
+                text("bold", json!([{ "type": "bold" }])),
+                { "type": "text", "text": " and " },
+                text("link", json!([{ "type": "link", "attrs": { "href": "https://example.com" } }])),
+            ])),
+            { "type": "blockquote", "content": [
+                paragraph(json!([{ "type": "text", "text": "first" }])),
+                paragraph(json!([{ "type": "text", "text": "second" }])),
+            ] },
+            { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [{ "type": "text", "text": "let a = 1;" }] },
+            { "type": "horizontalRule" },
+        ]);
+
+        assert_eq!(
+            markdown(nodes),
+            "## Title\n\n**bold** and [link](https://example.com)\n\n> first\n>\n> second\n\n```rust\nlet a = 1;\n```\n\n---"
+        );
+    }
+
+    #[test]
+    fn test_markdown_lists() {
+        let item = |content: &str| json!({ "type": "listItem", "content": [paragraph(json!([{ "type": "text", "text": content }]))] });
+        let nodes = json!([
+            { "type": "bulletList", "content": [
+                { "type": "listItem", "content": [
+                    paragraph(json!([{ "type": "text", "text": "parent" }])),
+                    { "type": "bulletList", "content": [item("child")] },
+                ] },
+                item("sibling"),
+            ] },
+            { "type": "orderedList", "attrs": { "start": 3 }, "content": [item("three"), item("four")] },
+            { "type": "taskList", "content": [
+                { "type": "taskItem", "attrs": { "checked": true }, "content": [paragraph(json!([{ "type": "text", "text": "done" }]))] },
+                { "type": "taskItem", "attrs": { "checked": false }, "content": [paragraph(json!([{ "type": "text", "text": "todo" }]))] },
+            ] },
+        ]);
+
+        assert_eq!(
+            markdown(nodes),
+            "- parent\n  - child\n- sibling\n\n3. three\n4. four\n\n- [x] done\n- [ ] todo"
+        );
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let cell = |cell_type: &str, content: &str| json!({ "type": cell_type, "content": [paragraph(json!([{ "type": "text", "text": content }]))] });
+        let nodes = json!([{ "type": "table", "content": [
+            { "type": "tableRow", "content": [cell("tableHeader", "a"), cell("tableHeader", "b|c")] },
+            { "type": "tableRow", "content": [
+                { "type": "tableCell", "attrs": { "colspan": 2 }, "content": [
+                    paragraph(json!([{ "type": "text", "text": "one" }, { "type": "hardBreak" }, { "type": "text", "text": "two" }])),
+                ] },
+            ] },
+        ] }]);
+
+        assert_eq!(
+            markdown(nodes),
+            "| a | b\\|c |\n| --- | --- |\n| one<br>two |  |"
+        );
+    }
+
+    #[test]
+    fn test_markdown_table_span_is_clamped() {
+        let nodes = json!([{ "type": "table", "content": [
+            { "type": "tableRow", "content": [
+                { "type": "tableHeader", "attrs": { "colspan": u64::MAX }, "content": [paragraph(json!(null))] },
+            ] },
+        ] }]);
+
+        let columns = markdown(nodes)
+            .lines()
+            .next()
+            .unwrap()
+            .matches(" |")
+            .count();
+
+        assert_eq!(columns, MAX_CELL_SPAN as usize);
+    }
+
+    #[test]
+    fn test_markdown_escaping() {
+        let nodes = json!([
+            paragraph(json!([{ "type": "text", "text": "*not* _emphasis_ [link](x) <b> `code` a\\b ~~s~~" }])),
+            paragraph(json!([{ "type": "text", "text": "# not a heading" }])),
+            paragraph(json!([{ "type": "text", "text": "- not a list" }])),
+            paragraph(json!([{ "type": "text", "text": "1. not a list" }])),
+            paragraph(json!([text("a `tick`", json!([{ "type": "code" }]))])),
+            paragraph(json!([text("`edge`", json!([{ "type": "code" }]))])),
+            paragraph(json!([text(" padded ", json!([{ "type": "bold" }]))])),
+            paragraph(json!([text("space", json!([{ "type": "link", "attrs": { "href": "/a b(c)" } }]))])),
+            { "type": "codeBlock", "content": [{ "type": "text", "text": "```\nfenced\n```" }] },
+        ]);
+
+        assert_eq!(
+            markdown(nodes),
+            [
+                "\\*not\\* \\_emphasis\\_ \\[link\\](x) \\<b\\> \\`code\\` a\\\\b \\~\\~s\\~\\~",
+                "\\# not a heading",
+                "\\- not a list",
+                "1\\. not a list",
+                "`` a `tick` ``",
+                "`` `edge` ``",
+                " **padded** ",
+                "[space](</a b(c)>)",
+                "````\n```\nfenced\n```\n````",
+            ]
+            .join("\n\n")
+        );
+    }
+
+    #[test]
+    fn test_markdown_mentions_and_files() {
+        let id = Uuid::nil();
+        let nodes = json!([
+            paragraph(json!([
+                { "type": "mention", "attrs": { "id": id, "kind": "user", "label": "ann_b" } },
+                { "type": "text", "text": " " },
+                { "type": "mention", "attrs": { "id": id, "kind": "page", "label": "Plan" } },
+                { "type": "text", "text": " " },
+                { "type": "mention", "attrs": { "id": id, "kind": "task" } },
+            ])),
+            { "type": "file", "attrs": { "src": "/files/a.png", "type": "image/png", "title": "a.png" } },
+            { "type": "file", "attrs": { "src": "/files/b.pdf", "type": "application/pdf", "title": "b.pdf" } },
+            { "type": "file", "attrs": { "title": "c.zip" } },
+        ]);
+
+        assert_eq!(
+            markdown(nodes),
+            format!(
+                "@ann\\_b [Plan](/pages/{id}) [{id}](/tasks/{id})\n\n![a.png](/files/a.png)\n\n[b.pdf](/files/b.pdf)\n\nc.zip"
+            )
+        );
+    }
+
+    #[test]
+    fn test_html_blocks() {
+        let nodes = json!([
+            { "type": "heading", "attrs": { "level": 3 }, "content": [{ "type": "text", "text": "Title" }] },
+            paragraph(json!([
+                text("all", json!([{ "type": "bold" }, { "type": "italic" }, { "type": "link", "attrs": { "href": "/pages/1" } }])),
+                { "type": "hardBreak" },
+                text("code", json!([{ "type": "code" }])),
+            ])),
+            { "type": "orderedList", "attrs": { "start": 2 }, "content": [
+                { "type": "listItem", "content": [paragraph(json!([{ "type": "text", "text": "two" }]))] },
+            ] },
+            { "type": "taskList", "content": [
+                { "type": "taskItem", "attrs": { "checked": true }, "content": [paragraph(json!([{ "type": "text", "text": "done" }]))] },
+            ] },
+            { "type": "table", "content": [
+                { "type": "tableRow", "content": [{ "type": "tableHeader", "attrs": { "colspan": 2 }, "content": [paragraph(json!(null))] }] },
+            ] },
+            { "type": "codeBlock", "attrs": { "language": "html" }, "content": [{ "type": "text", "text": "<p>" }] },
+        ]);
+
+        assert_eq!(
+            html(nodes),
+            [
+                "<h3>Title</h3>",
+                "<p><a href=\"/pages/1\"><em><strong>all</strong></em></a><br><code>code</code></p>",
+                "<ol start=\"2\">\n<li><p>two</p></li>\n</ol>",
+                "<ul data-type=\"taskList\">\n<li data-checked=\"true\"><input type=\"checkbox\" disabled checked> <p>done</p></li>\n</ul>",
+                "<table>\n<tbody>\n<tr><th colspan=\"2\"><p></p></th></tr>\n</tbody>\n</table>",
+                "<pre><code class=\"language-html\">&lt;p&gt;</code></pre>",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn test_html_escaping() {
+        let id = Uuid::nil();
+        let nodes = json!([
+            paragraph(json!([
+                { "type": "text", "text": "<script>alert('x') & \"y\"</script>" },
+                text("link", json!([{ "type": "link", "attrs": { "href": "/a\"onclick=\"x" } }])),
+                { "type": "mention", "attrs": { "id": id, "kind": "user", "label": "<b>" } },
+            ])),
+            { "type": "codeBlock", "attrs": { "language": "\"><script>" }, "content": [] },
+            { "type": "file", "attrs": { "src": "/f\"x", "title": "<name>" } },
+        ]);
+
+        assert_eq!(
+            html(nodes),
+            format!(
+                "<p>&lt;script&gt;alert(&#39;x&#39;) &amp; &quot;y&quot;&lt;/script&gt;<a href=\"/a&quot;onclick=&quot;x\">link</a><span data-type=\"mention\" data-kind=\"user\" data-id=\"{id}\">@&lt;b&gt;</span></p>\n<pre><code class=\"language-&quot;&gt;&lt;script&gt;\"></code></pre>\n<a href=\"/f&quot;x\">&lt;name&gt;</a>"
+            )
+        );
+    }
+}
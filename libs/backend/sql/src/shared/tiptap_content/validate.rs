@@ -0,0 +1,803 @@
+//! Schema of the documents written by the editor: StarterKit nodes and marks, task lists, tables,
+//! file blocks, mentions and comment anchors.
+
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use super::{FileAttributes, MentionAttributes};
+
+/// Limit of the serialized document.
+const MAX_DOCUMENT_SIZE: usize = 2 * 1024 * 1024;
+/// Limit of node nesting, the document itself being at depth 0.
+const MAX_DEPTH: usize = 32;
+/// Limit of the `colspan` and `rowspan` of table cells.
+pub(super) const MAX_CELL_SPAN: u64 = 1000;
+
+const ALLOWED_URL_SCHEMES: [&str; 4] = ["http", "https", "mailto", "tel"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaViolation {
+    MissingField,
+    InvalidType,
+    UnknownType,
+    NotAllowed,
+    OutOfRange,
+    Empty,
+    TooDeep,
+    TooLarge,
+    DisallowedUrl,
+}
+
+#[derive(Debug, Clone)]
+pub struct SchemaError {
+    /// Location in the document, e.g. `content[1].content[0].marks[0].attrs.href`.
+    /// Empty for errors about the whole document.
+    pub path: String,
+    pub violation: SchemaViolation,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Group {
+    Block,
+    Inline,
+}
+
+/// Content allowed inside a node type.
+enum ContentRule {
+    Leaf,
+    Group(Group),
+    Text,
+    Only(&'static [&'static str]),
+    /// A paragraph followed by blocks, as list items are.
+    ParagraphThenBlocks,
+}
+
+fn node_spec(node_type: &str) -> Option<(Group, ContentRule)> {
+    let spec = match node_type {
+        "paragraph" | "heading" => (Group::Block, ContentRule::Group(Group::Inline)),
+        "blockquote" => (Group::Block, ContentRule::Group(Group::Block)),
+        "codeBlock" => (Group::Block, ContentRule::Text),
+        "bulletList" | "orderedList" => (Group::Block, ContentRule::Only(&["listItem"])),
+        "taskList" => (Group::Block, ContentRule::Only(&["taskItem"])),
+        "table" => (Group::Block, ContentRule::Only(&["tableRow"])),
+        "horizontalRule" | "file" => (Group::Block, ContentRule::Leaf),
+        "listItem" | "taskItem" => (Group::Block, ContentRule::ParagraphThenBlocks),
+        "tableRow" => (
+            Group::Block,
+            ContentRule::Only(&["tableCell", "tableHeader"]),
+        ),
+        "tableCell" | "tableHeader" => (Group::Block, ContentRule::Group(Group::Block)),
+        "text" | "hardBreak" | "mention" => (Group::Inline, ContentRule::Leaf),
+        _ => return None,
+    };
+
+    Some(spec)
+}
+
+/// Whether the node type is a known block, unknown types are not.
+pub(super) fn is_block(node_type: &str) -> bool {
+    node_spec(node_type).is_some_and(|(group, _)| group == Group::Block)
+}
+
+/// Whether the node type is a known node without content.
+pub(super) fn is_leaf(node_type: &str) -> bool {
+    matches!(node_spec(node_type), Some((_, ContentRule::Leaf)))
+}
+
+/// Nodes only valid inside a specific parent, never as a member of their group.
+fn is_nested_only(node_type: &str) -> bool {
+    matches!(
+        node_type,
+        "listItem" | "taskItem" | "tableRow" | "tableCell" | "tableHeader"
+    )
+}
+
+const MARK_TYPES: [&str; 7] = [
+    "bold",
+    "italic",
+    "strike",
+    "underline",
+    "code",
+    "link",
+    "comment",
+];
+
+/// Browsers ignore whitespace and control characters in schemes, so they are dropped first.
+/// URLs without a scheme are relative and allowed.
+fn is_allowed_url(url: &str) -> bool {
+    let url = url
+        .chars()
+        .filter(|c| !c.is_ascii_whitespace() && !c.is_control())
+        .collect::<String>();
+
+    let Some((scheme, _)) = url.split_once(':') else {
+        return true;
+    };
+
+    if scheme.contains(['/', '?', '#']) {
+        return true;
+    }
+
+    ALLOWED_URL_SCHEMES
+        .iter()
+        .any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+}
+
+pub(super) fn validate(document: &Value, size: usize) -> Vec<SchemaError> {
+    let mut validator = Validator { errors: Vec::new() };
+
+    if size > MAX_DOCUMENT_SIZE {
+        validator.error(String::new(), SchemaViolation::TooLarge);
+        return validator.errors;
+    }
+
+    if let Some(content) = document.get("content") {
+        validator.children(content, "", 0, &ContentRule::Group(Group::Block));
+    }
+
+    validator.errors
+}
+
+struct Validator {
+    errors: Vec<SchemaError>,
+}
+
+impl Validator {
+    fn error(&mut self, path: String, violation: SchemaViolation) {
+        self.errors.push(SchemaError { path, violation });
+    }
+
+    fn children(&mut self, content: &Value, parent_path: &str, depth: usize, rule: &ContentRule) {
+        let path = join(parent_path, "content");
+
+        if content.is_null() {
+            return;
+        }
+        let Some(children) = content.as_array() else {
+            self.error(path, SchemaViolation::InvalidType);
+            return;
+        };
+
+        if matches!(rule, ContentRule::Leaf) && !children.is_empty() {
+            self.error(path, SchemaViolation::NotAllowed);
+            return;
+        }
+
+        if depth + 1 > MAX_DEPTH {
+            self.error(path, SchemaViolation::TooDeep);
+            return;
+        }
+
+        for (index, child) in children.iter().enumerate() {
+            let child_path = format!("{path}[{index}]");
+
+            let Some(child_type) = self.node(child, &child_path, depth + 1) else {
+                continue;
+            };
+
+            let allowed = match rule {
+                ContentRule::Leaf => false,
+                ContentRule::Text => child_type == "text",
+                ContentRule::Only(types) => types.contains(&child_type),
+                ContentRule::Group(group) => {
+                    !is_nested_only(child_type)
+                        && node_spec(child_type).is_some_and(|(g, _)| g == *group)
+                }
+                ContentRule::ParagraphThenBlocks if index == 0 => child_type == "paragraph",
+                ContentRule::ParagraphThenBlocks => {
+                    !is_nested_only(child_type)
+                        && node_spec(child_type).is_some_and(|(g, _)| g == Group::Block)
+                }
+            };
+
+            if !allowed {
+                self.error(child_path, SchemaViolation::NotAllowed);
+            }
+        }
+
+        // Text in code blocks is plain
+        if matches!(rule, ContentRule::Text) {
+            for (index, child) in children.iter().enumerate() {
+                if child.get("marks").is_some_and(|marks| !marks.is_null()) {
+                    self.error(
+                        format!("{path}[{index}].marks"),
+                        SchemaViolation::NotAllowed,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Validates a node and its content, returns its type if it is a known one.
+    fn node<'a>(&mut self, node: &'a Value, path: &str, depth: usize) -> Option<&'a str> {
+        let Some(object) = node.as_object() else {
+            self.error(path.to_string(), SchemaViolation::InvalidType);
+            return None;
+        };
+
+        let node_type = match object.get("type") {
+            None => {
+                self.error(join(path, "type"), SchemaViolation::MissingField);
+                return None;
+            }
+            Some(node_type) => match node_type.as_str() {
+                Some(node_type) => node_type,
+                None => {
+                    self.error(join(path, "type"), SchemaViolation::InvalidType);
+                    return None;
+                }
+            },
+        };
+
+        let Some((_, rule)) = node_spec(node_type) else {
+            self.error(join(path, "type"), SchemaViolation::UnknownType);
+            return None;
+        };
+
+        self.attrs(node_type, object, path);
+
+        if let Some(marks) = object.get("marks") {
+            if node_type == "text" {
+                self.marks(marks, path);
+            } else if !marks.is_null() {
+                self.error(join(path, "marks"), SchemaViolation::NotAllowed);
+            }
+        }
+
+        if let Some(content) = object.get("content") {
+            self.children(content, path, depth, &rule);
+        }
+
+        Some(node_type)
+    }
+
+    fn attrs(&mut self, node_type: &str, node: &Map<String, Value>, path: &str) {
+        let attrs = node.get("attrs").filter(|attrs| !attrs.is_null());
+        let attrs_path = join(path, "attrs");
+
+        if let Some(attrs) = attrs
+            && !attrs.is_object()
+        {
+            self.error(attrs_path, SchemaViolation::InvalidType);
+            return;
+        }
+
+        let attr = |name: &str| attrs.and_then(|a| a.get(name)).filter(|v| !v.is_null());
+
+        match node_type {
+            "text" => match node.get("text") {
+                None => self.error(join(path, "text"), SchemaViolation::MissingField),
+                Some(Value::String(text)) if text.is_empty() => {
+                    self.error(join(path, "text"), SchemaViolation::Empty)
+                }
+                Some(Value::String(_)) => {}
+                Some(_) => self.error(join(path, "text"), SchemaViolation::InvalidType),
+            },
+            "heading" => match attr("level").map(|level| level.as_u64()) {
+                None => {}
+                Some(Some(1..=6)) => {}
+                Some(Some(_)) => {
+                    self.error(join(&attrs_path, "level"), SchemaViolation::OutOfRange)
+                }
+                Some(None) => self.error(join(&attrs_path, "level"), SchemaViolation::InvalidType),
+            },
+            "orderedList" => {
+                if let Some(start) = attr("start")
+                    && !start.is_u64()
+                    && !start.is_i64()
+                {
+                    self.error(join(&attrs_path, "start"), SchemaViolation::InvalidType);
+                }
+            }
+            "taskItem" => {
+                if let Some(checked) = attr("checked")
+                    && !checked.is_boolean()
+                {
+                    self.error(join(&attrs_path, "checked"), SchemaViolation::InvalidType);
+                }
+            }
+            "codeBlock" => {
+                if let Some(language) = attr("language")
+                    && !language.is_string()
+                {
+                    self.error(join(&attrs_path, "language"), SchemaViolation::InvalidType);
+                }
+            }
+            "tableCell" | "tableHeader" => {
+                for name in ["colspan", "rowspan"] {
+                    match attr(name).map(|span| span.as_u64()) {
+                        None => {}
+                        Some(Some(1..=MAX_CELL_SPAN)) => {}
+                        Some(Some(_)) => {
+                            self.error(join(&attrs_path, name), SchemaViolation::OutOfRange)
+                        }
+                        Some(None) => {
+                            self.error(join(&attrs_path, name), SchemaViolation::InvalidType)
+                        }
+                    }
+                }
+            }
+            "mention" => match attrs {
+                None => self.error(attrs_path, SchemaViolation::MissingField),
+                Some(attrs) => {
+                    if serde_json::from_value::<MentionAttributes>(attrs.clone()).is_err() {
+                        self.error(attrs_path, SchemaViolation::InvalidType);
+                    }
+                }
+            },
+            "file" => {
+                let Some(attrs) = attrs else {
+                    return;
+                };
+                let Ok(file) = serde_json::from_value::<FileAttributes>(attrs.clone()) else {
+                    self.error(attrs_path, SchemaViolation::InvalidType);
+                    return;
+                };
+
+                for (name, url) in [("src", &file.src), ("href", &file.href)] {
+                    if let Some(url) = url
+                        && !is_allowed_url(url)
+                    {
+                        self.error(join(&attrs_path, name), SchemaViolation::DisallowedUrl);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn marks(&mut self, marks: &Value, path: &str) {
+        let path = join(path, "marks");
+
+        if marks.is_null() {
+            return;
+        }
+        let Some(marks) = marks.as_array() else {
+            self.error(path, SchemaViolation::InvalidType);
+            return;
+        };
+
+        for (index, mark) in marks.iter().enumerate() {
+            let mark_path = format!("{path}[{index}]");
+
+            let Some(mark_type) = mark.get("type") else {
+                let violation = match mark.is_object() {
+                    true => SchemaViolation::MissingField,
+                    false => SchemaViolation::InvalidType,
+                };
+                self.error(join(&mark_path, "type"), violation);
+                continue;
+            };
+
+            let Some(mark_type) = mark_type.as_str() else {
+                self.error(join(&mark_path, "type"), SchemaViolation::InvalidType);
+                continue;
+            };
+
+            if !MARK_TYPES.contains(&mark_type) {
+                self.error(join(&mark_path, "type"), SchemaViolation::UnknownType);
+                continue;
+            }
+
+            if mark_type == "link" {
+                let href_path = format!("{mark_path}.attrs.href");
+
+                match mark.pointer("/attrs/href") {
+                    None | Some(Value::Null) => {
+                        self.error(href_path, SchemaViolation::MissingField)
+                    }
+                    Some(Value::String(href)) if !is_allowed_url(href) => {
+                        self.error(href_path, SchemaViolation::DisallowedUrl)
+                    }
+                    Some(Value::String(_)) => {}
+                    Some(_) => self.error(href_path, SchemaViolation::InvalidType),
+                }
+            }
+
+            if mark_type == "comment" {
+                let id_path = format!("{mark_path}.attrs.commentId");
+
+                match mark.pointer("/attrs/commentId") {
+                    None | Some(Value::Null) => self.error(id_path, SchemaViolation::MissingField),
+                    Some(Value::String(id)) if Uuid::parse_str(id).is_ok() => {}
+                    Some(_) => self.error(id_path, SchemaViolation::InvalidType),
+                }
+            }
+        }
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn errors(content: Value) -> Vec<(String, SchemaViolation)> {
+        let document = json!({ "type": "doc", "content": content });
+        let size = document.to_string().len();
+
+        validate(&document, size)
+            .into_iter()
+            .map(|error| (error.path, error.violation))
+            .collect()
+    }
+
+    fn paragraph(content: Value) -> Value {
+        json!({ "type": "paragraph", "content": content })
+    }
+
+    fn link(href: &str) -> Value {
+        paragraph(json!([{
+            "type": "text",
+            "text": "link",
+            "marks": [{ "type": "link", "attrs": { "href": href } }],
+        }]))
+    }
+
+    #[test]
+    fn test_valid_document() {
+        let content = json!([
+            { "type": "heading", "attrs": { "level": 2 }, "content": [{ "type": "text", "text": "Title" }] },
+            paragraph(json!([
+                { "type": "text", "text": "bold", "marks": [{ "type": "bold" }] },
+                { "type": "hardBreak" },
+                { "type": "mention", "attrs": { "id": Uuid::nil(), "kind": "page", "label": "Page" } },
+            ])),
+            { "type": "bulletList", "content": [
+                { "type": "listItem", "content": [
+                    paragraph(json!([{ "type": "text", "text": "item" }])),
+                    { "type": "taskList", "content": [
+                        { "type": "taskItem", "attrs": { "checked": true }, "content": [paragraph(json!(null))] },
+                    ] },
+                ] },
+            ] },
+            { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [{ "type": "text", "text": "fn main() {}" }] },
+            { "type": "table", "content": [
+                { "type": "tableRow", "content": [
+                    { "type": "tableHeader", "content": [paragraph(json!([{ "type": "text", "text": "cell" }]))] },
+                ] },
+            ] },
+            { "type": "horizontalRule" },
+            { "type": "file", "attrs": { "id": Uuid::nil(), "title": "a.png", "src": "/assets/a.png" } },
+        ]);
+
+        assert_eq!(errors(content), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_and_missing_types() {
+        let content = json!([
+            { "type": "iframe" },
+            { "content": [] },
+            { "type": 1 },
+            paragraph(json!([{ "type": "text", "text": "a", "marks": [{ "type": "highlight" }] }])),
+        ]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                ("content[0].type".to_string(), SchemaViolation::UnknownType),
+                ("content[1].type".to_string(), SchemaViolation::MissingField),
+                ("content[2].type".to_string(), SchemaViolation::InvalidType),
+                (
+                    "content[3].content[0].marks[0].type".to_string(),
+                    SchemaViolation::UnknownType
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_children_must_match_parent() {
+        let content = json!([
+            { "type": "text", "text": "inline at the top" },
+            { "type": "listItem", "content": [paragraph(json!(null))] },
+            paragraph(json!([paragraph(json!(null))])),
+            { "type": "bulletList", "content": [paragraph(json!(null))] },
+            { "type": "listItem", "content": [] },
+            { "type": "bulletList", "content": [
+                { "type": "listItem", "content": [{ "type": "heading" }] },
+            ] },
+            { "type": "horizontalRule", "content": [paragraph(json!(null))] },
+        ]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                ("content[0]".to_string(), SchemaViolation::NotAllowed),
+                ("content[1]".to_string(), SchemaViolation::NotAllowed),
+                (
+                    "content[2].content[0]".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+                (
+                    "content[3].content[0]".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+                ("content[4]".to_string(), SchemaViolation::NotAllowed),
+                (
+                    "content[5].content[0].content[0]".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+                (
+                    "content[6].content".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_block_text_is_plain() {
+        let content = json!([{ "type": "codeBlock", "content": [
+            { "type": "text", "text": "code", "marks": [{ "type": "bold" }] },
+            { "type": "hardBreak" },
+        ] }]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                (
+                    "content[0].content[1]".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+                (
+                    "content[0].content[0].marks".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attributes() {
+        let content = json!([
+            { "type": "heading", "attrs": { "level": 7 } },
+            { "type": "heading", "attrs": { "level": "1" } },
+            { "type": "orderedList", "attrs": { "start": "1" }, "content": [] },
+            { "type": "taskList", "content": [
+                { "type": "taskItem", "attrs": { "checked": "yes" }, "content": [paragraph(json!(null))] },
+            ] },
+            { "type": "codeBlock", "attrs": { "language": 1 } },
+            paragraph(json!([
+                { "type": "mention" },
+                { "type": "mention", "attrs": { "id": "not a uuid" } },
+                { "type": "text", "text": "" },
+                { "type": "text" },
+            ])),
+            { "type": "paragraph", "attrs": [] },
+            { "type": "file", "attrs": { "size": "large" } },
+        ]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                (
+                    "content[0].attrs.level".to_string(),
+                    SchemaViolation::OutOfRange
+                ),
+                (
+                    "content[1].attrs.level".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[2].attrs.start".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[3].content[0].attrs.checked".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[4].attrs.language".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[5].content[0].attrs".to_string(),
+                    SchemaViolation::MissingField
+                ),
+                (
+                    "content[5].content[1].attrs".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[5].content[2].text".to_string(),
+                    SchemaViolation::Empty
+                ),
+                (
+                    "content[5].content[3].text".to_string(),
+                    SchemaViolation::MissingField
+                ),
+                ("content[6].attrs".to_string(), SchemaViolation::InvalidType),
+                ("content[7].attrs".to_string(), SchemaViolation::InvalidType),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_cell_spans() {
+        let cell = |attrs: Value| json!({ "type": "tableCell", "attrs": attrs, "content": [paragraph(json!(null))] });
+        let content = json!([{ "type": "table", "content": [{ "type": "tableRow", "content": [
+            cell(json!({ "colspan": 2, "rowspan": null })),
+            cell(json!({ "colspan": 0 })),
+            cell(json!({ "colspan": u64::MAX })),
+            cell(json!({ "rowspan": 1.5 })),
+            cell(json!({ "rowspan": "2" })),
+        ] }] }]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                (
+                    "content[0].content[0].content[1].attrs.colspan".to_string(),
+                    SchemaViolation::OutOfRange
+                ),
+                (
+                    "content[0].content[0].content[2].attrs.colspan".to_string(),
+                    SchemaViolation::OutOfRange
+                ),
+                (
+                    "content[0].content[0].content[3].attrs.rowspan".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[0].content[0].content[4].attrs.rowspan".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_marks() {
+        let comment = |comment_id: Value| json!({ "type": "text", "text": "a", "marks": [{ "type": "comment", "attrs": { "commentId": comment_id } }] });
+        let content = json!([paragraph(json!([
+            comment(json!(Uuid::nil())),
+            comment(json!("not a uuid")),
+            comment(json!(null)),
+            { "type": "text", "text": "a", "marks": [{ "type": "link" }] },
+            { "type": "text", "text": "a", "marks": [{ "type": "link", "attrs": { "href": 1 } }] },
+            { "type": "text", "text": "a", "marks": ["bold"] },
+            { "type": "text", "text": "a", "marks": {} },
+            { "type": "hardBreak", "marks": [{ "type": "bold" }] },
+        ])),]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                (
+                    "content[0].content[1].marks[0].attrs.commentId".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[0].content[2].marks[0].attrs.commentId".to_string(),
+                    SchemaViolation::MissingField
+                ),
+                (
+                    "content[0].content[3].marks[0].attrs.href".to_string(),
+                    SchemaViolation::MissingField
+                ),
+                (
+                    "content[0].content[4].marks[0].attrs.href".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[0].content[5].marks[0].type".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[0].content[6].marks".to_string(),
+                    SchemaViolation::InvalidType
+                ),
+                (
+                    "content[0].content[7].marks".to_string(),
+                    SchemaViolation::NotAllowed
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_allowed_url() {
+        for url in [
+            "https://example.com",
+            "HTTP://example.com",
+            "mailto:someone@example.com",
+            "tel:+123",
+            "/pages/1",
+            "relative/path:with-colon",
+            "?taskId=1",
+            "#anchor",
+            "",
+        ] {
+            assert!(is_allowed_url(url), "{url}");
+        }
+
+        for url in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "java\tscript:alert(1)",
+            "java\nscript:alert(1)",
+            " javascript:alert(1)",
+            "java\u{0}script:alert(1)",
+            "data:text/html,<script>alert(1)</script>",
+            "vbscript:msgbox(1)",
+            "file:///etc/passwd",
+        ] {
+            assert!(!is_allowed_url(url), "{url:?}");
+        }
+    }
+
+    #[test]
+    fn test_disallowed_urls_are_reported() {
+        let content = json!([
+            link("java\tscript:alert(1)"),
+            link("https://example.com"),
+            { "type": "file", "attrs": { "src": "javascript:alert(1)", "href": "data:text/html,x" } },
+        ]);
+
+        assert_eq!(
+            errors(content),
+            vec![
+                (
+                    "content[0].content[0].marks[0].attrs.href".to_string(),
+                    SchemaViolation::DisallowedUrl
+                ),
+                (
+                    "content[2].attrs.src".to_string(),
+                    SchemaViolation::DisallowedUrl
+                ),
+                (
+                    "content[2].attrs.href".to_string(),
+                    SchemaViolation::DisallowedUrl
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_depth_limit() {
+        let nested = |depth: usize| {
+            let mut node = paragraph(json!(null));
+            for _ in 1..depth {
+                node = json!({ "type": "blockquote", "content": [node] });
+            }
+            json!([node])
+        };
+
+        assert_eq!(errors(nested(MAX_DEPTH)), vec![]);
+
+        let errors = errors(nested(MAX_DEPTH + 1));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].1, SchemaViolation::TooDeep);
+        assert_eq!(
+            errors[0].0,
+            format!("{}content", "content[0].".repeat(MAX_DEPTH))
+        );
+    }
+
+    #[test]
+    fn test_size_limit() {
+        let document = json!({ "type": "doc", "content": [{ "type": "iframe" }] });
+
+        let errors = validate(&document, MAX_DOCUMENT_SIZE + 1);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "");
+        assert_eq!(errors[0].violation, SchemaViolation::TooLarge);
+
+        assert_eq!(validate(&document, MAX_DOCUMENT_SIZE).len(), 1);
+        assert_eq!(
+            validate(&document, MAX_DOCUMENT_SIZE)[0].violation,
+            SchemaViolation::UnknownType
+        );
+    }
+}
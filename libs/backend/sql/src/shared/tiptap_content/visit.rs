@@ -0,0 +1,68 @@
+//! Depth-first traversal of TipTap nodes, parents are visited before their children.
+
+use serde_json::Value;
+
+/// Returned by visitors to steer the traversal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visit {
+    Continue,
+    /// Continues with the next sibling, the children of the node are not visited.
+    SkipChildren,
+    Stop,
+}
+
+fn children(node: &Value) -> Option<&Vec<Value>> {
+    node.get("content").and_then(|c| c.as_array())
+}
+
+fn children_mut(node: &mut Value) -> Option<&mut Vec<Value>> {
+    node.get_mut("content").and_then(|c| c.as_array_mut())
+}
+
+/// Returns false once a visitor returned `Visit::Stop`.
+pub(super) fn walk<F>(node: &Value, visit: &mut F) -> bool
+where
+    F: FnMut(&Value) -> Visit,
+{
+    match visit(node) {
+        Visit::Stop => return false,
+        Visit::SkipChildren => return true,
+        Visit::Continue => {}
+    }
+
+    children(node)
+        .into_iter()
+        .flatten()
+        .all(|child| walk(child, visit))
+}
+
+/// Same as `walk`, children are visited after the visitor changed their parent.
+pub(super) fn walk_mut<F>(node: &mut Value, visit: &mut F) -> bool
+where
+    F: FnMut(&mut Value) -> Visit,
+{
+    match visit(node) {
+        Visit::Stop => return false,
+        Visit::SkipChildren => return true,
+        Visit::Continue => {}
+    }
+
+    children_mut(node)
+        .into_iter()
+        .flatten()
+        .all(|child| walk_mut(child, visit))
+}
+
+/// Removes the descendants of `node` for which `keep` returns false.
+pub(super) fn retain<F>(node: &mut Value, keep: &mut F)
+where
+    F: FnMut(&Value) -> bool,
+{
+    if let Some(children) = children_mut(node) {
+        children.retain(|child| keep(child));
+
+        for child in children {
+            retain(child, keep);
+        }
+    }
+}
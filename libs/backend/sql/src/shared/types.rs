@@ -2,7 +2,7 @@ use std::fmt::{self, Display};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema, Default)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema, Default, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub enum SortOrder {
     #[default]
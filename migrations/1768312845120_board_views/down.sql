@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_board_views_page_id;
+
+DROP TABLE IF EXISTS board_views;
@@ -0,0 +1,18 @@
+CREATE TABLE
+    board_views (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        page_id UUID NOT NULL,
+        owner_id UUID NOT NULL,
+        name VARCHAR(255) NOT NULL,
+        is_shared BOOLEAN NOT NULL DEFAULT FALSE,
+        filters JSONB NOT NULL DEFAULT '{}'::jsonb,
+        sort JSONB NOT NULL DEFAULT '{}'::jsonb,
+        group_by TEXT NOT NULL DEFAULT 'status',
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        deleted_at TIMESTAMPTZ,
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        FOREIGN KEY (owner_id) REFERENCES users (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS idx_board_views_page_id ON board_views (page_id);
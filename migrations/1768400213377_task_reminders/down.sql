@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS task_reminders;
+
+DROP INDEX IF EXISTS idx_task_watchers_user_id;
+
+DROP TABLE IF EXISTS task_watchers;
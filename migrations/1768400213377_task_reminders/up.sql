@@ -0,0 +1,25 @@
+CREATE TABLE
+    task_watchers (
+        task_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        PRIMARY KEY (task_id, user_id),
+        FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS idx_task_watchers_user_id ON task_watchers (user_id);
+
+CREATE TABLE
+    task_reminders (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        task_id UUID NOT NULL,
+        user_id UUID NOT NULL,
+        kind TEXT NOT NULL,
+        due_date TIMESTAMPTZ NOT NULL,
+        sent_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+        -- A rescheduled task gets a new due_date and therefore fresh reminders
+        UNIQUE (task_id, user_id, kind, due_date)
+    );
@@ -0,0 +1,15 @@
+CREATE TABLE
+    task_checklist_items (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        task_id UUID NOT NULL,
+        text TEXT NOT NULL,
+        is_done BOOLEAN NOT NULL DEFAULT FALSE,
+        assignee_id UUID,
+        position INT NOT NULL CHECK (position > 0),
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+        FOREIGN KEY (assignee_id) REFERENCES users (id) ON DELETE SET NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_task_checklist_items_task_id ON task_checklist_items (task_id);
@@ -0,0 +1,23 @@
+CREATE TABLE
+    calendar_feeds (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        user_id UUID NOT NULL,
+        -- Only the SHA-256 of the token is stored, the token itself is shown once on creation
+        token_hash TEXT NOT NULL UNIQUE,
+        scope TEXT NOT NULL,
+        workspace_id UUID,
+        page_id UUID,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        last_used_at TIMESTAMPTZ,
+        revoked_at TIMESTAMPTZ,
+        FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE,
+        FOREIGN KEY (workspace_id) REFERENCES workspaces (id) ON DELETE CASCADE,
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        CHECK (
+            (scope = 'user' AND workspace_id IS NULL AND page_id IS NULL)
+            OR (scope = 'workspace' AND workspace_id IS NOT NULL AND page_id IS NULL)
+            OR (scope = 'board' AND workspace_id IS NULL AND page_id IS NOT NULL)
+        )
+    );
+
+CREATE INDEX IF NOT EXISTS idx_calendar_feeds_user_id ON calendar_feeds (user_id);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_mentions_target;
+
+DROP TABLE IF EXISTS mentions;
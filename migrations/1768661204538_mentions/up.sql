@@ -0,0 +1,18 @@
+CREATE TABLE
+    mentions (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        -- Page holding the mention, task_id is set when it is in a task description
+        page_id UUID NOT NULL,
+        task_id UUID,
+        target_kind TEXT NOT NULL,
+        target_id UUID NOT NULL,
+        mentioned_by UUID,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        FOREIGN KEY (task_id) REFERENCES tasks (id) ON DELETE CASCADE,
+        FOREIGN KEY (mentioned_by) REFERENCES users (id) ON DELETE SET NULL,
+        CONSTRAINT mentions_target_kind_check CHECK (target_kind IN ('user', 'task', 'page')),
+        CONSTRAINT mentions_source_target_key UNIQUE NULLS NOT DISTINCT (page_id, task_id, target_kind, target_id)
+    );
+
+CREATE INDEX IF NOT EXISTS idx_mentions_target ON mentions (target_kind, target_id);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_links_target;
+
+DROP TABLE IF EXISTS links;
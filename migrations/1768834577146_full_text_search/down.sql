@@ -0,0 +1,17 @@
+DROP INDEX IF EXISTS idx_task_chat_messages_search_vector;
+
+DROP INDEX IF EXISTS idx_tasks_search_vector;
+
+DROP INDEX IF EXISTS idx_text_page_contents_search_vector;
+
+DROP INDEX IF EXISTS idx_pages_search_vector;
+
+ALTER TABLE task_chat_messages DROP COLUMN IF EXISTS search_vector;
+
+ALTER TABLE tasks DROP COLUMN IF EXISTS search_vector;
+
+ALTER TABLE text_page_contents DROP COLUMN IF EXISTS search_vector;
+
+ALTER TABLE pages DROP COLUMN IF EXISTS search_vector;
+
+DROP FUNCTION IF EXISTS tiptap_text (JSONB);
@@ -0,0 +1,32 @@
+-- Text of a TipTap document: the values of its "text" keys joined by spaces
+CREATE OR REPLACE FUNCTION tiptap_text (content JSONB) RETURNS TEXT AS $$
+    SELECT string_agg(value, ' ')
+    FROM jsonb_array_elements_text(jsonb_path_query_array(content, 'strict $.**.text'))
+$$ LANGUAGE SQL IMMUTABLE PARALLEL SAFE;
+
+-- Search vectors are generated columns so that writers outside this service (the chat socket)
+-- keep them up to date too.
+ALTER TABLE pages
+ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', title)) STORED;
+
+ALTER TABLE text_page_contents
+ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+    to_tsvector('simple', COALESCE(tiptap_text (content), ''))
+) STORED;
+
+ALTER TABLE tasks
+ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', title), 'A') ||
+    setweight(to_tsvector('simple', COALESCE(tiptap_text (description), '')), 'B')
+) STORED;
+
+ALTER TABLE task_chat_messages
+ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (to_tsvector('simple', text)) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_pages_search_vector ON pages USING GIN (search_vector);
+
+CREATE INDEX IF NOT EXISTS idx_text_page_contents_search_vector ON text_page_contents USING GIN (search_vector);
+
+CREATE INDEX IF NOT EXISTS idx_tasks_search_vector ON tasks USING GIN (search_vector);
+
+CREATE INDEX IF NOT EXISTS idx_task_chat_messages_search_vector ON task_chat_messages USING GIN (search_vector);
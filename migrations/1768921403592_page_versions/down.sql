@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_page_versions_page_id;
+
+DROP TABLE IF EXISTS page_versions;
@@ -0,0 +1,15 @@
+CREATE TABLE
+    page_versions (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        page_id UUID NOT NULL,
+        content JSONB,
+        -- NULL for the baseline taken from content saved before history existed
+        created_by UUID,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        -- Moves forward while saves of one editing session are coalesced into the version
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        FOREIGN KEY (created_by) REFERENCES users (id) ON DELETE SET NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_page_versions_page_id ON page_versions (page_id, updated_at DESC);
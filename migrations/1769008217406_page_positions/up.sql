@@ -0,0 +1,26 @@
+ALTER TABLE pages
+ADD COLUMN IF NOT EXISTS position INTEGER NOT NULL DEFAULT 0;
+
+-- Existing siblings keep their creation order
+UPDATE pages p
+SET
+    position = ordered.position
+FROM
+    (
+        SELECT
+            id,
+            ROW_NUMBER() OVER (
+                PARTITION BY
+                    workspace_id,
+                    parent_page_id
+                ORDER BY
+                    created_at,
+                    id
+            ) AS position
+        FROM
+            pages
+    ) ordered
+WHERE
+    p.id = ordered.id;
+
+CREATE INDEX IF NOT EXISTS idx_pages_parent_position ON pages (workspace_id, parent_page_id, position);
@@ -0,0 +1 @@
+DROP FUNCTION IF EXISTS effective_page_access (UUID, UUID);
@@ -0,0 +1,33 @@
+-- Role a user holds on a page: the nearest explicit grant walking up from the page through its
+-- parent groups, falling back to the role in the page's workspace. Returns the row it came from.
+CREATE OR REPLACE FUNCTION effective_page_access (p_user_id UUID, p_page_id UUID) RETURNS TABLE (
+    id UUID,
+    role VARCHAR(50),
+    created_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ
+) LANGUAGE sql STABLE AS $$
+    WITH RECURSIVE chain AS (
+        SELECT pages.id, pages.parent_page_id, pages.workspace_id, 0 AS depth
+        FROM pages
+        WHERE pages.id = p_page_id
+        UNION ALL
+        SELECT parent.id, parent.parent_page_id, parent.workspace_id, chain.depth + 1
+        FROM pages parent
+        INNER JOIN chain ON parent.id = chain.parent_page_id
+        WHERE chain.depth < 64
+    ),
+    sources AS (
+        SELECT pa.id, pa.role, pa.created_at, pa.updated_at, chain.depth AS priority
+        FROM chain
+        INNER JOIN page_accesses pa ON pa.page_id = chain.id AND pa.user_id = p_user_id
+        UNION ALL
+        SELECT wa.id, wa.role, wa.created_at, wa.updated_at, 2147483647
+        FROM chain
+        INNER JOIN workspace_accesses wa ON wa.workspace_id = chain.workspace_id AND wa.user_id = p_user_id
+        WHERE chain.depth = 0
+    )
+    SELECT sources.id, sources.role, sources.created_at, sources.updated_at
+    FROM sources
+    ORDER BY sources.priority
+    LIMIT 1
+$$;
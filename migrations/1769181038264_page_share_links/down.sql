@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_page_share_links_page_id;
+
+DROP TABLE IF EXISTS page_share_links;
@@ -0,0 +1,18 @@
+CREATE TABLE
+    page_share_links (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        page_id UUID NOT NULL,
+        -- Only the SHA-256 of the token is stored, the token itself is shown once on creation
+        token_hash TEXT NOT NULL UNIQUE,
+        -- bcrypt hash, NULL when the link is not password protected
+        password_hash TEXT,
+        expires_at TIMESTAMPTZ,
+        created_by UUID NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        last_used_at TIMESTAMPTZ,
+        revoked_at TIMESTAMPTZ,
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        FOREIGN KEY (created_by) REFERENCES users (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS idx_page_share_links_page_id ON page_share_links (page_id);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_text_page_updates_page_id;
+
+DROP TABLE IF EXISTS text_page_updates;
@@ -0,0 +1,13 @@
+-- Yjs updates of collaboratively edited text pages. Updates commute, so the document is
+-- the result of applying all of them in any order. Clients replace the rows they synced
+-- with their merged state, which keeps the log short.
+CREATE TABLE
+    text_page_updates (
+        id BIGSERIAL PRIMARY KEY,
+        page_id UUID NOT NULL,
+        data BYTEA NOT NULL,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (page_id) REFERENCES text_page_contents (page_id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS idx_text_page_updates_page_id ON text_page_updates (page_id, id);
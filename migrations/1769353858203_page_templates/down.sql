@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_page_templates_workspace_id;
+
+DROP TABLE IF EXISTS page_templates;
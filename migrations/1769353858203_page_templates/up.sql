@@ -0,0 +1,20 @@
+CREATE TABLE
+    page_templates (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        workspace_id UUID NOT NULL,
+        created_by UUID NOT NULL,
+        name VARCHAR(255) NOT NULL,
+        type TEXT NOT NULL,
+        -- `{{date}}` and `{{user}}` are expanded when a page is created from the template
+        title_pattern VARCHAR(255) NOT NULL,
+        -- Text templates only
+        content JSONB,
+        -- Board templates only, statuses of the created board in order
+        statuses JSONB NOT NULL DEFAULT '[]'::jsonb,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (workspace_id) REFERENCES workspaces (id) ON DELETE CASCADE,
+        FOREIGN KEY (created_by) REFERENCES users (id) ON DELETE CASCADE
+    );
+
+CREATE INDEX IF NOT EXISTS idx_page_templates_workspace_id ON page_templates (workspace_id);
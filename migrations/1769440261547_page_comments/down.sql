@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_page_comments_parent_comment_id;
+
+DROP INDEX IF EXISTS idx_page_comments_page_id;
+
+DROP TABLE IF EXISTS page_comments;
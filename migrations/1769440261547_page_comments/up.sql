@@ -0,0 +1,24 @@
+CREATE TABLE
+    page_comments (
+        id UUID PRIMARY KEY DEFAULT uuid_generate_v4 (),
+        page_id UUID NOT NULL,
+        author_id UUID NOT NULL,
+        -- Replies point to the first comment of their thread, threads are one level deep
+        parent_comment_id UUID,
+        text TEXT NOT NULL,
+        -- First comments only, the anchored text when the thread was started. The anchor itself is a
+        -- `comment` mark of the page content, so it follows the text through edits
+        quote TEXT,
+        resolved_at TIMESTAMPTZ,
+        resolved_by UUID,
+        created_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        updated_at TIMESTAMPTZ NOT NULL DEFAULT (CURRENT_TIMESTAMP),
+        FOREIGN KEY (page_id) REFERENCES pages (id) ON DELETE CASCADE,
+        FOREIGN KEY (author_id) REFERENCES users (id) ON DELETE CASCADE,
+        FOREIGN KEY (parent_comment_id) REFERENCES page_comments (id) ON DELETE CASCADE,
+        FOREIGN KEY (resolved_by) REFERENCES users (id) ON DELETE SET NULL
+    );
+
+CREATE INDEX IF NOT EXISTS idx_page_comments_page_id ON page_comments (page_id);
+
+CREATE INDEX IF NOT EXISTS idx_page_comments_parent_comment_id ON page_comments (parent_comment_id);
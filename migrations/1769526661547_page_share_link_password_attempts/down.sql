@@ -0,0 +1,3 @@
+ALTER TABLE page_share_links
+DROP COLUMN IF EXISTS password_locked_until,
+DROP COLUMN IF EXISTS failed_password_attempts;
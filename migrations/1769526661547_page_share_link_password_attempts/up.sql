@@ -0,0 +1,4 @@
+-- Every password attempt is counted, a link is locked for a growing time once the limit is reached
+ALTER TABLE page_share_links
+ADD COLUMN failed_password_attempts INT NOT NULL DEFAULT 0,
+ADD COLUMN password_locked_until TIMESTAMPTZ;